@@ -11,14 +11,14 @@
 // limitations under the License.
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::ast::Ident;
-use crate::ast::ObjectName;
+use crate::ast::value::escape_single_quote_string;
+use crate::ast::{display_comma_separated, Ident, ObjectName};
 
 /// SQL data types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -26,8 +26,12 @@ use crate::ast::ObjectName;
 pub enum DataType {
     /// Fixed-length character type e.g. CHAR(10)
     Char(Option<u64>),
-    /// Variable-length character type e.g. VARCHAR(10)
-    Varchar(Option<u64>),
+    /// Variable-length character type e.g. VARCHAR(10) or MSSQL VARCHAR(MAX)
+    Varchar(Option<CharacterLength>),
+    /// Variable-length national character type e.g. NVARCHAR(10) or NVARCHAR(MAX)
+    Nvarchar(Option<CharacterLength>),
+    /// Large character object with optional length e.g. CHARACTER LARGE OBJECT(1000)
+    CharacterLargeObject(Option<u64>),
     /// Uuid type
     Uuid,
     /// Large character object e.g. CLOB(1000)
@@ -50,6 +54,10 @@ pub enum DataType {
     SmallInt(Option<u64>),
     /// Unsigned small integer with optional display width e.g. SMALLINT UNSIGNED or SMALLINT(5) UNSIGNED
     UnsignedSmallInt(Option<u64>),
+    /// MySQL medium integer with optional display width e.g. MEDIUMINT or MEDIUMINT(8)
+    MediumInt(Option<u64>),
+    /// Unsigned medium integer with optional display width e.g. MEDIUMINT UNSIGNED
+    UnsignedMediumInt(Option<u64>),
     /// Integer with optional display width e.g. INT or INT(11)
     Int(Option<u64>),
     /// Unsigned integer with optional display width e.g. INT UNSIGNED or INT(11) UNSIGNED
@@ -58,18 +66,31 @@ pub enum DataType {
     BigInt(Option<u64>),
     /// Unsigned big integer with optional display width e.g. BIGINT UNSIGNED or BIGINT(20) UNSIGNED
     UnsignedBigInt(Option<u64>),
+    /// BigQuery 64-bit integer INT64
+    Int64,
+    /// BigQuery 64-bit floating point FLOAT64
+    Float64,
+    /// Oracle and Snowflake number with optional precision and scale e.g. NUMBER(38,0),
+    /// where Oracle's NUMBER(*,2) has a scale but no precision
+    Number(Option<u64>, Option<u64>),
+    /// Fixed-length bit string e.g. BIT or BIT(8)
+    Bit(Option<u64>),
+    /// Variable-length bit string e.g. BIT VARYING(8)
+    BitVarying(Option<u64>),
     /// Floating point e.g. REAL
     Real,
-    /// Double e.g. DOUBLE PRECISION
+    /// Double e.g. DOUBLE
     Double,
+    /// Double e.g. DOUBLE PRECISION
+    DoublePrecision,
     /// Boolean
     Boolean,
     /// Date
     Date,
-    /// Time
-    Time,
-    /// Timestamp without tz
-    Timestamp(Option<u64>),
+    /// Time with optional precision and time zone information e.g. TIME(6) WITH TIME ZONE
+    Time(Option<u64>, TimezoneInfo),
+    /// Timestamp with optional precision and time zone information e.g. TIMESTAMPTZ(3)
+    Timestamp(Option<u64>, TimezoneInfo),
     /// DateTime without tz
     DateTime(Option<u64>),
     /// Interval
@@ -78,24 +99,128 @@ pub enum DataType {
     Regclass,
     /// Text
     Text,
+    /// MySQL TINYTEXT
+    TinyText,
+    /// MySQL MEDIUMTEXT
+    MediumText,
+    /// MySQL LONGTEXT
+    LongText,
+    /// MySQL TINYBLOB
+    TinyBlob,
+    /// MySQL MEDIUMBLOB
+    MediumBlob,
+    /// MySQL LONGBLOB
+    LongBlob,
     /// String
     String,
     /// Bytea
     Bytea,
-    /// Custom type such as enums
-    Custom(ObjectName),
+    /// Snowflake semi-structured VARIANT
+    Variant,
+    /// Snowflake semi-structured OBJECT
+    Object,
+    /// JSON
+    Json,
+    /// PostgreSQL binary JSON
+    Jsonb,
+    /// MySQL spatial GEOMETRY
+    Geometry,
+    /// PostgreSQL IPv4 or IPv6 host address INET
+    Inet,
+    /// PostgreSQL MONEY
+    Money,
+    /// Enumeration e.g. MySQL `ENUM('a', 'b')` or ClickHouse `Enum('a' = 1, 'b' = 2)`
+    Enum(Vec<EnumMember>),
+    /// ClickHouse enumeration with 8-bit values e.g. `Enum8('a' = 1, 'b' = 2)`
+    Enum8(Vec<EnumMember>),
+    /// ClickHouse enumeration with 16-bit values e.g. `Enum16('a' = 1, 'b' = 2)`
+    Enum16(Vec<EnumMember>),
+    /// MySQL set of values e.g. SET('a', 'b')
+    Set(Vec<String>),
+    /// Map from key type to value type e.g. MAP(STRING, INT)
+    Map(Box<DataType>, Box<DataType>),
+    /// Struct with optionally named fields e.g. STRUCT<a INT, b STRING>
+    Struct(Vec<StructField>),
+    /// Custom type such as enums, with optional type modifiers e.g.
+    /// PostGIS `geometry(Point, 4326)` or pgvector `vector(1536)`
+    Custom(ObjectName, Vec<String>),
+    /// A character type with an explicit character set e.g.
+    /// `VARCHAR(10) CHARACTER SET utf8mb4`
+    CharacterSet(Box<DataType>, ObjectName),
     /// Arrays
     Array(Box<DataType>, bool),
+    /// PostgreSQL style array with optional size e.g. INT[] or VARCHAR(20)[3]
+    BracketArray(Box<DataType>, Option<u64>),
     /// Tuple
     Tuple(Option<Vec<Ident>>, Vec<Box<DataType>>),
+    /// ClickHouse type whose values may also be NULL e.g. `Nullable(String)`
+    Nullable(Box<DataType>),
+    /// ClickHouse dictionary-encoded type e.g. `LowCardinality(String)`
+    LowCardinality(Box<DataType>),
+    /// ClickHouse nested table of named columns e.g. `Nested(a UInt8, b String)`
+    Nested(Vec<StructField>),
+    /// ClickHouse fixed-length byte string e.g. `FixedString(16)`
+    FixedString(u64),
+    /// ClickHouse 32-bit decimal with the given scale e.g. `Decimal32(4)`
+    Decimal32(u64),
+    /// ClickHouse 64-bit decimal with the given scale e.g. `Decimal64(4)`
+    Decimal64(u64),
+    /// ClickHouse 128-bit decimal with the given scale e.g. `Decimal128(9)`
+    Decimal128(u64),
+    /// ClickHouse 256-bit decimal with the given scale e.g. `Decimal256(18)`
+    Decimal256(u64),
+    /// ClickHouse date and time with sub-second precision and optional time
+    /// zone e.g. `DateTime64(3, 'UTC')`
+    DateTime64(u64, Option<String>),
+    /// No data type was given, as for an MSSQL computed column `c AS (a + b)`
+    Unspecified,
+}
+
+/// The length of a variable-length character type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CharacterLength {
+    /// A number of characters e.g. the `10` in VARCHAR(10)
+    Length(u64),
+    /// MSSQL maximum length e.g. VARCHAR(MAX)
+    Max,
+}
+
+impl fmt::Display for CharacterLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CharacterLength::Length(length) => write!(f, "{}", length),
+            CharacterLength::Max => write!(f, "MAX"),
+        }
+    }
+}
+
+/// Time zone information attached to `TIME` and `TIMESTAMP` types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TimezoneInfo {
+    /// No time zone information given e.g. TIMESTAMP
+    None,
+    /// e.g. TIMESTAMP WITH TIME ZONE
+    WithTimeZone,
+    /// e.g. TIMESTAMP WITHOUT TIME ZONE
+    WithoutTimeZone,
+    /// PostgreSQL abbreviation for `WITH TIME ZONE` e.g. TIMESTAMPTZ
+    Tz,
+    /// Snowflake local time zone variant e.g. TIMESTAMP_LTZ
+    LocalTz,
+    /// Snowflake variant without time zone e.g. TIMESTAMP_NTZ
+    NoTz,
 }
 
 impl fmt::Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             DataType::Char(size) => format_type_with_optional_length(f, "CHAR", size, false),
-            DataType::Varchar(size) => {
-                format_type_with_optional_length(f, "CHARACTER VARYING", size, false)
+            DataType::Varchar(size) => format_character_type(f, "CHARACTER VARYING", size),
+            DataType::Nvarchar(size) => format_character_type(f, "NVARCHAR", size),
+            DataType::CharacterLargeObject(size) => {
+                format_type_with_optional_length(f, "CHARACTER LARGE OBJECT", size, false)
             }
             DataType::Uuid => write!(f, "UUID"),
             DataType::Clob(size) => write!(f, "CLOB({})", size),
@@ -122,6 +247,12 @@ impl fmt::Display for DataType {
             DataType::UnsignedSmallInt(zerofill) => {
                 format_type_with_optional_length(f, "SMALLINT", zerofill, true)
             }
+            DataType::MediumInt(zerofill) => {
+                format_type_with_optional_length(f, "MEDIUMINT", zerofill, false)
+            }
+            DataType::UnsignedMediumInt(zerofill) => {
+                format_type_with_optional_length(f, "MEDIUMINT", zerofill, true)
+            }
             DataType::Int(zerofill) => format_type_with_optional_length(f, "INT", zerofill, false),
             DataType::UnsignedInt(zerofill) => {
                 format_type_with_optional_length(f, "INT", zerofill, true)
@@ -132,17 +263,58 @@ impl fmt::Display for DataType {
             DataType::UnsignedBigInt(zerofill) => {
                 format_type_with_optional_length(f, "BIGINT", zerofill, true)
             }
+            DataType::Int64 => write!(f, "INT64"),
+            DataType::Float64 => write!(f, "FLOAT64"),
+            DataType::Number(precision, scale) => match (precision, scale) {
+                (Some(precision), Some(scale)) => write!(f, "NUMBER({},{})", precision, scale),
+                (None, Some(scale)) => write!(f, "NUMBER(*,{})", scale),
+                (_, None) => format_type_with_optional_length(f, "NUMBER", precision, false),
+            },
+            DataType::Bit(size) => format_type_with_optional_length(f, "BIT", size, false),
+            DataType::BitVarying(size) => {
+                format_type_with_optional_length(f, "BIT VARYING", size, false)
+            }
             DataType::Real => write!(f, "REAL"),
             DataType::Double => write!(f, "DOUBLE"),
+            DataType::DoublePrecision => write!(f, "DOUBLE PRECISION"),
             DataType::Boolean => write!(f, "BOOLEAN"),
             DataType::Date => write!(f, "DATE"),
-            DataType::Time => write!(f, "TIME"),
-            DataType::Timestamp(n) => format_type_with_optional_length(f, "TIMESTAMP", n, false),
+            DataType::Time(precision, timezone) => {
+                format_datetime_precision_and_tz(f, "TIME", precision, timezone)
+            }
+            DataType::Timestamp(precision, timezone) => {
+                format_datetime_precision_and_tz(f, "TIMESTAMP", precision, timezone)
+            }
             DataType::Interval => write!(f, "INTERVAL"),
             DataType::Regclass => write!(f, "REGCLASS"),
             DataType::Text => write!(f, "TEXT"),
+            DataType::TinyText => write!(f, "TINYTEXT"),
+            DataType::MediumText => write!(f, "MEDIUMTEXT"),
+            DataType::LongText => write!(f, "LONGTEXT"),
+            DataType::TinyBlob => write!(f, "TINYBLOB"),
+            DataType::MediumBlob => write!(f, "MEDIUMBLOB"),
+            DataType::LongBlob => write!(f, "LONGBLOB"),
             DataType::String => write!(f, "STRING"),
             DataType::Bytea => write!(f, "BYTEA"),
+            DataType::Variant => write!(f, "VARIANT"),
+            DataType::Object => write!(f, "OBJECT"),
+            DataType::Json => write!(f, "JSON"),
+            DataType::Jsonb => write!(f, "JSONB"),
+            DataType::Geometry => write!(f, "GEOMETRY"),
+            DataType::Inet => write!(f, "INET"),
+            DataType::Money => write!(f, "MONEY"),
+            DataType::Enum(members) => write!(f, "ENUM({})", display_comma_separated(members)),
+            DataType::Enum8(members) => {
+                write!(f, "Enum8({})", display_comma_separated(members))
+            }
+            DataType::Enum16(members) => {
+                write!(f, "Enum16({})", display_comma_separated(members))
+            }
+            DataType::Set(values) => format_string_values(f, "SET", values),
+            DataType::Map(key, value) => write!(f, "MAP({}, {})", key, value),
+            DataType::Struct(fields) => {
+                write!(f, "STRUCT<{}>", display_comma_separated(fields))
+            }
             DataType::Array(ty, nullable) => {
                 if *nullable {
                     write!(f, "ARRAY({} NULL)", ty)
@@ -150,16 +322,82 @@ impl fmt::Display for DataType {
                     write!(f, "ARRAY({})", ty)
                 }
             }
-            DataType::Custom(ty) => write!(f, "{}", ty),
+            DataType::BracketArray(ty, size) => match size {
+                Some(size) => write!(f, "{}[{}]", ty, size),
+                None => write!(f, "{}[]", ty),
+            },
+            DataType::Custom(ty, modifiers) => {
+                if modifiers.is_empty() {
+                    write!(f, "{}", ty)
+                } else {
+                    write!(f, "{}({})", ty, modifiers.join(", "))
+                }
+            }
+            DataType::CharacterSet(ty, charset) => write!(f, "{} CHARACTER SET {}", ty, charset),
             DataType::DateTime(n) => format_type_with_optional_length(f, "DATETIME", n, false),
             DataType::Tuple(names, types) => match names {
                 Some(names) => format_named_tuple(f, names, types),
                 None => format_tuple(f, types),
             },
+            DataType::Nullable(ty) => write!(f, "Nullable({})", ty),
+            DataType::LowCardinality(ty) => write!(f, "LowCardinality({})", ty),
+            DataType::Nested(fields) => write!(f, "Nested({})", display_comma_separated(fields)),
+            DataType::FixedString(len) => write!(f, "FixedString({})", len),
+            DataType::Decimal32(scale) => write!(f, "Decimal32({})", scale),
+            DataType::Decimal64(scale) => write!(f, "Decimal64({})", scale),
+            DataType::Decimal128(scale) => write!(f, "Decimal128({})", scale),
+            DataType::Decimal256(scale) => write!(f, "Decimal256({})", scale),
+            DataType::DateTime64(precision, time_zone) => match time_zone {
+                Some(tz) => write!(
+                    f,
+                    "DateTime64({}, '{}')",
+                    precision,
+                    escape_single_quote_string(tz)
+                ),
+                None => write!(f, "DateTime64({})", precision),
+            },
+            DataType::Unspecified => Ok(()),
         }
     }
 }
 
+/// A field of a struct type e.g. `a INT64` in BigQuery `STRUCT<a INT64>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StructField {
+    /// Fields of anonymous struct types have no name e.g. `STRUCT<INT64>`
+    pub field_name: Option<Ident>,
+    pub field_type: DataType,
+}
+
+impl fmt::Display for StructField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(name) = &self.field_name {
+            write!(f, "{} ", name)?;
+        }
+        write!(f, "{}", self.field_type)
+    }
+}
+
+/// A member of an enumeration type, with an explicit value in ClickHouse
+/// e.g. `'a'` or `'a' = 1`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnumMember {
+    pub name: String,
+    pub value: Option<i64>,
+}
+
+impl fmt::Display for EnumMember {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "'{}'", escape_single_quote_string(&self.name))?;
+        if let Some(value) = self.value {
+            write!(f, " = {}", value)?;
+        }
+        Ok(())
+    }
+}
+
 fn format_type_with_optional_length(
     f: &mut fmt::Formatter,
     sql_type: &'static str,
@@ -176,6 +414,58 @@ fn format_type_with_optional_length(
     Ok(())
 }
 
+fn format_character_type(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    len: &Option<CharacterLength>,
+) -> fmt::Result {
+    write!(f, "{}", sql_type)?;
+    if let Some(len) = len {
+        write!(f, "({})", len)?;
+    }
+    Ok(())
+}
+
+fn format_string_values(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    values: &[String],
+) -> fmt::Result {
+    write!(f, "{}(", sql_type)?;
+    let mut first = true;
+    for value in values {
+        if !first {
+            write!(f, ", ")?;
+        }
+        first = false;
+        write!(f, "'{}'", escape_single_quote_string(value))?;
+    }
+    write!(f, ")")
+}
+
+fn format_datetime_precision_and_tz(
+    f: &mut fmt::Formatter,
+    sql_type: &'static str,
+    precision: &Option<u64>,
+    timezone: &TimezoneInfo,
+) -> fmt::Result {
+    // the abbreviated forms attach the time zone to the type name itself
+    match timezone {
+        TimezoneInfo::Tz => write!(f, "{}TZ", sql_type)?,
+        TimezoneInfo::LocalTz => write!(f, "{}_LTZ", sql_type)?,
+        TimezoneInfo::NoTz => write!(f, "{}_NTZ", sql_type)?,
+        _ => write!(f, "{}", sql_type)?,
+    }
+    if let Some(precision) = precision {
+        write!(f, "({})", precision)?;
+    }
+    match timezone {
+        TimezoneInfo::WithTimeZone => write!(f, " WITH TIME ZONE"),
+        TimezoneInfo::WithoutTimeZone => write!(f, " WITHOUT TIME ZONE"),
+        _ => Ok(()),
+    }
+}
+
 fn format_tuple(f: &mut fmt::Formatter, types: &[Box<DataType>]) -> fmt::Result {
     write!(f, "TUPLE(")?;
     let mut first = true;
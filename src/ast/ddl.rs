@@ -14,13 +14,16 @@
 //! (commonly referred to as Data Definition Language, or DDL)
 
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::ast::{display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName};
+use crate::ast::value::escape_single_quote_string;
+use crate::ast::{
+    display_comma_separated, display_separated, DataType, Expr, Ident, ObjectName, SqlOption,
+};
 use crate::tokenizer::Token;
 
 /// An `ALTER TABLE` (`Statement::AlterTable`) operation
@@ -29,8 +32,12 @@ use crate::tokenizer::Token;
 pub enum AlterTableOperation {
     /// `ADD <table_constraint>`
     AddConstraint(TableConstraint),
-    /// `ADD [ COLUMN ] <column_def>`
-    AddColumn { column_def: ColumnDef },
+    /// `ADD [ COLUMN ] <column_def> [ FIRST | AFTER <column_name> ]`
+    AddColumn {
+        column_def: ColumnDef,
+        /// MySQL column position
+        position: Option<ColumnPosition>,
+    },
     /// TODO: implement `DROP CONSTRAINT <name>`
     DropConstraint { name: Ident },
     /// `DROP [ COLUMN ] [ IF EXISTS ] <column_name> [ CASCADE ]`
@@ -60,13 +67,41 @@ pub enum AlterTableOperation {
     },
     /// `RENAME TO <table_name>`
     RenameTable { table_name: ObjectName },
-    // CHANGE [ COLUMN ] <old_name> <new_name> <data_type> [ <options> ]
+    // CHANGE [ COLUMN ] <old_name> <new_name> <data_type> [ <options> ] [ FIRST | AFTER <column_name> ]
     ChangeColumn {
         old_name: Ident,
         new_name: Ident,
         data_type: DataType,
         options: Vec<ColumnOption>,
+        /// MySQL column position
+        position: Option<ColumnPosition>,
+    },
+    /// MySQL `MODIFY [ COLUMN ] <column_def> [ FIRST | AFTER <column_name> ]`
+    ModifyColumn {
+        column_def: ColumnDef,
+        position: Option<ColumnPosition>,
+    },
+    /// `ALTER [ COLUMN ] <column_name> <op>`
+    AlterColumn {
+        column_name: Ident,
+        op: AlterColumnOperation,
     },
+    /// MySQL `RENAME { INDEX | KEY } <old_name> TO <new_name>`
+    RenameIndex { old_name: Ident, new_name: Ident },
+    /// PostgreSQL `SET SCHEMA <schema_name>`
+    SetSchema { schema_name: ObjectName },
+    /// PostgreSQL `OWNER TO <new_owner>`
+    OwnerTo { new_owner: Ident },
+    /// PostgreSQL `ENABLE TRIGGER { <name> | ALL | USER }`
+    EnableTrigger { name: Ident },
+    /// PostgreSQL `DISABLE TRIGGER { <name> | ALL | USER }`
+    DisableTrigger { name: Ident },
+    /// Hive `SET TBLPROPERTIES (<name> = <value> [, ...])`
+    SetTblProperties { table_properties: Vec<SqlOption> },
+    /// Snowflake `CLUSTER BY (<expr> [, ...])`
+    ClusterBy { exprs: Vec<Expr> },
+    /// Snowflake `SWAP WITH <table_name>`
+    SwapWith { table_name: ObjectName },
 }
 
 impl fmt::Display for AlterTableOperation {
@@ -82,8 +117,15 @@ impl fmt::Display for AlterTableOperation {
                 ine = if *if_not_exists { " IF NOT EXISTS" } else { "" }
             ),
             AlterTableOperation::AddConstraint(c) => write!(f, "ADD {}", c),
-            AlterTableOperation::AddColumn { column_def } => {
-                write!(f, "ADD COLUMN {}", column_def)
+            AlterTableOperation::AddColumn {
+                column_def,
+                position,
+            } => {
+                write!(f, "ADD COLUMN {}", column_def)?;
+                if let Some(position) = position {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
             }
             AlterTableOperation::DropPartitions {
                 partitions,
@@ -131,14 +173,120 @@ impl fmt::Display for AlterTableOperation {
                 new_name,
                 data_type,
                 options,
+                position,
             } => {
                 write!(f, "CHANGE COLUMN {} {} {}", old_name, new_name, data_type)?;
-                if options.is_empty() {
-                    Ok(())
-                } else {
-                    write!(f, " {}", display_separated(options, " "))
+                if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                if let Some(position) = position {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
+            }
+            AlterTableOperation::ModifyColumn {
+                column_def,
+                position,
+            } => {
+                write!(f, "MODIFY COLUMN {}", column_def)?;
+                if let Some(position) = position {
+                    write!(f, " {}", position)?;
+                }
+                Ok(())
+            }
+            AlterTableOperation::AlterColumn { column_name, op } => {
+                write!(f, "ALTER COLUMN {} {}", column_name, op)
+            }
+            AlterTableOperation::RenameIndex { old_name, new_name } => {
+                write!(f, "RENAME INDEX {} TO {}", old_name, new_name)
+            }
+            AlterTableOperation::SetSchema { schema_name } => {
+                write!(f, "SET SCHEMA {}", schema_name)
+            }
+            AlterTableOperation::OwnerTo { new_owner } => write!(f, "OWNER TO {}", new_owner),
+            AlterTableOperation::EnableTrigger { name } => write!(f, "ENABLE TRIGGER {}", name),
+            AlterTableOperation::DisableTrigger { name } => write!(f, "DISABLE TRIGGER {}", name),
+            AlterTableOperation::SetTblProperties { table_properties } => write!(
+                f,
+                "SET TBLPROPERTIES ({})",
+                display_comma_separated(table_properties)
+            ),
+            AlterTableOperation::ClusterBy { exprs } => {
+                write!(f, "CLUSTER BY ({})", display_comma_separated(exprs))
+            }
+            AlterTableOperation::SwapWith { table_name } => write!(f, "SWAP WITH {}", table_name),
+        }
+    }
+}
+
+/// An `ALTER COLUMN` (`AlterTableOperation::AlterColumn`) operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AlterColumnOperation {
+    /// `SET NOT NULL`
+    SetNotNull,
+    /// `DROP NOT NULL`
+    DropNotNull,
+    /// `SET DEFAULT <expr>`
+    SetDefault { value: Expr },
+    /// `DROP DEFAULT`
+    DropDefault,
+    /// `[ SET DATA ] TYPE <data_type> [ USING <expr> ]`
+    SetDataType {
+        data_type: DataType,
+        /// PostgreSQL expression computing the new value from the old one
+        using: Option<Expr>,
+    },
+    /// PostgreSQL `ADD GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <options> ) ]`
+    AddGenerated {
+        generated_as: GeneratedAs,
+        sequence_options: Vec<SequenceOptions>,
+    },
+}
+
+impl fmt::Display for AlterColumnOperation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlterColumnOperation::SetNotNull => write!(f, "SET NOT NULL"),
+            AlterColumnOperation::DropNotNull => write!(f, "DROP NOT NULL"),
+            AlterColumnOperation::SetDefault { value } => write!(f, "SET DEFAULT {}", value),
+            AlterColumnOperation::DropDefault => write!(f, "DROP DEFAULT"),
+            AlterColumnOperation::SetDataType { data_type, using } => {
+                write!(f, "SET DATA TYPE {}", data_type)?;
+                if let Some(expr) = using {
+                    write!(f, " USING {}", expr)?;
                 }
+                Ok(())
             }
+            AlterColumnOperation::AddGenerated {
+                generated_as,
+                sequence_options,
+            } => {
+                write!(f, "ADD GENERATED {} AS IDENTITY", generated_as)?;
+                if !sequence_options.is_empty() {
+                    write!(f, " ({})", display_separated(sequence_options, " "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The position of a column added or modified by MySQL `ALTER TABLE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ColumnPosition {
+    /// `FIRST`
+    First,
+    /// `AFTER <column_name>`
+    After(Ident),
+}
+
+impl fmt::Display for ColumnPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnPosition::First => write!(f, "FIRST"),
+            ColumnPosition::After(column_name) => write!(f, "AFTER {}", column_name),
         }
     }
 }
@@ -148,12 +296,19 @@ impl fmt::Display for AlterTableOperation {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TableConstraint {
-    /// `[ CONSTRAINT <name> ] { PRIMARY KEY | UNIQUE } (<columns>)`
+    /// `[ CONSTRAINT <name> ] { PRIMARY KEY | UNIQUE [ KEY | INDEX ] [ <index_name> ] }
+    /// (<key_parts>) [ USING { BTREE | HASH } ]`
     Unique {
         name: Option<Ident>,
-        columns: Vec<Ident>,
+        /// MySQL index name e.g. `UNIQUE KEY <index_name> (<key_parts>)`
+        index_name: Option<Ident>,
+        /// Whether the MySQL `KEY` or `INDEX` keyword followed `UNIQUE`
+        index_type_display: KeyOrIndexDisplay,
+        columns: Vec<KeyPart>,
         /// Whether this is a `PRIMARY KEY` or just a `UNIQUE` constraint
         is_primary: bool,
+        /// MySQL `USING { BTREE | HASH }`
+        index_type: Option<IndexType>,
     },
     /// A referential integrity constraint (`[ CONSTRAINT <name> ] FOREIGN KEY (<columns>)
     /// REFERENCES <foreign_table> (<referred_columns>)
@@ -173,6 +328,23 @@ pub enum TableConstraint {
         name: Option<Ident>,
         expr: Box<Expr>,
     },
+    /// MySQL `{ INDEX | KEY } [ <index_name> ] (<key_parts>) [ USING { BTREE | HASH } ]`,
+    /// an index which is not a constraint
+    Index {
+        /// Whether the index was declared with `KEY` or `INDEX`
+        index_type_display: KeyOrIndexDisplay,
+        index_name: Option<Ident>,
+        columns: Vec<KeyPart>,
+        index_type: Option<IndexType>,
+    },
+    /// MySQL `{ FULLTEXT | SPATIAL } [ INDEX | KEY ] [ <index_name> ] (<key_parts>)`
+    FulltextOrSpatial {
+        /// Whether this is a `FULLTEXT` rather than a `SPATIAL` index
+        fulltext: bool,
+        index_type_display: KeyOrIndexDisplay,
+        index_name: Option<Ident>,
+        columns: Vec<KeyPart>,
+    },
 }
 
 impl fmt::Display for TableConstraint {
@@ -180,15 +352,30 @@ impl fmt::Display for TableConstraint {
         match self {
             TableConstraint::Unique {
                 name,
+                index_name,
+                index_type_display,
                 columns,
                 is_primary,
-            } => write!(
-                f,
-                "{}{} ({})",
-                display_constraint_name(name),
-                if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
-                display_comma_separated(columns)
-            ),
+                index_type,
+            } => {
+                write!(
+                    f,
+                    "{}{}",
+                    display_constraint_name(name),
+                    if *is_primary { "PRIMARY KEY" } else { "UNIQUE" },
+                )?;
+                if !index_type_display.is_none() {
+                    write!(f, " {}", index_type_display)?;
+                }
+                if let Some(index_name) = index_name {
+                    write!(f, " {}", index_name)?;
+                }
+                write!(f, " ({})", display_comma_separated(columns))?;
+                if let Some(index_type) = index_type {
+                    write!(f, " USING {}", index_type)?;
+                }
+                Ok(())
+            }
             TableConstraint::ForeignKey {
                 name,
                 columns,
@@ -216,6 +403,106 @@ impl fmt::Display for TableConstraint {
             TableConstraint::Check { name, expr } => {
                 write!(f, "{}CHECK ({})", display_constraint_name(name), expr)
             }
+            TableConstraint::Index {
+                index_type_display,
+                index_name,
+                columns,
+                index_type,
+            } => {
+                write!(f, "{}", index_type_display)?;
+                if let Some(index_name) = index_name {
+                    write!(f, " {}", index_name)?;
+                }
+                write!(f, " ({})", display_comma_separated(columns))?;
+                if let Some(index_type) = index_type {
+                    write!(f, " USING {}", index_type)?;
+                }
+                Ok(())
+            }
+            TableConstraint::FulltextOrSpatial {
+                fulltext,
+                index_type_display,
+                index_name,
+                columns,
+            } => {
+                write!(f, "{}", if *fulltext { "FULLTEXT" } else { "SPATIAL" })?;
+                if !index_type_display.is_none() {
+                    write!(f, " {}", index_type_display)?;
+                }
+                if let Some(index_name) = index_name {
+                    write!(f, " {}", index_name)?;
+                }
+                write!(f, " ({})", display_comma_separated(columns))
+            }
+        }
+    }
+}
+
+/// Which of MySQL's interchangeable `KEY` and `INDEX` keywords, if any, was
+/// used in an index definition e.g. `UNIQUE KEY` or `FULLTEXT INDEX`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum KeyOrIndexDisplay {
+    None,
+    Key,
+    Index,
+}
+
+impl KeyOrIndexDisplay {
+    pub fn is_none(self) -> bool {
+        matches!(self, KeyOrIndexDisplay::None)
+    }
+}
+
+impl fmt::Display for KeyOrIndexDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyOrIndexDisplay::None => Ok(()),
+            KeyOrIndexDisplay::Key => write!(f, "KEY"),
+            KeyOrIndexDisplay::Index => write!(f, "INDEX"),
+        }
+    }
+}
+
+/// A column of a MySQL index definition, with an optional prefix length and
+/// sort order e.g. `name(10) DESC`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyPart {
+    pub column: Ident,
+    /// The number of leading characters indexed e.g. `name(10)`
+    pub length: Option<u64>,
+    /// Optional `ASC` or `DESC`
+    pub asc: Option<bool>,
+}
+
+impl fmt::Display for KeyPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.column)?;
+        if let Some(length) = self.length {
+            write!(f, "({})", length)?;
+        }
+        match self.asc {
+            Some(true) => write!(f, " ASC"),
+            Some(false) => write!(f, " DESC"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// MySQL index storage structure, `USING { BTREE | HASH }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IndexType {
+    BTree,
+    Hash,
+}
+
+impl fmt::Display for IndexType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndexType::BTree => write!(f, "BTREE"),
+            IndexType::Hash => write!(f, "HASH"),
         }
     }
 }
@@ -232,7 +519,13 @@ pub struct ColumnDef {
 
 impl fmt::Display for ColumnDef {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self.name, self.data_type)?;
+        write!(f, "{}", self.name)?;
+        if self.data_type != DataType::Unspecified {
+            write!(f, " {}", self.data_type)?;
+        }
+        if let Some(collation) = &self.collation {
+            write!(f, " COLLATE {}", collation)?;
+        }
         for option in &self.options {
             write!(f, " {}", option)?;
         }
@@ -295,9 +588,37 @@ pub enum ColumnOption {
     },
     /// `CHECK (<expr>)`
     Check(Expr),
-    /// Dialect-specific options, such as:
-    /// - MySQL's `AUTO_INCREMENT` or SQLite's `AUTOINCREMENT`
-    /// - ...
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <sequence_options> ) ]`
+    Identity {
+        generated_as: GeneratedAs,
+        sequence_options: Vec<SequenceOptions>,
+    },
+    /// Generated column `GENERATED ALWAYS AS (<expr>) [ STORED | VIRTUAL ]`, or
+    /// the MySQL and MSSQL computed column `AS (<expr>) [ STORED | VIRTUAL | PERSISTED ]`
+    Generated {
+        /// Whether the option starts with `GENERATED ALWAYS`
+        generated_keyword: bool,
+        expr: Expr,
+        mode: Option<GeneratedColumnMode>,
+    },
+    /// MySQL `ON UPDATE <expr>` e.g. `ON UPDATE CURRENT_TIMESTAMP`
+    OnUpdate(Expr),
+    /// `COMMENT '<comment>'`
+    Comment(String),
+    /// MySQL `AUTO_INCREMENT`
+    AutoIncrement,
+    /// SQLite `AUTOINCREMENT`
+    Autoincrement,
+    /// Snowflake `MASKING POLICY <policy> [ USING (<columns>) ]`
+    MaskingPolicy {
+        policy: ObjectName,
+        using_columns: Vec<Ident>,
+    },
+    /// ClickHouse compression codecs e.g. `CODEC(Delta, ZSTD(3))`
+    Codec(Vec<Expr>),
+    /// ClickHouse column time to live e.g. `TTL d + INTERVAL 1 DAY`
+    Ttl(Expr),
+    /// Other dialect-specific options
     DialectSpecific(Vec<Token>),
 }
 
@@ -330,11 +651,334 @@ impl fmt::Display for ColumnOption {
                 Ok(())
             }
             Check(expr) => write!(f, "CHECK ({})", expr),
+            Identity {
+                generated_as,
+                sequence_options,
+            } => {
+                write!(f, "GENERATED {} AS IDENTITY", generated_as)?;
+                if !sequence_options.is_empty() {
+                    write!(f, " ({})", display_separated(sequence_options, " "))?;
+                }
+                Ok(())
+            }
+            Generated {
+                generated_keyword,
+                expr,
+                mode,
+            } => {
+                if *generated_keyword {
+                    write!(f, "GENERATED ALWAYS ")?;
+                }
+                write!(f, "AS ({})", expr)?;
+                if let Some(mode) = mode {
+                    write!(f, " {}", mode)?;
+                }
+                Ok(())
+            }
+            OnUpdate(expr) => write!(f, "ON UPDATE {}", expr),
+            Comment(comment) => write!(f, "COMMENT '{}'", escape_single_quote_string(comment)),
+            AutoIncrement => write!(f, "AUTO_INCREMENT"),
+            Autoincrement => write!(f, "AUTOINCREMENT"),
+            MaskingPolicy {
+                policy,
+                using_columns,
+            } => {
+                write!(f, "MASKING POLICY {}", policy)?;
+                if !using_columns.is_empty() {
+                    write!(f, " USING ({})", display_comma_separated(using_columns))?;
+                }
+                Ok(())
+            }
+            Codec(codecs) => write!(f, "CODEC({})", display_comma_separated(codecs)),
+            Ttl(expr) => write!(f, "TTL {}", expr),
             DialectSpecific(val) => write!(f, "{}", display_separated(val, " ")),
         }
     }
 }
 
+/// `PARTITION BY <method> ( <expr> [, ...] )` of a partitioned table, followed
+/// in MySQL by an optional `PARTITIONS <n>` and list of partition definitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionBy {
+    pub method: PartitionMethod,
+    /// The partition key columns or expressions
+    pub columns: Vec<Expr>,
+    /// MySQL `PARTITIONS <n>`
+    pub partitions: Option<u64>,
+    /// MySQL `( PARTITION <name> VALUES ... [, ...] )`
+    pub definitions: Vec<PartitionDefinition>,
+}
+
+impl fmt::Display for PartitionBy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.method,
+            display_comma_separated(&self.columns)
+        )?;
+        if let Some(partitions) = self.partitions {
+            write!(f, " PARTITIONS {}", partitions)?;
+        }
+        if !self.definitions.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.definitions))?;
+        }
+        Ok(())
+    }
+}
+
+/// A MySQL partition definition, `PARTITION <name> [ VALUES ... ] [ <option> ... ]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionDefinition {
+    pub name: Ident,
+    pub values: Option<PartitionValues>,
+    /// Options such as `ENGINE=InnoDB` or `COMMENT='...'`
+    pub options: Vec<TableOption>,
+}
+
+impl fmt::Display for PartitionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PARTITION {}", self.name)?;
+        if let Some(values) = &self.values {
+            write!(f, " VALUES {}", values)?;
+        }
+        for option in &self.options {
+            write!(f, " {}", option)?;
+        }
+        Ok(())
+    }
+}
+
+/// The values held by a MySQL partition, after `VALUES`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionValues {
+    /// `LESS THAN (<expr> [, ...])` of a range partition
+    LessThan(Vec<Expr>),
+    /// `LESS THAN MAXVALUE`
+    LessThanMaxValue,
+    /// `IN (<expr> [, ...])` of a list partition
+    In(Vec<Expr>),
+}
+
+impl fmt::Display for PartitionValues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionValues::LessThan(values) => {
+                write!(f, "LESS THAN ({})", display_comma_separated(values))
+            }
+            PartitionValues::LessThanMaxValue => write!(f, "LESS THAN MAXVALUE"),
+            PartitionValues::In(values) => write!(f, "IN ({})", display_comma_separated(values)),
+        }
+    }
+}
+
+/// A MySQL table option following the column list of `CREATE TABLE`, e.g.
+/// `ENGINE=InnoDB`. The optional `=` is always displayed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableOption {
+    /// `ENGINE=<engine>`
+    Engine(Ident),
+    /// `AUTO_INCREMENT=<value>`
+    AutoIncrement(u64),
+    /// `[ DEFAULT ] { CHARSET | CHARACTER SET }=<charset>`
+    CharacterSet { default: bool, name: Ident },
+    /// `[ DEFAULT ] COLLATE=<collation>`
+    Collate { default: bool, name: Ident },
+    /// `ROW_FORMAT=<format>`
+    RowFormat(Ident),
+    /// `COMMENT='<comment>'`
+    Comment(String),
+    /// Any other `<name>=<value>` option e.g. `KEY_BLOCK_SIZE=8`
+    Other { name: Ident, value: Expr },
+}
+
+impl fmt::Display for TableOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableOption::Engine(engine) => write!(f, "ENGINE={}", engine),
+            TableOption::AutoIncrement(value) => write!(f, "AUTO_INCREMENT={}", value),
+            TableOption::CharacterSet { default, name } => write!(
+                f,
+                "{}CHARSET={}",
+                if *default { "DEFAULT " } else { "" },
+                name
+            ),
+            TableOption::Collate { default, name } => write!(
+                f,
+                "{}COLLATE={}",
+                if *default { "DEFAULT " } else { "" },
+                name
+            ),
+            TableOption::RowFormat(format) => write!(f, "ROW_FORMAT={}", format),
+            TableOption::Comment(comment) => {
+                write!(f, "COMMENT='{}'", escape_single_quote_string(comment))
+            }
+            TableOption::Other { name, value } => write!(f, "{}={}", name, value),
+        }
+    }
+}
+
+/// The method used to assign rows to partitions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionMethod {
+    Range,
+    List,
+    Hash,
+    /// MySQL `RANGE COLUMNS`
+    RangeColumns,
+    /// MySQL `LIST COLUMNS`
+    ListColumns,
+    /// MySQL `KEY`
+    Key,
+}
+
+impl fmt::Display for PartitionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionMethod::Range => write!(f, "RANGE"),
+            PartitionMethod::List => write!(f, "LIST"),
+            PartitionMethod::Hash => write!(f, "HASH"),
+            PartitionMethod::RangeColumns => write!(f, "RANGE COLUMNS"),
+            PartitionMethod::ListColumns => write!(f, "LIST COLUMNS"),
+            PartitionMethod::Key => write!(f, "KEY"),
+        }
+    }
+}
+
+/// PostgreSQL `PARTITION OF <parent> { FOR VALUES <bound> | DEFAULT }` of a
+/// table which is a partition of another
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PartitionOf {
+    pub parent: ObjectName,
+    pub bound: PartitionBound,
+}
+
+/// The values of the partition key which a partition holds
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartitionBound {
+    /// `FOR VALUES IN (<expr> [, ...])` of a list partition
+    In(Vec<Expr>),
+    /// `FOR VALUES FROM (<expr> [, ...]) TO (<expr> [, ...])` of a range
+    /// partition, where a bound may also be `MINVALUE` or `MAXVALUE`
+    FromTo { from: Vec<Expr>, to: Vec<Expr> },
+    /// `FOR VALUES WITH (MODULUS <modulus>, REMAINDER <remainder>)` of a hash partition
+    With { modulus: u64, remainder: u64 },
+    /// `DEFAULT`
+    Default,
+}
+
+impl fmt::Display for PartitionBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionBound::In(values) => {
+                write!(f, "FOR VALUES IN ({})", display_comma_separated(values))
+            }
+            PartitionBound::FromTo { from, to } => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                display_comma_separated(from),
+                display_comma_separated(to)
+            ),
+            PartitionBound::With { modulus, remainder } => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {}, REMAINDER {})",
+                modulus, remainder
+            ),
+            PartitionBound::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+/// How the value of a generated column is stored
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GeneratedColumnMode {
+    Stored,
+    Virtual,
+    /// MSSQL `PERSISTED`
+    Persisted,
+}
+
+impl fmt::Display for GeneratedColumnMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneratedColumnMode::Stored => write!(f, "STORED"),
+            GeneratedColumnMode::Virtual => write!(f, "VIRTUAL"),
+            GeneratedColumnMode::Persisted => write!(f, "PERSISTED"),
+        }
+    }
+}
+
+/// Whether an identity column always takes its value from the sequence
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GeneratedAs {
+    Always,
+    ByDefault,
+}
+
+impl fmt::Display for GeneratedAs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeneratedAs::Always => write!(f, "ALWAYS"),
+            GeneratedAs::ByDefault => write!(f, "BY DEFAULT"),
+        }
+    }
+}
+
+/// An option of `CREATE SEQUENCE`, `ALTER SEQUENCE` or an identity column
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SequenceOptions {
+    /// `INCREMENT [ BY ] <increment>`
+    IncrementBy(Expr),
+    /// `MINVALUE <minvalue> | NO MINVALUE`
+    MinValue(Option<Expr>),
+    /// `MAXVALUE <maxvalue> | NO MAXVALUE`
+    MaxValue(Option<Expr>),
+    /// `START [ WITH ] <start>`
+    StartWith(Expr),
+    /// `RESTART [ [ WITH ] <restart> ]`, only in `ALTER SEQUENCE`
+    Restart(Option<Expr>),
+    /// `CACHE <cache>`
+    Cache(Expr),
+    /// `[ NO ] CYCLE`
+    Cycle(bool),
+    /// `OWNED BY { <table>.<column> | NONE }`
+    OwnedBy(Option<ObjectName>),
+    /// `SEQUENCE NAME <name>`, only for identity columns
+    SequenceName(ObjectName),
+}
+
+impl fmt::Display for SequenceOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SequenceOptions::IncrementBy(increment) => write!(f, "INCREMENT BY {}", increment),
+            SequenceOptions::MinValue(Some(value)) => write!(f, "MINVALUE {}", value),
+            SequenceOptions::MinValue(None) => write!(f, "NO MINVALUE"),
+            SequenceOptions::MaxValue(Some(value)) => write!(f, "MAXVALUE {}", value),
+            SequenceOptions::MaxValue(None) => write!(f, "NO MAXVALUE"),
+            SequenceOptions::StartWith(start) => write!(f, "START WITH {}", start),
+            SequenceOptions::Restart(Some(restart)) => write!(f, "RESTART WITH {}", restart),
+            SequenceOptions::Restart(None) => write!(f, "RESTART"),
+            SequenceOptions::Cache(cache) => write!(f, "CACHE {}", cache),
+            SequenceOptions::Cycle(cycle) => {
+                write!(f, "{}CYCLE", if *cycle { "" } else { "NO " })
+            }
+            SequenceOptions::OwnedBy(Some(column)) => write!(f, "OWNED BY {}", column),
+            SequenceOptions::OwnedBy(None) => write!(f, "OWNED BY NONE"),
+            SequenceOptions::SequenceName(name) => write!(f, "SEQUENCE NAME {}", name),
+        }
+    }
+}
+
 fn display_constraint_name(name: &'_ Option<Ident>) -> impl fmt::Display + '_ {
     struct ConstraintName<'a>(&'a Option<Ident>);
     impl<'a> fmt::Display for ConstraintName<'a> {
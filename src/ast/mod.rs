@@ -29,18 +29,24 @@ use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-pub use self::data_type::DataType;
+pub use self::data_type::{CharacterLength, DataType, EnumMember, StructField, TimezoneInfo};
 pub use self::ddl::{
-    AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef, ReferentialAction,
-    TableConstraint,
+    AlterColumnOperation, AlterTableOperation, ColumnDef, ColumnOption, ColumnOptionDef,
+    ColumnPosition, GeneratedAs, GeneratedColumnMode, IndexType, KeyOrIndexDisplay, KeyPart,
+    PartitionBound, PartitionBy, PartitionDefinition, PartitionMethod, PartitionOf,
+    PartitionValues, ReferentialAction, SequenceOptions, TableConstraint, TableOption,
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
-    Cte, Fetch, Instant, Join, JoinConstraint, JoinOperator, LateralView, Offset, OffsetRows,
-    OrderByExpr, Query, Select, SelectItem, SetExpr, SetOperator, StreamSlice, TableAlias,
-    TableFactor, TableWithJoins, Top, Values, With,
+    AfterMatchSkip, Cte, EmptyMatchesMode, Fetch, Instant, InstantPoint, Join, JoinConstraint,
+    JoinOperator, LateralView, MatchRecognizePattern, MatchRecognizeSymbol, Measure, Offset,
+    OffsetRows, OrderByExpr, Query, RepetitionQuantifier, RowsPerMatch, Select, SelectItem,
+    SetExpr, SetOperator, StreamSlice, SymbolDefinition, TableAlias, TableAliasColumnDef,
+    TableFactor, TableSample, TableSampleKind, TableSampleMethod, TableSampleModifier,
+    TableSampleSeed, TableSampleSeedModifier, TableSampleSize, TableSampleUnit, TableWithJoins,
+    Top, Values, With,
 };
-pub use self::value::{DateTimeField, TrimWhereField, Value};
+pub use self::value::{DateTimeField, DollarQuotedString, TrimWhereField, Value};
 
 struct DisplaySeparated<'a, T>
 where
@@ -163,6 +169,24 @@ pub enum Expr {
     IsNull(Box<Expr>),
     /// `IS NOT NULL` operator
     IsNotNull(Box<Expr>),
+    /// `IS TRUE` operator
+    IsTrue(Box<Expr>),
+    /// `IS NOT TRUE` operator
+    IsNotTrue(Box<Expr>),
+    /// `IS FALSE` operator
+    IsFalse(Box<Expr>),
+    /// `IS NOT FALSE` operator
+    IsNotFalse(Box<Expr>),
+    /// `IS UNKNOWN` operator
+    IsUnknown(Box<Expr>),
+    /// `IS NOT UNKNOWN` operator
+    IsNotUnknown(Box<Expr>),
+    /// `IS [ NOT ] [ <normal form> ] NORMALIZED` operator
+    IsNormalized {
+        expr: Box<Expr>,
+        form: Option<NormalizationForm>,
+        negated: bool,
+    },
     /// `IS DISTINCT FROM` operator
     IsDistinctFrom(Box<Expr>, Box<Expr>),
     /// `IS NOT DISTINCT FROM` operator
@@ -186,12 +210,54 @@ pub enum Expr {
         low: Box<Expr>,
         high: Box<Expr>,
     },
+    /// `<expr> [ NOT ] { LIKE | ILIKE } <pattern> [ ESCAPE '<escape_char>' ]`
+    Like {
+        negated: bool,
+        /// `ILIKE` rather than `LIKE`
+        case_insensitive: bool,
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        /// A single character, or empty to disable escaping in PostgreSQL
+        escape_char: Option<String>,
+    },
+    /// Snowflake `<expr> [ NOT ] { LIKE | ILIKE } { ANY | ALL } (<pattern>, ...) [ ESCAPE '<escape_char>' ]`
+    LikeQuantified {
+        negated: bool,
+        case_insensitive: bool,
+        expr: Box<Expr>,
+        quantifier: LikeQuantifier,
+        patterns: Vec<Expr>,
+        escape_char: Option<String>,
+    },
+    /// `<expr> [ NOT ] SIMILAR TO <pattern> [ ESCAPE '<escape_char>' ]`
+    SimilarTo {
+        negated: bool,
+        expr: Box<Expr>,
+        pattern: Box<Expr>,
+        escape_char: Option<String>,
+    },
     /// Binary operation e.g. `1 + 1` or `foo > bar`
     BinaryOp {
         left: Box<Expr>,
         op: BinaryOperator,
         right: Box<Expr>,
     },
+    /// Quantified comparison e.g. `foo = ANY (SELECT ...)` or `foo <> SOME ($1)`
+    AnyOp {
+        left: Box<Expr>,
+        compare_op: BinaryOperator,
+        /// A subquery or an array-valued expression
+        right: Box<Expr>,
+        /// `SOME` is a synonym for `ANY`, kept to round-trip the original text
+        is_some: bool,
+    },
+    /// Quantified comparison e.g. `foo > ALL (ARRAY[1, 2, 3])`
+    AllOp {
+        left: Box<Expr>,
+        compare_op: BinaryOperator,
+        /// A subquery or an array-valued expression
+        right: Box<Expr>,
+    },
     /// Unary operation e.g. `NOT foo`
     UnaryOp {
         op: UnaryOperator,
@@ -202,18 +268,56 @@ pub enum Expr {
         expr: Box<Expr>,
         data_type: DataType,
         pg_style: bool,
+        /// Optional format string e.g. `CAST(d AS DATE FORMAT 'YYYY-MM-DD')`
+        format: Option<Box<CastFormat>>,
     },
     /// TRY_CAST an expression to a different data type e.g. `TRY_CAST(foo AS VARCHAR(123))`
     //  this differs from CAST in the choice of how to implement invalid conversions
     TryCast {
         expr: Box<Expr>,
         data_type: DataType,
+        format: Option<Box<CastFormat>>,
+    },
+    /// BigQuery SAFE_CAST e.g. `SAFE_CAST(foo AS INT64)`, which returns NULL
+    /// instead of raising an error when the conversion fails
+    SafeCast {
+        expr: Box<Expr>,
+        data_type: DataType,
+        format: Option<Box<CastFormat>>,
+    },
+    /// CONVERT a value to a different data type or character set e.g.
+    /// MySQL `CONVERT(foo USING utf8mb4)` or MSSQL `CONVERT(VARCHAR(10), foo, 120)`
+    Convert {
+        expr: Box<Expr>,
+        /// The target data type, absent for `CONVERT(expr USING charset)`
+        data_type: Option<DataType>,
+        /// The target character set of `USING`
+        charset: Option<ObjectName>,
+        /// MSSQL puts the target type before the value
+        target_before_value: bool,
+        /// MSSQL style arguments e.g. `120` in `CONVERT(VARCHAR(10), d, 120)`
+        styles: Vec<Expr>,
+    },
+    /// AT TIME ZONE converts a timestamp to the given time zone e.g. `ts AT TIME ZONE 'UTC'`
+    AtTimeZone {
+        timestamp: Box<Expr>,
+        time_zone: Box<Expr>,
     },
     /// EXTRACT(DateTimeField FROM <expr>)
     Extract {
         field: DateTimeField,
         expr: Box<Expr>,
     },
+    /// CEIL(<expr> TO <DateTimeField>)
+    Ceil {
+        expr: Box<Expr>,
+        field: DateTimeField,
+    },
+    /// FLOOR(<expr> TO <DateTimeField>)
+    Floor {
+        expr: Box<Expr>,
+        field: DateTimeField,
+    },
     /// POSITION(<expr> IN <expr>)
     Position {
         substr_expr: Box<Expr>,
@@ -242,6 +346,33 @@ pub enum Expr {
     Nested(Box<Expr>),
     // Tuple expression e.g. `(foo,bar)`
     Tuple(Vec<Expr>),
+    /// Row constructor e.g. `ROW(1, 'a')`
+    Row(Vec<Expr>),
+    /// Struct constructor e.g. `STRUCT(1 AS a, 'x' AS b)` or BigQuery
+    /// `STRUCT<a INT64>(1)` with explicitly typed fields
+    Struct {
+        values: Vec<Expr>,
+        fields: Vec<StructField>,
+    },
+    /// A named value inside a struct constructor e.g. `1 AS a`
+    Named {
+        expr: Box<Expr>,
+        name: Ident,
+    },
+    /// DuckDB struct literal e.g. `{'a': 1, 'b': 'x'}`
+    Dictionary(Vec<DictionaryField>),
+    /// DuckDB map literal e.g. `MAP {'k': v}`
+    Map(Vec<MapEntry>),
+    /// Lambda passed to a higher-order function e.g. `x -> x + 1` or `(k, v) -> v > 0`
+    Lambda {
+        params: Vec<Ident>,
+        body: Box<Expr>,
+    },
+    /// Access of a field of a composite value e.g. `(composite).field`
+    CompositeAccess {
+        expr: Box<Expr>,
+        key: Ident,
+    },
     /// A literal value, such as string, number, date or NULL
     Value(Value),
     /// A constant of form `<data_type> 'value'`.
@@ -251,10 +382,58 @@ pub enum Expr {
         data_type: DataType,
         value: String,
     },
+    /// MySQL full-text search e.g. `MATCH (title, body) AGAINST ('term' IN BOOLEAN MODE)`
+    MatchAgainst {
+        columns: Vec<ObjectName>,
+        match_value: Box<Expr>,
+        search_modifier: Option<SearchModifier>,
+    },
+    /// A string literal with a MySQL character set introducer e.g. `_utf8mb4'abc'`
+    IntroducedString {
+        introducer: String,
+        value: Value,
+    },
+    /// INTERVAL literals, roughly in the following format:
+    /// `INTERVAL '<value>' [ <leading_field> [ (<leading_precision>) ] ]
+    /// [ TO <last_field> [ (<fractional_seconds_precision>) ] ]`,
+    /// e.g. `INTERVAL '123:45.67' MINUTE(3) TO SECOND(2)`.
+    /// MySQL also accepts an unquoted expression e.g. `INTERVAL 1 DAY`.
+    ///
+    /// The parser does not validate the `<value>`, nor does it ensure
+    /// that the `<leading_field>` units >= the units in `<last_field>`,
+    /// so the user will have to reject intervals like `HOUR TO YEAR`.
+    Interval {
+        value: Box<Expr>,
+        leading_field: Option<DateTimeField>,
+        leading_precision: Option<u64>,
+        last_field: Option<DateTimeField>,
+        /// The seconds precision can be specified in SQL source as
+        /// `INTERVAL '__' SECOND(_, x)` (in which case the `leading_field`
+        /// will be `Second` and the `last_field` will be `None`),
+        /// or as `__ TO SECOND(x)`.
+        fractional_seconds_precision: Option<u64>,
+    },
     MapAccess {
         column: Box<Expr>,
         keys: Vec<Value>,
     },
+    /// An array subscript e.g. `arr[1]`; nested subscripts such as
+    /// `arr[1][2]` wrap the inner access
+    ArrayIndex {
+        obj: Box<Expr>,
+        index: Box<Expr>,
+    },
+    /// An array slice e.g. `arr[1:3]`, where either bound may be omitted
+    ArraySlice {
+        obj: Box<Expr>,
+        lower_bound: Option<Box<Expr>>,
+        upper_bound: Option<Box<Expr>>,
+    },
+    /// Access of data nested in a semi-structured value, e.g. Snowflake `col:a.b[0]`
+    JsonAccess {
+        value: Box<Expr>,
+        path: JsonPath,
+    },
     /// Scalar function call e.g. `LEFT(foo, 5)`
     Function(Function),
     /// `CASE [<operand>] WHEN <condition> THEN <result> ... [ELSE <result>] END`
@@ -283,13 +462,16 @@ pub enum Expr {
     /// The `ROLLUP` expr.
     Rollup(Vec<Vec<Expr>>),
     /// The `Array` expr
-    Array(Vec<Expr>),
+    Array(Array),
+    /// An array built from the rows of a subquery e.g. `ARRAY(SELECT x FROM t)`
+    ArraySubquery(Box<Query>),
 }
 
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Expr::Identifier(s) => write!(f, "{}", s),
+            Expr::JsonAccess { value, path } => write!(f, "{}{}", value, path),
             Expr::MapAccess { column, keys } => {
                 write!(f, "{}", column)?;
                 for k in keys {
@@ -303,9 +485,45 @@ impl fmt::Display for Expr {
                 }
                 Ok(())
             }
+            Expr::ArrayIndex { obj, index } => write!(f, "{}[{}]", obj, index),
+            Expr::ArraySlice {
+                obj,
+                lower_bound,
+                upper_bound,
+            } => {
+                write!(f, "{}[", obj)?;
+                if let Some(lower_bound) = lower_bound {
+                    write!(f, "{}", lower_bound)?;
+                }
+                write!(f, ":")?;
+                if let Some(upper_bound) = upper_bound {
+                    write!(f, "{}", upper_bound)?;
+                }
+                write!(f, "]")
+            }
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::IsNull(ast) => write!(f, "{} IS NULL", ast),
             Expr::IsNotNull(ast) => write!(f, "{} IS NOT NULL", ast),
+            Expr::IsTrue(ast) => write!(f, "{} IS TRUE", ast),
+            Expr::IsNotTrue(ast) => write!(f, "{} IS NOT TRUE", ast),
+            Expr::IsFalse(ast) => write!(f, "{} IS FALSE", ast),
+            Expr::IsNotFalse(ast) => write!(f, "{} IS NOT FALSE", ast),
+            Expr::IsUnknown(ast) => write!(f, "{} IS UNKNOWN", ast),
+            Expr::IsNotUnknown(ast) => write!(f, "{} IS NOT UNKNOWN", ast),
+            Expr::IsNormalized {
+                expr,
+                form,
+                negated,
+            } => {
+                write!(f, "{} IS ", expr)?;
+                if *negated {
+                    write!(f, "NOT ")?;
+                }
+                if let Some(form) = form {
+                    write!(f, "{} ", form)?;
+                }
+                write!(f, "NORMALIZED")
+            }
             Expr::InList {
                 expr,
                 list,
@@ -353,23 +571,226 @@ impl fmt::Display for Expr {
                 expr,
                 data_type,
                 pg_style,
+                format,
             } => {
                 if *pg_style {
                     write!(f, "{}::{}", expr, data_type)
                 } else {
-                    write!(f, "CAST({} AS {})", expr, data_type)
+                    write!(f, "CAST({} AS {}", expr, data_type)?;
+                    format_cast_format(f, format)?;
+                    write!(f, ")")
                 }
             }
-            Expr::TryCast { expr, data_type } => write!(f, "TRY_CAST({} AS {})", expr, data_type),
+            Expr::TryCast {
+                expr,
+                data_type,
+                format,
+            } => {
+                write!(f, "TRY_CAST({} AS {}", expr, data_type)?;
+                format_cast_format(f, format)?;
+                write!(f, ")")
+            }
+            Expr::SafeCast {
+                expr,
+                data_type,
+                format,
+            } => {
+                write!(f, "SAFE_CAST({} AS {}", expr, data_type)?;
+                format_cast_format(f, format)?;
+                write!(f, ")")
+            }
+            Expr::Convert {
+                expr,
+                data_type,
+                charset,
+                target_before_value,
+                styles,
+            } => {
+                write!(f, "CONVERT(")?;
+                if let Some(data_type) = data_type {
+                    if *target_before_value {
+                        write!(f, "{}, {}", data_type, expr)?;
+                    } else {
+                        write!(f, "{}, {}", expr, data_type)?;
+                    }
+                } else {
+                    write!(f, "{}", expr)?;
+                }
+                if let Some(charset) = charset {
+                    write!(f, " USING {}", charset)?;
+                }
+                if !styles.is_empty() {
+                    write!(f, ", {}", display_comma_separated(styles))?;
+                }
+                write!(f, ")")
+            }
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => write!(f, "{} AT TIME ZONE {}", timestamp, time_zone),
+            Expr::Like {
+                negated,
+                case_insensitive,
+                expr,
+                pattern,
+                escape_char,
+            } => {
+                write!(
+                    f,
+                    "{} {}{} {}",
+                    expr,
+                    if *negated { "NOT " } else { "" },
+                    if *case_insensitive { "ILIKE" } else { "LIKE" },
+                    pattern
+                )?;
+                format_escape_char(f, escape_char)
+            }
+            Expr::LikeQuantified {
+                negated,
+                case_insensitive,
+                expr,
+                quantifier,
+                patterns,
+                escape_char,
+            } => {
+                write!(
+                    f,
+                    "{} {}{} {} ({})",
+                    expr,
+                    if *negated { "NOT " } else { "" },
+                    if *case_insensitive { "ILIKE" } else { "LIKE" },
+                    quantifier,
+                    display_comma_separated(patterns)
+                )?;
+                format_escape_char(f, escape_char)
+            }
+            Expr::SimilarTo {
+                negated,
+                expr,
+                pattern,
+                escape_char,
+            } => {
+                write!(
+                    f,
+                    "{} {}SIMILAR TO {}",
+                    expr,
+                    if *negated { "NOT " } else { "" },
+                    pattern
+                )?;
+                format_escape_char(f, escape_char)
+            }
+            Expr::AnyOp {
+                left,
+                compare_op,
+                right,
+                is_some,
+            } => {
+                let quantifier = if *is_some { "SOME" } else { "ANY" };
+                write!(f, "{} {} {}", left, compare_op, quantifier)?;
+                format_quantified_operand(f, right)
+            }
+            Expr::AllOp {
+                left,
+                compare_op,
+                right,
+            } => {
+                write!(f, "{} {} ALL", left, compare_op)?;
+                format_quantified_operand(f, right)
+            }
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
+            Expr::Ceil { expr, field } => write!(f, "CEIL({} TO {})", expr, field),
+            Expr::Floor { expr, field } => write!(f, "FLOOR({} TO {})", expr, field),
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
             Expr::Nested(ast) => write!(f, "({})", ast),
             Expr::Tuple(ast) => write!(f, "({})", display_comma_separated(ast)),
+            Expr::Row(values) => write!(f, "ROW({})", display_comma_separated(values)),
+            Expr::Struct { values, fields } => {
+                if fields.is_empty() {
+                    write!(f, "STRUCT({})", display_comma_separated(values))
+                } else {
+                    write!(
+                        f,
+                        "STRUCT<{}>({})",
+                        display_comma_separated(fields),
+                        display_comma_separated(values)
+                    )
+                }
+            }
+            Expr::Named { expr, name } => write!(f, "{} AS {}", expr, name),
+            Expr::Dictionary(fields) => write!(f, "{{{}}}", display_comma_separated(fields)),
+            Expr::Map(entries) => write!(f, "MAP {{{}}}", display_comma_separated(entries)),
+            Expr::CompositeAccess { expr, key } => write!(f, "{}.{}", expr, key),
+            Expr::Lambda { params, body } => {
+                if params.len() == 1 {
+                    write!(f, "{} -> {}", params[0], body)
+                } else {
+                    write!(f, "({}) -> {}", display_comma_separated(params), body)
+                }
+            }
             Expr::Value(v) => write!(f, "{}", v),
             Expr::TypedString { data_type, value } => {
                 write!(f, "{}", data_type)?;
                 write!(f, " '{}'", &value::escape_single_quote_string(value))
             }
+            Expr::MatchAgainst {
+                columns,
+                match_value,
+                search_modifier,
+            } => {
+                write!(
+                    f,
+                    "MATCH ({}) AGAINST ({}",
+                    display_comma_separated(columns),
+                    match_value
+                )?;
+                if let Some(search_modifier) = search_modifier {
+                    write!(f, " {}", search_modifier)?;
+                }
+                write!(f, ")")
+            }
+            Expr::IntroducedString { introducer, value } => match value {
+                Value::SingleQuotedString(_) => write!(f, "{}{}", introducer, value),
+                // `_binaryX'FF'` would read back as a single identifier
+                _ => write!(f, "{} {}", introducer, value),
+            },
+            Expr::Interval {
+                value,
+                leading_field: Some(DateTimeField::Second),
+                leading_precision: Some(leading_precision),
+                last_field,
+                fractional_seconds_precision: Some(fractional_seconds_precision),
+            } => {
+                // When the leading field is SECOND, the parser guarantees that
+                // the last field is None.
+                assert!(last_field.is_none());
+                write!(
+                    f,
+                    "INTERVAL {} SECOND ({}, {})",
+                    value, leading_precision, fractional_seconds_precision
+                )
+            }
+            Expr::Interval {
+                value,
+                leading_field,
+                leading_precision,
+                last_field,
+                fractional_seconds_precision,
+            } => {
+                write!(f, "INTERVAL {}", value)?;
+                if let Some(leading_field) = leading_field {
+                    write!(f, " {}", leading_field)?;
+                }
+                if let Some(leading_precision) = leading_precision {
+                    write!(f, " ({})", leading_precision)?;
+                }
+                if let Some(last_field) = last_field {
+                    write!(f, " TO {}", last_field)?;
+                }
+                if let Some(fractional_seconds_precision) = fractional_seconds_precision {
+                    write!(f, " ({})", fractional_seconds_precision)?;
+                }
+                Ok(())
+            }
             Expr::Function(fun) => write!(f, "{}", fun),
             Expr::Case {
                 operand,
@@ -462,20 +883,175 @@ impl fmt::Display for Expr {
 
                 write!(f, ")")
             }
-            Expr::Array(exprs) => write!(
-                f,
-                "[{}]",
-                exprs
-                    .iter()
-                    .map(|v| v.to_string())
-                    .collect::<Vec<String>>()
-                    .as_slice()
-                    .join(", ")
-            ),
+            Expr::Array(array) => write!(f, "{}", array),
+            Expr::ArraySubquery(query) => write!(f, "ARRAY({})", query),
         }
     }
 }
 
+/// A backslash is printed as `'\\'`, which reads back as a backslash both in
+/// dialects with and without backslash escapes in strings
+fn format_escape_char(f: &mut fmt::Formatter, escape_char: &Option<String>) -> fmt::Result {
+    match escape_char.as_deref() {
+        Some("\\") => write!(f, " ESCAPE '\\\\'"),
+        Some(escape_char) => write!(
+            f,
+            " ESCAPE '{}'",
+            value::escape_single_quote_string(escape_char)
+        ),
+        None => Ok(()),
+    }
+}
+
+/// Subqueries already print their own parentheses
+fn format_quantified_operand(f: &mut fmt::Formatter, operand: &Expr) -> fmt::Result {
+    match operand {
+        Expr::Subquery(_) => write!(f, "{}", operand),
+        _ => write!(f, "({})", operand),
+    }
+}
+
+fn format_cast_format(f: &mut fmt::Formatter, format: &Option<Box<CastFormat>>) -> fmt::Result {
+    if let Some(format) = format {
+        write!(f, " FORMAT {}", format)?;
+    }
+    Ok(())
+}
+
+/// The search modifier of a MySQL `MATCH ... AGAINST` full-text search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SearchModifier {
+    /// `IN NATURAL LANGUAGE MODE`
+    InNaturalLanguageMode,
+    /// `IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION`
+    InNaturalLanguageModeWithQueryExpansion,
+    /// `IN BOOLEAN MODE`
+    InBooleanMode,
+    /// `WITH QUERY EXPANSION`
+    WithQueryExpansion,
+}
+
+impl fmt::Display for SearchModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SearchModifier::InNaturalLanguageMode => "IN NATURAL LANGUAGE MODE",
+            SearchModifier::InNaturalLanguageModeWithQueryExpansion => {
+                "IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION"
+            }
+            SearchModifier::InBooleanMode => "IN BOOLEAN MODE",
+            SearchModifier::WithQueryExpansion => "WITH QUERY EXPANSION",
+        })
+    }
+}
+
+/// The format string of a `CAST(... FORMAT ...)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CastFormat {
+    /// `FORMAT 'YYYY-MM-DD'`
+    Value(Value),
+    /// BigQuery `FORMAT 'YYYY-MM-DD HH24:MI' AT TIME ZONE 'UTC'`
+    ValueAtTimeZone(Value, Value),
+}
+
+impl fmt::Display for CastFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CastFormat::Value(v) => write!(f, "{}", v),
+            CastFormat::ValueAtTimeZone(v, tz) => write!(f, "{} AT TIME ZONE {}", v, tz),
+        }
+    }
+}
+
+/// A field of a DuckDB struct literal e.g. `'a': 1` in `{'a': 1}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DictionaryField {
+    pub key: Ident,
+    pub value: Box<Expr>,
+}
+
+impl fmt::Display for DictionaryField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.value)
+    }
+}
+
+/// An entry of a map literal e.g. `'k': v` in `MAP {'k': v}`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MapEntry {
+    pub key: Box<Expr>,
+    pub value: Box<Expr>,
+}
+
+impl fmt::Display for MapEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.value)
+    }
+}
+
+/// An array literal e.g. `ARRAY[1, 2]` or `[1, 2]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Array {
+    pub elem: Vec<Expr>,
+    /// Whether the literal is prefixed by the `ARRAY` keyword
+    pub named: bool,
+}
+
+impl fmt::Display for Array {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}]",
+            if self.named { "ARRAY" } else { "" },
+            display_comma_separated(&self.elem)
+        )
+    }
+}
+
+/// The Unicode normalization form of `IS [ NOT ] <form> NORMALIZED`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NormalizationForm {
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
+}
+
+impl fmt::Display for NormalizationForm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NormalizationForm::NFC => "NFC",
+            NormalizationForm::NFD => "NFD",
+            NormalizationForm::NFKC => "NFKC",
+            NormalizationForm::NFKD => "NFKD",
+        })
+    }
+}
+
+/// The quantifier of a multi-pattern `LIKE` e.g. `LIKE ANY ('a%', 'b%')`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LikeQuantifier {
+    /// The value matches at least one of the patterns
+    Any,
+    /// The value matches every pattern
+    All,
+}
+
+impl fmt::Display for LikeQuantifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LikeQuantifier::Any => "ANY",
+            LikeQuantifier::All => "ALL",
+        })
+    }
+}
+
 /// A window specification (i.e. `OVER (PARTITION BY .. ORDER BY .. etc.)`)
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -741,6 +1317,16 @@ pub enum Statement {
         query: Option<Box<Query>>,
         without_rowid: bool,
         like: Option<ObjectName>,
+        /// PostgreSQL `PARTITION OF <parent> FOR VALUES ...`
+        partition_of: Option<Box<PartitionOf>>,
+        /// PostgreSQL `INHERITS (<parent> [, ...])`
+        inherits: Vec<ObjectName>,
+        /// MySQL table options e.g. `ENGINE=InnoDB DEFAULT CHARSET=utf8mb4`
+        table_options: Vec<TableOption>,
+        /// PostgreSQL `PARTITION BY { RANGE | LIST | HASH } (...)`
+        partition_by: Option<Box<PartitionBy>>,
+        /// PostgreSQL `TABLESPACE <tablespace>`
+        tablespace: Option<Ident>,
     },
     /// SQLite's `CREATE VIRTUAL TABLE .. USING <module_name> (<module_args>)`
     CreateVirtualTable {
@@ -757,12 +1343,16 @@ pub enum Statement {
         columns: Vec<OrderByExpr>,
         unique: bool,
         if_not_exists: bool,
+        /// PostgreSQL storage parameters e.g. `WITH (fillfactor = 70)`
+        with: Vec<SqlOption>,
+        /// PostgreSQL `TABLESPACE <tablespace>`
+        tablespace: Option<Ident>,
     },
     /// ALTER TABLE
     AlterTable {
         /// Table name
         name: ObjectName,
-        operation: AlterTableOperation,
+        operations: Vec<AlterTableOperation>,
     },
     /// DROP
     Drop {
@@ -834,6 +1424,38 @@ pub enum Statement {
         location: Option<String>,
         managed_location: Option<String>,
     },
+    /// `CREATE [ OR REPLACE ] [ TEMPORARY ] FUNCTION`, as in PostgreSQL, Hive and BigQuery
+    CreateFunction {
+        or_replace: bool,
+        temporary: bool,
+        name: ObjectName,
+        /// Hive functions have no argument list
+        args: Option<Vec<OperateFunctionArg>>,
+        return_type: Option<FunctionReturnType>,
+        body: CreateFunctionBody,
+    },
+    /// `CREATE [ TEMPORARY ] SEQUENCE [ IF NOT EXISTS ] name [ AS data_type ] [ options ]`
+    CreateSequence {
+        temporary: bool,
+        if_not_exists: bool,
+        name: ObjectName,
+        data_type: Option<DataType>,
+        sequence_options: Vec<SequenceOptions>,
+    },
+    /// `ALTER SEQUENCE [ IF EXISTS ] name [ AS data_type ] [ options ]`
+    AlterSequence {
+        if_exists: bool,
+        name: ObjectName,
+        data_type: Option<DataType>,
+        sequence_options: Vec<SequenceOptions>,
+    },
+    /// MSSQL `CREATE [ OR ALTER ] PROCEDURE name [ params ] AS BEGIN ... END`
+    CreateProcedure {
+        or_alter: bool,
+        name: ObjectName,
+        params: Vec<ProcedureParam>,
+        body: Vec<Statement>,
+    },
     /// `ASSERT <condition> [AS <message>]`
     Assert {
         condition: Expr,
@@ -1176,6 +1798,11 @@ impl fmt::Display for Statement {
                 query,
                 without_rowid,
                 like,
+                partition_of,
+                inherits,
+                table_options,
+                partition_by,
+                tablespace,
             } => {
                 // We want to allow the following options
                 // Empty column list, allowed by PostgreSQL:
@@ -1193,16 +1820,31 @@ impl fmt::Display for Statement {
                     temporary = if *temporary { "TEMPORARY " } else { "" },
                     name = name,
                 )?;
+                if let Some(partition_of) = partition_of {
+                    write!(f, " PARTITION OF {}", partition_of.parent)?;
+                }
                 if !columns.is_empty() || !constraints.is_empty() {
                     write!(f, " ({}", display_comma_separated(columns))?;
                     if !columns.is_empty() && !constraints.is_empty() {
                         write!(f, ", ")?;
                     }
                     write!(f, "{})", display_comma_separated(constraints))?;
-                } else if query.is_none() && like.is_none() {
+                } else if query.is_none() && like.is_none() && partition_of.is_none() {
                     // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
                     write!(f, " ()")?;
                 }
+                if let Some(partition_of) = partition_of {
+                    write!(f, " {}", partition_of.bound)?;
+                }
+                if !inherits.is_empty() {
+                    write!(f, " INHERITS ({})", display_comma_separated(inherits))?;
+                }
+                if !table_options.is_empty() {
+                    write!(f, " {}", display_separated(table_options, " "))?;
+                }
+                if let Some(partition_by) = partition_by {
+                    write!(f, " {}", partition_by)?;
+                }
                 // Only for SQLite
                 if *without_rowid {
                     write!(f, " WITHOUT ROWID")?;
@@ -1298,6 +1940,9 @@ impl fmt::Display for Statement {
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
                 }
+                if let Some(tablespace) = tablespace {
+                    write!(f, " TABLESPACE {}", tablespace)?;
+                }
                 if let Some(query) = query {
                     write!(f, " AS {}", query)?;
                 }
@@ -1327,17 +1972,33 @@ impl fmt::Display for Statement {
                 columns,
                 unique,
                 if_not_exists,
-            } => write!(
-                f,
-                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
-                unique = if *unique { "UNIQUE " } else { "" },
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                name = name,
-                table_name = table_name,
-                columns = display_separated(columns, ",")
-            ),
-            Statement::AlterTable { name, operation } => {
-                write!(f, "ALTER TABLE {} {}", name, operation)
+                with,
+                tablespace,
+            } => {
+                write!(
+                    f,
+                    "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
+                    unique = if *unique { "UNIQUE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                    table_name = table_name,
+                    columns = display_separated(columns, ",")
+                )?;
+                if !with.is_empty() {
+                    write!(f, " WITH ({})", display_comma_separated(with))?;
+                }
+                if let Some(tablespace) = tablespace {
+                    write!(f, " TABLESPACE {}", tablespace)?;
+                }
+                Ok(())
+            }
+            Statement::AlterTable { name, operations } => {
+                write!(
+                    f,
+                    "ALTER TABLE {} {}",
+                    name,
+                    display_comma_separated(operations)
+                )
             }
             Statement::Drop {
                 object_type,
@@ -1446,6 +2107,92 @@ impl fmt::Display for Statement {
                 if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
                 name = schema_name
             ),
+            Statement::CreateFunction {
+                or_replace,
+                temporary,
+                name,
+                args,
+                return_type,
+                body,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_replace}{temp}FUNCTION {name}",
+                    or_replace = if *or_replace { "OR REPLACE " } else { "" },
+                    temp = if *temporary { "TEMPORARY " } else { "" },
+                    name = name,
+                )?;
+                if let Some(args) = args {
+                    write!(f, "({})", display_comma_separated(args))?;
+                }
+                if let Some(return_type) = return_type {
+                    write!(f, " RETURNS {}", return_type)?;
+                }
+                write!(f, "{}", body)
+            }
+            Statement::CreateSequence {
+                temporary,
+                if_not_exists,
+                name,
+                data_type,
+                sequence_options,
+            } => {
+                write!(
+                    f,
+                    "CREATE {temp}SEQUENCE {if_not_exists}{name}",
+                    temp = if *temporary { "TEMPORARY " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                )?;
+                if let Some(data_type) = data_type {
+                    write!(f, " AS {}", data_type)?;
+                }
+                for option in sequence_options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            Statement::AlterSequence {
+                if_exists,
+                name,
+                data_type,
+                sequence_options,
+            } => {
+                write!(
+                    f,
+                    "ALTER SEQUENCE {if_exists}{name}",
+                    if_exists = if *if_exists { "IF EXISTS " } else { "" },
+                    name = name,
+                )?;
+                if let Some(data_type) = data_type {
+                    write!(f, " AS {}", data_type)?;
+                }
+                for option in sequence_options {
+                    write!(f, " {}", option)?;
+                }
+                Ok(())
+            }
+            Statement::CreateProcedure {
+                or_alter,
+                name,
+                params,
+                body,
+            } => {
+                write!(
+                    f,
+                    "CREATE {or_alter}PROCEDURE {name}",
+                    or_alter = if *or_alter { "OR ALTER " } else { "" },
+                    name = name,
+                )?;
+                if !params.is_empty() {
+                    write!(f, " {}", display_comma_separated(params))?;
+                }
+                write!(f, " AS BEGIN ")?;
+                for statement in body {
+                    write!(f, "{}; ", statement)?;
+                }
+                write!(f, "END")
+            }
             Statement::Assert { condition, message } => {
                 write!(f, "ASSERT {}", condition)?;
                 if let Some(m) = message {
@@ -1700,6 +2447,42 @@ impl fmt::Display for FunctionArgExpr {
     }
 }
 
+/// A path into a semi-structured value, e.g. `:a.b[0]` in Snowflake `col:a.b[0]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JsonPath {
+    pub path: Vec<JsonPathElem>,
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, elem) in self.path.iter().enumerate() {
+            match elem {
+                JsonPathElem::Dot { key, quoted } => {
+                    // the first key follows the colon introducing the path
+                    f.write_str(if i == 0 { ":" } else { "." })?;
+                    if *quoted {
+                        write!(f, "\"{}\"", key)?;
+                    } else {
+                        write!(f, "{}", key)?;
+                    }
+                }
+                JsonPathElem::Bracket { key } => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum JsonPathElem {
+    /// An object key, e.g. `a` in `:a` or `.a`, possibly double-quoted
+    Dot { key: String, quoted: bool },
+    /// A subscript, e.g. `[0]` or `['a']`
+    Bracket { key: Expr },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FunctionArg {
@@ -1856,6 +2639,7 @@ pub enum ObjectType {
     View,
     Index,
     Schema,
+    Sequence,
 }
 
 impl fmt::Display for ObjectType {
@@ -1865,6 +2649,7 @@ impl fmt::Display for ObjectType {
             ObjectType::View => "VIEW",
             ObjectType::Index => "INDEX",
             ObjectType::Schema => "SCHEMA",
+            ObjectType::Sequence => "SEQUENCE",
         })
     }
 }
@@ -2020,6 +2805,244 @@ impl fmt::Display for SetVariableValue {
     }
 }
 
+/// An argument of a `CREATE FUNCTION` statement e.g. `IN a INTEGER DEFAULT 1`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OperateFunctionArg {
+    pub mode: Option<ArgMode>,
+    /// PostgreSQL arguments may be unnamed e.g. `f(INTEGER)`
+    pub name: Option<Ident>,
+    pub data_type: DataType,
+    pub default_expr: Option<Expr>,
+}
+
+impl fmt::Display for OperateFunctionArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(mode) = &self.mode {
+            write!(f, "{} ", mode)?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, "{} ", name)?;
+        }
+        write!(f, "{}", self.data_type)?;
+        if let Some(default_expr) = &self.default_expr {
+            write!(f, " DEFAULT {}", default_expr)?;
+        }
+        Ok(())
+    }
+}
+
+/// The mode of a function argument
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ArgMode {
+    In,
+    Out,
+    InOut,
+    Variadic,
+}
+
+impl fmt::Display for ArgMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgMode::In => write!(f, "IN"),
+            ArgMode::Out => write!(f, "OUT"),
+            ArgMode::InOut => write!(f, "INOUT"),
+            ArgMode::Variadic => write!(f, "VARIADIC"),
+        }
+    }
+}
+
+/// The options following the signature of a `CREATE FUNCTION` statement, which
+/// PostgreSQL accepts in any order e.g. `LANGUAGE SQL IMMUTABLE AS 'select 1'`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CreateFunctionBody {
+    /// `LANGUAGE lang_name`
+    pub language: Option<Ident>,
+    /// `IMMUTABLE | STABLE | VOLATILE`
+    pub behavior: Option<FunctionBehavior>,
+    /// `CALLED ON NULL INPUT | RETURNS NULL ON NULL INPUT | STRICT`
+    pub called_on_null: Option<FunctionCalledOnNull>,
+    /// `SECURITY INVOKER | SECURITY DEFINER`
+    pub security: Option<FunctionSecurity>,
+    /// `AS 'definition'`, `AS $$definition$$` or BigQuery `AS (expr)`
+    pub as_: Option<FunctionDefinition>,
+    /// Hive `USING JAR 'uri', FILE 'uri'`
+    pub using: Vec<CreateFunctionUsing>,
+}
+
+impl fmt::Display for CreateFunctionBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(language) = &self.language {
+            write!(f, " LANGUAGE {}", language)?;
+        }
+        if let Some(behavior) = &self.behavior {
+            write!(f, " {}", behavior)?;
+        }
+        if let Some(called_on_null) = &self.called_on_null {
+            write!(f, " {}", called_on_null)?;
+        }
+        if let Some(security) = &self.security {
+            write!(f, " {}", security)?;
+        }
+        if let Some(definition) = &self.as_ {
+            write!(f, " AS {}", definition)?;
+        }
+        if !self.using.is_empty() {
+            write!(f, " USING {}", display_comma_separated(&self.using))?;
+        }
+        Ok(())
+    }
+}
+
+/// The return type of a `CREATE FUNCTION` statement, following `RETURNS`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionReturnType {
+    /// A single value e.g. `RETURNS INT`
+    DataType(DataType),
+    /// PostgreSQL `RETURNS SETOF INT`
+    SetOf(DataType),
+    /// PostgreSQL `RETURNS TABLE (a INT, b TEXT)`
+    Table(Vec<ColumnDef>),
+}
+
+impl fmt::Display for FunctionReturnType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionReturnType::DataType(data_type) => write!(f, "{}", data_type),
+            FunctionReturnType::SetOf(data_type) => write!(f, "SETOF {}", data_type),
+            FunctionReturnType::Table(columns) => {
+                write!(f, "TABLE ({})", display_comma_separated(columns))
+            }
+        }
+    }
+}
+
+/// The volatility of a function
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionBehavior {
+    Immutable,
+    Stable,
+    Volatile,
+}
+
+impl fmt::Display for FunctionBehavior {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionBehavior::Immutable => write!(f, "IMMUTABLE"),
+            FunctionBehavior::Stable => write!(f, "STABLE"),
+            FunctionBehavior::Volatile => write!(f, "VOLATILE"),
+        }
+    }
+}
+
+/// Whether a function is called when some of its arguments are null
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionCalledOnNull {
+    CalledOnNullInput,
+    ReturnsNullOnNullInput,
+    Strict,
+}
+
+impl fmt::Display for FunctionCalledOnNull {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionCalledOnNull::CalledOnNullInput => write!(f, "CALLED ON NULL INPUT"),
+            FunctionCalledOnNull::ReturnsNullOnNullInput => {
+                write!(f, "RETURNS NULL ON NULL INPUT")
+            }
+            FunctionCalledOnNull::Strict => write!(f, "STRICT"),
+        }
+    }
+}
+
+/// The privileges a function is executed with
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionSecurity {
+    Invoker,
+    Definer,
+}
+
+impl fmt::Display for FunctionSecurity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionSecurity::Invoker => write!(f, "SECURITY INVOKER"),
+            FunctionSecurity::Definer => write!(f, "SECURITY DEFINER"),
+        }
+    }
+}
+
+/// The definition of a function following `AS`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FunctionDefinition {
+    /// A function body or Hive class name e.g. `'select $1 + 1'`
+    SingleQuotedDef(String),
+    /// A PostgreSQL function body e.g. `$$ BEGIN ... END $$`
+    DollarQuotedDef(DollarQuotedString),
+    /// A BigQuery SQL function body e.g. `(x + 1)`
+    Expr(Expr),
+}
+
+impl fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FunctionDefinition::SingleQuotedDef(s) => {
+                write!(f, "'{}'", value::escape_single_quote_string(s))
+            }
+            FunctionDefinition::DollarQuotedDef(s) => write!(f, "{}", s),
+            FunctionDefinition::Expr(expr) => write!(f, "({})", expr),
+        }
+    }
+}
+
+/// A Hive resource of a `CREATE FUNCTION` statement e.g. `JAR 'hdfs:///udf.jar'`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CreateFunctionUsing {
+    Jar(String),
+    File(String),
+    Archive(String),
+}
+
+impl fmt::Display for CreateFunctionUsing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateFunctionUsing::Jar(uri) => write!(f, "JAR '{}'", uri),
+            CreateFunctionUsing::File(uri) => write!(f, "FILE '{}'", uri),
+            CreateFunctionUsing::Archive(uri) => write!(f, "ARCHIVE '{}'", uri),
+        }
+    }
+}
+
+/// A parameter of an MSSQL `CREATE PROCEDURE` statement e.g. `@id INT = 0 OUTPUT`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProcedureParam {
+    pub name: Ident,
+    pub data_type: DataType,
+    pub default_expr: Option<Expr>,
+    pub output: bool,
+}
+
+impl fmt::Display for ProcedureParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)?;
+        if let Some(default_expr) = &self.default_expr {
+            write!(f, " = {}", default_expr)?;
+        }
+        if self.output {
+            write!(f, " OUTPUT")?;
+        }
+        Ok(())
+    }
+}
+
 /// Sqlite specific syntax
 ///
 /// https://sqlite.org/lang_conflict.html
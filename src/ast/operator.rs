@@ -73,10 +73,6 @@ pub enum BinaryOperator {
     And,
     Or,
     Xor,
-    Like,
-    NotLike,
-    ILike,
-    NotILike,
     Regexp,
     NotRegexp,
     RLike,
@@ -91,6 +87,28 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// `->`, JSON field (or array element) access in PostgreSQL and MySQL
+    Arrow,
+    /// `->>`, JSON field access as text in PostgreSQL and MySQL
+    LongArrow,
+    /// `#>`, JSON path access in PostgreSQL
+    HashArrow,
+    /// `#>>`, JSON path access as text in PostgreSQL
+    HashLongArrow,
+    /// `@>`, PostgreSQL "contains"
+    AtArrow,
+    /// `<@`, PostgreSQL "is contained by"
+    ArrowAt,
+    /// `?`, PostgreSQL JSONB key existence
+    Question,
+    /// `?|`, PostgreSQL JSONB existence of any of the keys
+    QuestionPipe,
+    /// `?&`, PostgreSQL JSONB existence of all of the keys
+    QuestionAnd,
+    /// `@?`, PostgreSQL JSONB path existence
+    AtQuestion,
+    /// `:=`, MySQL variable assignment e.g. `@a := @a + 1`
+    Assignment,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -113,10 +131,6 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::And => "AND",
             BinaryOperator::Or => "OR",
             BinaryOperator::Xor => "XOR",
-            BinaryOperator::Like => "LIKE",
-            BinaryOperator::NotLike => "NOT LIKE",
-            BinaryOperator::ILike => "ILIKE",
-            BinaryOperator::NotILike => "NOT ILIKE",
             BinaryOperator::Regexp => "REGEXP",
             BinaryOperator::NotRegexp => "NOT REGEXP",
             BinaryOperator::RLike => "RLIKE",
@@ -131,6 +145,17 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::Arrow => "->",
+            BinaryOperator::LongArrow => "->>",
+            BinaryOperator::HashArrow => "#>",
+            BinaryOperator::HashLongArrow => "#>>",
+            BinaryOperator::AtArrow => "@>",
+            BinaryOperator::ArrowAt => "<@",
+            BinaryOperator::Question => "?",
+            BinaryOperator::QuestionPipe => "?|",
+            BinaryOperator::QuestionAnd => "?&",
+            BinaryOperator::AtQuestion => "@?",
+            BinaryOperator::Assignment => ":=",
         })
     }
 }
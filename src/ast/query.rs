@@ -290,6 +290,8 @@ pub enum SelectItem {
     ExprWithAlias { expr: Expr, alias: Ident },
     /// `alias.*` or even `schema.table.*`
     QualifiedWildcard(ObjectName),
+    /// All fields of a composite value e.g. `(func()).*`
+    ExprWildcard(Expr),
     /// An unqualified `*`
     Wildcard,
 }
@@ -300,6 +302,7 @@ impl fmt::Display for SelectItem {
             SelectItem::UnnamedExpr(expr) => write!(f, "{}", expr),
             SelectItem::ExprWithAlias { expr, alias } => write!(f, "{} AS {}", expr, alias),
             SelectItem::QualifiedWildcard(prefix) => write!(f, "{}.*", prefix),
+            SelectItem::ExprWildcard(expr) => write!(f, "{}.*", expr),
             SelectItem::Wildcard => write!(f, "*"),
         }
     }
@@ -322,10 +325,86 @@ impl fmt::Display for TableWithJoins {
     }
 }
 
+/// A point (or range) in the history of a table, used for time travel queries
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Instant {
+    /// `AT (SNAPSHOT => <id>)`
     SnapshotID(String),
+    /// Snowflake `AT(<point> => <expr>)`, e.g. `AT(OFFSET => -60)`
+    At {
+        point: InstantPoint,
+        value: Box<Expr>,
+    },
+    /// Snowflake `BEFORE(<point> => <expr>)`, e.g. `BEFORE(STATEMENT => '<id>')`
+    Before {
+        point: InstantPoint,
+        value: Box<Expr>,
+    },
+    /// Snowflake `END(<point> => <expr>)`, closing the interval of `CHANGES`
+    End {
+        point: InstantPoint,
+        value: Box<Expr>,
+    },
+    /// `FOR SYSTEM_TIME AS OF <expr>` (SQL:2011, BigQuery, MSSQL)
+    SystemTimeAsOf(Box<Expr>),
+    /// Delta Lake `VERSION AS OF <expr>`
+    VersionAsOf(Box<Expr>),
+    /// Delta Lake `TIMESTAMP AS OF <expr>`
+    TimestampAsOf(Box<Expr>),
+    /// Snowflake `CHANGES(INFORMATION => { DEFAULT | APPEND_ONLY }) <start> [ <end> ]`
+    /// where `start` is an `AT` or `BEFORE` instant and `end` an `END` one
+    Changes {
+        information: Ident,
+        start: Box<Instant>,
+        end: Option<Box<Instant>>,
+    },
+}
+
+impl fmt::Display for Instant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instant::SnapshotID(id) => write!(f, "at (snapshot => '{}')", id),
+            Instant::At { point, value } => write!(f, "AT({} => {})", point, value),
+            Instant::Before { point, value } => write!(f, "BEFORE({} => {})", point, value),
+            Instant::End { point, value } => write!(f, "END({} => {})", point, value),
+            Instant::SystemTimeAsOf(expr) => write!(f, "FOR SYSTEM_TIME AS OF {}", expr),
+            Instant::VersionAsOf(expr) => write!(f, "VERSION AS OF {}", expr),
+            Instant::TimestampAsOf(expr) => write!(f, "TIMESTAMP AS OF {}", expr),
+            Instant::Changes {
+                information,
+                start,
+                end,
+            } => {
+                write!(f, "CHANGES(INFORMATION => {}) {}", information, start)?;
+                if let Some(end) = end {
+                    write!(f, " {}", end)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The kind of point referenced by a Snowflake `AT`, `BEFORE` or `END` instant
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InstantPoint {
+    Timestamp,
+    Offset,
+    Statement,
+    Stream,
+}
+
+impl fmt::Display for InstantPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            InstantPoint::Timestamp => "TIMESTAMP",
+            InstantPoint::Offset => "OFFSET",
+            InstantPoint::Statement => "STATEMENT",
+            InstantPoint::Stream => "STREAM",
+        })
+    }
 }
 
 /// A table name or a parenthesized subquery with an optional alias
@@ -344,17 +423,49 @@ pub enum TableFactor {
 
         // Instant of table history
         instant: Option<Instant>,
+        /// `TABLESAMPLE ...` or Snowflake `SAMPLE ...` clause
+        sample: Option<TableSampleKind>,
     },
     Derived {
         lateral: bool,
         subquery: Box<Query>,
         alias: Option<TableAlias>,
+        /// `TABLESAMPLE ...` or Snowflake `SAMPLE ...` clause
+        sample: Option<TableSampleKind>,
     },
     /// `TABLE(<expr>)[ AS <alias> ]`
     TableFunction {
         expr: Expr,
         alias: Option<TableAlias>,
     },
+    /// A table-valued function call with options that `Table` can't represent, e.g.
+    /// Snowflake `LATERAL FLATTEN(input => col)` or `f() WITH ORDINALITY AS t(a, b)`
+    Function {
+        lateral: bool,
+        name: ObjectName,
+        args: Vec<FunctionArg>,
+        with_ordinality: bool,
+        /// Postgres column definition list given without a table alias,
+        /// e.g. `f() AS (a INT, b TEXT)`
+        column_defs: Vec<TableAliasColumnDef>,
+        alias: Option<TableAlias>,
+    },
+    /// `UNNEST(<expr>, ...) [ WITH ORDINALITY ] [ AS <alias> ] [ WITH OFFSET [ AS <alias> ] ]`
+    ///
+    /// `WITH ORDINALITY` is the Postgres form, `WITH OFFSET` the BigQuery one.
+    UNNEST {
+        array_exprs: Vec<Expr>,
+        with_ordinality: bool,
+        alias: Option<TableAlias>,
+        with_offset: bool,
+        with_offset_alias: Option<Ident>,
+    },
+    /// Postgres `ROWS FROM (<function>, ...) [ WITH ORDINALITY ] [ AS <alias> ]`
+    RowsFrom {
+        functions: Vec<Function>,
+        with_ordinality: bool,
+        alias: Option<TableAlias>,
+    },
     /// Represents a parenthesized table factor. The SQL spec only allows a
     /// join expression (`(foo <JOIN> bar [ <JOIN> baz ... ])`) to be nested,
     /// possibly several times.
@@ -362,6 +473,49 @@ pub enum TableFactor {
     /// The parser may also accept non-standard nesting of bare tables for some
     /// dialects, but the information about such nesting is stripped from AST.
     NestedJoin(Box<TableWithJoins>),
+    /// `<table> PIVOT(<aggregate> FOR <column> IN (<value>, ...)) [ AS <alias> ]`
+    ///
+    /// Rotates the values of `value_column` into columns, see e.g.
+    /// <https://docs.snowflake.com/en/sql-reference/constructs/pivot.html>
+    Pivot {
+        table: Box<TableFactor>,
+        aggregate_function: Expr,
+        value_column: Vec<Ident>,
+        pivot_values: Vec<Expr>,
+        alias: Option<TableAlias>,
+    },
+    /// `<table> UNPIVOT(<value> FOR <name> IN (<column>, ...)) [ AS <alias> ]`
+    ///
+    /// The inverse of `PIVOT`: rotates `columns` into rows, see e.g.
+    /// <https://docs.snowflake.com/en/sql-reference/constructs/unpivot.html>
+    Unpivot {
+        table: Box<TableFactor>,
+        value: Ident,
+        name: Ident,
+        columns: Vec<Ident>,
+        alias: Option<TableAlias>,
+    },
+    /// `<table> MATCH_RECOGNIZE(...) [ AS <alias> ]`, matching a row pattern
+    /// over the (partitioned, ordered) rows of `table`, see e.g.
+    /// <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize.html>
+    MatchRecognize {
+        table: Box<TableFactor>,
+        /// `PARTITION BY <expr> [, ... ]`
+        partition_by: Vec<Expr>,
+        /// `ORDER BY <expr> [, ... ]`
+        order_by: Vec<OrderByExpr>,
+        /// `MEASURES <expr> [AS] <alias> [, ... ]`
+        measures: Vec<Measure>,
+        /// `ONE ROW PER MATCH | ALL ROWS PER MATCH [ <option> ]`
+        rows_per_match: Option<RowsPerMatch>,
+        /// `AFTER MATCH SKIP <option>`
+        after_match_skip: Option<AfterMatchSkip>,
+        /// `PATTERN ( <pattern> )`
+        pattern: MatchRecognizePattern,
+        /// `DEFINE <symbol> AS <expr> [, ... ]`
+        symbols: Vec<SymbolDefinition>,
+        alias: Option<TableAlias>,
+    },
 }
 
 impl fmt::Display for TableFactor {
@@ -373,37 +527,48 @@ impl fmt::Display for TableFactor {
                 args,
                 with_hints,
                 instant,
+                sample,
             } => {
                 write!(f, "{}", name)?;
+                if let Some(instant) = instant {
+                    write!(f, " {}", instant)?;
+                }
                 if !args.is_empty() {
                     write!(f, "({})", display_comma_separated(args))?;
                 }
+                if let Some(TableSampleKind::BeforeTableAlias(sample)) = sample {
+                    write!(f, " {}", sample)?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {}", alias)?;
                 }
+                if let Some(TableSampleKind::AfterTableAlias(sample)) = sample {
+                    write!(f, " {}", sample)?;
+                }
                 if !with_hints.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_hints))?;
                 }
-
-                if let Some(v) = instant {
-                    match v {
-                        Instant::SnapshotID(id) => write!(f, " at (snapshot => '{}')", id)?,
-                    }
-                }
                 Ok(())
             }
             TableFactor::Derived {
                 lateral,
                 subquery,
                 alias,
+                sample,
             } => {
                 if *lateral {
                     write!(f, "LATERAL ")?;
                 }
                 write!(f, "({})", subquery)?;
+                if let Some(TableSampleKind::BeforeTableAlias(sample)) = sample {
+                    write!(f, " {}", sample)?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {}", alias)?;
                 }
+                if let Some(TableSampleKind::AfterTableAlias(sample)) = sample {
+                    write!(f, " {}", sample)?;
+                }
                 Ok(())
             }
             TableFactor::TableFunction { expr, alias } => {
@@ -413,16 +578,524 @@ impl fmt::Display for TableFactor {
                 }
                 Ok(())
             }
+            TableFactor::Function {
+                lateral,
+                name,
+                args,
+                with_ordinality,
+                column_defs,
+                alias,
+            } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
+                write!(f, "{}({})", name, display_comma_separated(args))?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
+                if !column_defs.is_empty() {
+                    write!(f, " AS ({})", display_comma_separated(column_defs))?;
+                }
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::UNNEST {
+                array_exprs,
+                with_ordinality,
+                alias,
+                with_offset,
+                with_offset_alias,
+            } => {
+                write!(f, "UNNEST({})", display_comma_separated(array_exprs))?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                if *with_offset {
+                    write!(f, " WITH OFFSET")?;
+                }
+                if let Some(alias) = with_offset_alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::RowsFrom {
+                functions,
+                with_ordinality,
+                alias,
+            } => {
+                write!(f, "ROWS FROM ({})", display_comma_separated(functions))?;
+                if *with_ordinality {
+                    write!(f, " WITH ORDINALITY")?;
+                }
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
             TableFactor::NestedJoin(table_reference) => write!(f, "({})", table_reference),
+            TableFactor::Pivot {
+                table,
+                aggregate_function,
+                value_column,
+                pivot_values,
+                alias,
+            } => {
+                write!(
+                    f,
+                    "{} PIVOT({} FOR {} IN ({}))",
+                    table,
+                    aggregate_function,
+                    display_separated(value_column, "."),
+                    display_comma_separated(pivot_values)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                write!(
+                    f,
+                    "{} UNPIVOT({} FOR {} IN ({}))",
+                    table,
+                    value,
+                    name,
+                    display_comma_separated(columns)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+            TableFactor::MatchRecognize {
+                table,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                symbols,
+                alias,
+            } => {
+                write!(f, "{} MATCH_RECOGNIZE(", table)?;
+                if !partition_by.is_empty() {
+                    write!(f, "PARTITION BY {} ", display_comma_separated(partition_by))?;
+                }
+                if !order_by.is_empty() {
+                    write!(f, "ORDER BY {} ", display_comma_separated(order_by))?;
+                }
+                if !measures.is_empty() {
+                    write!(f, "MEASURES {} ", display_comma_separated(measures))?;
+                }
+                if let Some(rows_per_match) = rows_per_match {
+                    write!(f, "{} ", rows_per_match)?;
+                }
+                if let Some(after_match_skip) = after_match_skip {
+                    write!(f, "{} ", after_match_skip)?;
+                }
+                write!(
+                    f,
+                    "PATTERN ({}) DEFINE {})",
+                    pattern,
+                    display_comma_separated(symbols)
+                )?;
+                if let Some(alias) = alias {
+                    write!(f, " AS {}", alias)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A `MATCH_RECOGNIZE` measure: `<expr> AS <alias>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Measure {
+    pub expr: Expr,
+    pub alias: Ident,
+}
+
+impl fmt::Display for Measure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} AS {}", self.expr, self.alias)
+    }
+}
+
+/// The number of rows `MATCH_RECOGNIZE` returns for each match
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RowsPerMatch {
+    /// `ONE ROW PER MATCH`
+    OneRow,
+    /// `ALL ROWS PER MATCH [ <mode> ]`
+    AllRows(Option<EmptyMatchesMode>),
+}
+
+impl fmt::Display for RowsPerMatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RowsPerMatch::OneRow => write!(f, "ONE ROW PER MATCH"),
+            RowsPerMatch::AllRows(mode) => {
+                write!(f, "ALL ROWS PER MATCH")?;
+                if let Some(mode) = mode {
+                    write!(f, " {}", mode)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// How `ALL ROWS PER MATCH` treats empty matches and unmatched rows
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EmptyMatchesMode {
+    /// `SHOW EMPTY MATCHES`
+    Show,
+    /// `OMIT EMPTY MATCHES`
+    Omit,
+    /// `WITH UNMATCHED ROWS`
+    WithUnmatched,
+}
+
+impl fmt::Display for EmptyMatchesMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            EmptyMatchesMode::Show => "SHOW EMPTY MATCHES",
+            EmptyMatchesMode::Omit => "OMIT EMPTY MATCHES",
+            EmptyMatchesMode::WithUnmatched => "WITH UNMATCHED ROWS",
+        })
+    }
+}
+
+/// Where `MATCH_RECOGNIZE` resumes looking for a match after finding one
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AfterMatchSkip {
+    /// `PAST LAST ROW`
+    PastLastRow,
+    /// `TO NEXT ROW`
+    ToNextRow,
+    /// `TO FIRST <symbol>`
+    ToFirst(Ident),
+    /// `TO LAST <symbol>`
+    ToLast(Ident),
+}
+
+impl fmt::Display for AfterMatchSkip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AFTER MATCH SKIP ")?;
+        match self {
+            AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
+            AfterMatchSkip::ToFirst(symbol) => write!(f, "TO FIRST {}", symbol),
+            AfterMatchSkip::ToLast(symbol) => write!(f, "TO LAST {}", symbol),
+        }
+    }
+}
+
+/// A `MATCH_RECOGNIZE` symbol definition: `<symbol> AS <expr>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SymbolDefinition {
+    pub symbol: Ident,
+    pub definition: Expr,
+}
+
+impl fmt::Display for SymbolDefinition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} AS {}", self.symbol, self.definition)
+    }
+}
+
+/// A symbol in a `MATCH_RECOGNIZE` pattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MatchRecognizeSymbol {
+    /// A symbol defined in the `DEFINE` clause (or an undefined one, matching any row)
+    Named(Ident),
+    /// The `^` anchor, matching the start of a partition
+    Start,
+    /// The `$` anchor, matching the end of a partition
+    End,
+}
+
+impl fmt::Display for MatchRecognizeSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchRecognizeSymbol::Named(symbol) => write!(f, "{}", symbol),
+            MatchRecognizeSymbol::Start => write!(f, "^"),
+            MatchRecognizeSymbol::End => write!(f, "$"),
         }
     }
 }
 
+/// A row pattern of `MATCH_RECOGNIZE`, a regular expression over symbols
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MatchRecognizePattern {
+    /// A single symbol or anchor, e.g. `A` or `^`
+    Symbol(MatchRecognizeSymbol),
+    /// `{- <symbol> -}`: matched rows are excluded from the output
+    Exclude(MatchRecognizeSymbol),
+    /// `PERMUTE(<symbol>, ...)`: the symbols in any order
+    Permute(Vec<MatchRecognizeSymbol>),
+    /// `<pattern> <pattern> ...`
+    Concat(Vec<MatchRecognizePattern>),
+    /// `( <pattern> )`
+    Group(Box<MatchRecognizePattern>),
+    /// `<pattern> | <pattern> | ...`
+    Alternation(Vec<MatchRecognizePattern>),
+    /// `<pattern> <quantifier> [ ? ]`, e.g. `A+`, `(A B){2,}` or the reluctant `A+?`
+    Repetition {
+        pattern: Box<MatchRecognizePattern>,
+        quantifier: RepetitionQuantifier,
+        /// The quantifier is followed by `?`, so it matches as few rows as possible
+        reluctant: bool,
+    },
+}
+
+impl fmt::Display for MatchRecognizePattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchRecognizePattern::Symbol(symbol) => write!(f, "{}", symbol),
+            MatchRecognizePattern::Exclude(symbol) => write!(f, "{{- {} -}}", symbol),
+            MatchRecognizePattern::Permute(symbols) => {
+                write!(f, "PERMUTE({})", display_comma_separated(symbols))
+            }
+            MatchRecognizePattern::Concat(patterns) => {
+                write!(f, "{}", display_separated(patterns, " "))
+            }
+            MatchRecognizePattern::Group(pattern) => write!(f, "({})", pattern),
+            MatchRecognizePattern::Alternation(patterns) => {
+                write!(f, "{}", display_separated(patterns, " | "))
+            }
+            MatchRecognizePattern::Repetition {
+                pattern,
+                quantifier,
+                reluctant,
+            } => {
+                write!(f, "{}{}", pattern, quantifier)?;
+                if *reluctant {
+                    write!(f, "?")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A quantifier of a `MATCH_RECOGNIZE` pattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RepetitionQuantifier {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    AtMostOne,
+    /// `{n}`
+    Exactly(u64),
+    /// `{n,}`
+    AtLeast(u64),
+    /// `{,m}`
+    AtMost(u64),
+    /// `{n,m}`
+    Range(u64, u64),
+}
+
+impl fmt::Display for RepetitionQuantifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RepetitionQuantifier::ZeroOrMore => write!(f, "*"),
+            RepetitionQuantifier::OneOrMore => write!(f, "+"),
+            RepetitionQuantifier::AtMostOne => write!(f, "?"),
+            RepetitionQuantifier::Exactly(n) => write!(f, "{{{}}}", n),
+            RepetitionQuantifier::AtLeast(n) => write!(f, "{{{},}}", n),
+            RepetitionQuantifier::AtMost(m) => write!(f, "{{,{}}}", m),
+            RepetitionQuantifier::Range(n, m) => write!(f, "{{{},{}}}", n, m),
+        }
+    }
+}
+
+/// Where a sampling clause appears relative to the alias of a table factor
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleKind {
+    /// Hive `<table> TABLESAMPLE (...) [AS] <alias>`
+    BeforeTableAlias(TableSample),
+    /// `<table> [AS] <alias> TABLESAMPLE (...)`
+    AfterTableAlias(TableSample),
+}
+
+/// A table sampling clause, e.g. `TABLESAMPLE BERNOULLI (10) REPEATABLE (42)`,
+/// Snowflake `SAMPLE (1000 ROWS)` or Hive `TABLESAMPLE (BUCKET 3 OUT OF 32 ON id)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableSample {
+    pub modifier: TableSampleModifier,
+    pub method: Option<TableSampleMethod>,
+    pub size: TableSampleSize,
+    pub seed: Option<TableSampleSeed>,
+}
+
+impl fmt::Display for TableSample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.modifier)?;
+        if let Some(method) = &self.method {
+            write!(f, " {}", method)?;
+        }
+        write!(f, " ({})", self.size)?;
+        if let Some(seed) = &self.seed {
+            write!(f, " {}", seed)?;
+        }
+        Ok(())
+    }
+}
+
+/// The keyword introducing a table sample: the standard `TABLESAMPLE` or
+/// its Snowflake synonym `SAMPLE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleModifier {
+    TableSample,
+    Sample,
+}
+
+impl fmt::Display for TableSampleModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleModifier::TableSample => "TABLESAMPLE",
+            TableSampleModifier::Sample => "SAMPLE",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleMethod {
+    Bernoulli,
+    System,
+    /// Snowflake synonym for `BERNOULLI`
+    Row,
+    /// Snowflake synonym for `SYSTEM`
+    Block,
+}
+
+impl fmt::Display for TableSampleMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleMethod::Bernoulli => "BERNOULLI",
+            TableSampleMethod::System => "SYSTEM",
+            TableSampleMethod::Row => "ROW",
+            TableSampleMethod::Block => "BLOCK",
+        })
+    }
+}
+
+/// The parenthesized part of a table sample
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleSize {
+    /// `<value> [ ROWS | PERCENT ]`
+    Quantity {
+        value: Expr,
+        unit: Option<TableSampleUnit>,
+    },
+    /// Hive `BUCKET <bucket> OUT OF <total> [ ON <expr> ]`
+    Bucket {
+        bucket: Value,
+        total: Value,
+        on: Option<Box<Expr>>,
+    },
+}
+
+impl fmt::Display for TableSampleSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableSampleSize::Quantity { value, unit } => {
+                write!(f, "{}", value)?;
+                if let Some(unit) = unit {
+                    write!(f, " {}", unit)?;
+                }
+                Ok(())
+            }
+            TableSampleSize::Bucket { bucket, total, on } => {
+                write!(f, "BUCKET {} OUT OF {}", bucket, total)?;
+                if let Some(on) = on {
+                    write!(f, " ON {}", on)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleUnit {
+    Rows,
+    Percent,
+}
+
+impl fmt::Display for TableSampleUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleUnit::Rows => "ROWS",
+            TableSampleUnit::Percent => "PERCENT",
+        })
+    }
+}
+
+/// `REPEATABLE (<seed>)` or Snowflake `SEED (<seed>)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableSampleSeed {
+    pub modifier: TableSampleSeedModifier,
+    pub value: Value,
+}
+
+impl fmt::Display for TableSampleSeed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.modifier, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TableSampleSeedModifier {
+    Repeatable,
+    Seed,
+}
+
+impl fmt::Display for TableSampleSeedModifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TableSampleSeedModifier::Repeatable => "REPEATABLE",
+            TableSampleSeedModifier::Seed => "SEED",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableAlias {
     pub name: Ident,
-    pub columns: Vec<Ident>,
+    pub columns: Vec<TableAliasColumnDef>,
 }
 
 impl fmt::Display for TableAlias {
@@ -435,6 +1108,35 @@ impl fmt::Display for TableAlias {
     }
 }
 
+/// A column of a table alias, optionally with a type as in Postgres
+/// column definition lists, e.g. `AS t(a INT, b TEXT)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TableAliasColumnDef {
+    pub name: Ident,
+    pub data_type: Option<DataType>,
+}
+
+impl TableAliasColumnDef {
+    /// Create a column without a type
+    pub fn from_name<S: Into<String>>(name: S) -> Self {
+        TableAliasColumnDef {
+            name: Ident::new(name),
+            data_type: None,
+        }
+    }
+}
+
+impl fmt::Display for TableAliasColumnDef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(data_type) = &self.data_type {
+            write!(f, " {}", data_type)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Join {
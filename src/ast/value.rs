@@ -21,6 +21,8 @@ use core::ops::Neg;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ast::Ident;
+
 /// Primitive SQL values such as number and string
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -32,10 +34,14 @@ pub enum Value {
     Number(BigDecimal, bool),
     /// 'string value'
     SingleQuotedString(String),
+    /// PostgreSQL dollar-quoted string e.g. `$$string value$$` or `$tag$string value$tag$`
+    DollarQuotedString(DollarQuotedString),
     /// N'string value'
     NationalStringLiteral(String),
     /// X'hex value'
     HexStringLiteral(String),
+    /// B'bit value'
+    BitStringLiteral(String),
     /// :string value
     ColonString(String),
     /// .string value
@@ -44,27 +50,10 @@ pub enum Value {
     DoubleQuotedString(String),
     /// Boolean value true or false
     Boolean(bool),
-    /// INTERVAL literals, roughly in the following format:
-    /// `INTERVAL '<value>' [ <leading_field> [ (<leading_precision>) ] ]
-    /// [ TO <last_field> [ (<fractional_seconds_precision>) ] ]`,
-    /// e.g. `INTERVAL '123:45.67' MINUTE(3) TO SECOND(2)`.
-    ///
-    /// The parser does not validate the `<value>`, nor does it ensure
-    /// that the `<leading_field>` units >= the units in `<last_field>`,
-    /// so the user will have to reject intervals like `HOUR TO YEAR`.
-    Interval {
-        value: String,
-        leading_field: Option<DateTimeField>,
-        leading_precision: Option<u64>,
-        last_field: Option<DateTimeField>,
-        /// The seconds precision can be specified in SQL source as
-        /// `INTERVAL '__' SECOND(_, x)` (in which case the `leading_field`
-        /// will be `Second` and the `last_field` will be `None`),
-        /// or as `__ TO SECOND(x)`.
-        fractional_seconds_precision: Option<u64>,
-    },
     /// `NULL` value
     Null,
+    /// Positional parameter placeholder e.g. `$1`
+    Placeholder(String),
 }
 
 impl Value {
@@ -76,26 +65,19 @@ impl Value {
             Value::Number(x, v) => Value::Number(x.neg(), v),
 
             Value::SingleQuotedString(v) => Value::SingleQuotedString(format!("-{}", v)),
+            Value::DollarQuotedString(v) => Value::DollarQuotedString(DollarQuotedString {
+                value: format!("-{}", v.value),
+                tag: v.tag,
+            }),
             Value::NationalStringLiteral(v) => Value::NationalStringLiteral(format!("-{}", v)),
             Value::HexStringLiteral(v) => Value::HexStringLiteral(format!("-{}", v)),
+            Value::BitStringLiteral(v) => Value::BitStringLiteral(format!("-{}", v)),
             Value::DoubleQuotedString(v) => Value::DoubleQuotedString(format!("-{}", v)),
             Value::ColonString(v) => Value::ColonString(format!("-{}", v)),
             Value::PeriodString(v) => Value::PeriodString(format!("-{}", v)),
             Value::Boolean(v) => Value::Boolean(v),
-            Value::Interval {
-                value,
-                leading_field,
-                leading_precision,
-                last_field,
-                fractional_seconds_precision,
-            } => Value::Interval {
-                value: format!("-{}", value),
-                leading_field,
-                leading_precision,
-                last_field,
-                fractional_seconds_precision,
-            },
             Value::Null => Value::Null,
+            Value::Placeholder(v) => Value::Placeholder(format!("-{}", v)),
         }
     }
 }
@@ -106,65 +88,82 @@ impl fmt::Display for Value {
             Value::Number(v, l) => write!(f, "{}{long}", v, long = if *l { "L" } else { "" }),
             Value::DoubleQuotedString(v) => write!(f, "\"{}\"", v),
             Value::SingleQuotedString(v) => write!(f, "'{}'", escape_single_quote_string(v)),
+            Value::DollarQuotedString(v) => write!(f, "{}", v),
             Value::NationalStringLiteral(v) => write!(f, "N'{}'", v),
             Value::HexStringLiteral(v) => write!(f, "X'{}'", v),
+            Value::BitStringLiteral(v) => write!(f, "B'{}'", v),
             Value::ColonString(v) => write!(f, ":{}", v),
             Value::PeriodString(v) => write!(f, ".{}", v),
             Value::Boolean(v) => write!(f, "{}", v),
-            Value::Interval {
-                value,
-                leading_field: Some(DateTimeField::Second),
-                leading_precision: Some(leading_precision),
-                last_field,
-                fractional_seconds_precision: Some(fractional_seconds_precision),
-            } => {
-                // When the leading field is SECOND, the parser guarantees that
-                // the last field is None.
-                assert!(last_field.is_none());
-                write!(
-                    f,
-                    "INTERVAL '{}' SECOND ({}, {})",
-                    escape_single_quote_string(value),
-                    leading_precision,
-                    fractional_seconds_precision
-                )
-            }
-            Value::Interval {
-                value,
-                leading_field,
-                leading_precision,
-                last_field,
-                fractional_seconds_precision,
-            } => {
-                write!(f, "INTERVAL '{}'", escape_single_quote_string(value))?;
-                if let Some(leading_field) = leading_field {
-                    write!(f, " {}", leading_field)?;
-                }
-                if let Some(leading_precision) = leading_precision {
-                    write!(f, " ({})", leading_precision)?;
-                }
-                if let Some(last_field) = last_field {
-                    write!(f, " TO {}", last_field)?;
-                }
-                if let Some(fractional_seconds_precision) = fractional_seconds_precision {
-                    write!(f, " ({})", fractional_seconds_precision)?;
-                }
-                Ok(())
-            }
             Value::Null => write!(f, "NULL"),
+            Value::Placeholder(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// The value and optional tag of a PostgreSQL dollar-quoted string
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DollarQuotedString {
+    pub value: String,
+    pub tag: Option<String>,
+}
+
+impl fmt::Display for DollarQuotedString {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.tag {
+            Some(tag) => write!(f, "${}${}${}$", tag, self.value, tag),
+            None => write!(f, "$${}$$", self.value),
         }
     }
 }
 
+/// A date/time field e.g. the `YEAR` in `EXTRACT(YEAR FROM d)` or `INTERVAL '1' YEAR`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum DateTimeField {
     Year,
     Month,
+    Week,
     Day,
     Hour,
     Minute,
     Second,
+    Century,
+    Decade,
+    /// Day of the week
+    Dow,
+    /// Day of the year
+    Doy,
+    Epoch,
+    /// ISO 8601 day of the week
+    Isodow,
+    /// ISO 8601 week-numbering year
+    Isoyear,
+    Julian,
+    Microsecond,
+    Millennium,
+    Millisecond,
+    Nanosecond,
+    Quarter,
+    Timezone,
+    TimezoneHour,
+    TimezoneMinute,
+    /// MySQL compound field e.g. `INTERVAL '1 2' DAY_HOUR`
+    DayHour,
+    DayMinute,
+    DaySecond,
+    DayMicrosecond,
+    HourMinute,
+    HourSecond,
+    HourMicrosecond,
+    MinuteSecond,
+    MinuteMicrosecond,
+    SecondMicrosecond,
+    YearMonth,
+    /// A field the parser does not know about, such as the Snowflake
+    /// abbreviations `yy` or `mm`
+    Custom(Ident),
 }
 
 impl fmt::Display for DateTimeField {
@@ -172,10 +171,39 @@ impl fmt::Display for DateTimeField {
         f.write_str(match self {
             DateTimeField::Year => "YEAR",
             DateTimeField::Month => "MONTH",
+            DateTimeField::Week => "WEEK",
             DateTimeField::Day => "DAY",
             DateTimeField::Hour => "HOUR",
             DateTimeField::Minute => "MINUTE",
             DateTimeField::Second => "SECOND",
+            DateTimeField::Century => "CENTURY",
+            DateTimeField::Decade => "DECADE",
+            DateTimeField::Dow => "DOW",
+            DateTimeField::Doy => "DOY",
+            DateTimeField::Epoch => "EPOCH",
+            DateTimeField::Isodow => "ISODOW",
+            DateTimeField::Isoyear => "ISOYEAR",
+            DateTimeField::Julian => "JULIAN",
+            DateTimeField::Microsecond => "MICROSECOND",
+            DateTimeField::Millennium => "MILLENNIUM",
+            DateTimeField::Millisecond => "MILLISECOND",
+            DateTimeField::Nanosecond => "NANOSECOND",
+            DateTimeField::Quarter => "QUARTER",
+            DateTimeField::Timezone => "TIMEZONE",
+            DateTimeField::TimezoneHour => "TIMEZONE_HOUR",
+            DateTimeField::TimezoneMinute => "TIMEZONE_MINUTE",
+            DateTimeField::DayHour => "DAY_HOUR",
+            DateTimeField::DayMinute => "DAY_MINUTE",
+            DateTimeField::DaySecond => "DAY_SECOND",
+            DateTimeField::DayMicrosecond => "DAY_MICROSECOND",
+            DateTimeField::HourMinute => "HOUR_MINUTE",
+            DateTimeField::HourSecond => "HOUR_SECOND",
+            DateTimeField::HourMicrosecond => "HOUR_MICROSECOND",
+            DateTimeField::MinuteSecond => "MINUTE_SECOND",
+            DateTimeField::MinuteMicrosecond => "MINUTE_MICROSECOND",
+            DateTimeField::SecondMicrosecond => "SECOND_MICROSECOND",
+            DateTimeField::YearMonth => "YEAR_MONTH",
+            DateTimeField::Custom(ident) => return write!(f, "{}", ident),
         })
     }
 }
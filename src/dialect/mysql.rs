@@ -18,12 +18,14 @@ pub struct MySqlDialect {}
 impl Dialect for MySqlDialect {
     fn is_identifier_start(&self, ch: char) -> bool {
         // See https://dev.mysql.com/doc/refman/8.0/en/identifiers.html.
+        // User variables such as `@var` and `@@session.var` are treated as identifiers.
         // We don't yet support identifiers beginning with numbers, as that
         // makes it hard to distinguish numeric literals.
         ('a'..='z').contains(&ch)
             || ('A'..='Z').contains(&ch)
             || ch == '_'
             || ch == '$'
+            || ch == '@'
             || ('\u{0080}'..='\u{ffff}').contains(&ch)
     }
 
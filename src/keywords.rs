@@ -69,13 +69,17 @@ define_keywords!(
     ABS,
     ACTION,
     ADD,
+    AFTER,
+    AGAINST,
     ALL,
     ALLOCATE,
     ALTER,
+    ALWAYS,
     ANALYZE,
     AND,
     ANY,
     APPLY,
+    ARCHIVE,
     ARE,
     ARRAY,
     ARRAY_AGG,
@@ -92,15 +96,21 @@ define_keywords!(
     AUTO_INCREMENT,
     AVG,
     AVRO,
+    BEFORE,
     BEGIN,
     BEGIN_FRAME,
     BEGIN_PARTITION,
+    BERNOULLI,
     BETWEEN,
     BIGINT,
     BINARY,
+    BIT,
     BLOB,
+    BLOCK,
     BOOLEAN,
     BOTH,
+    BTREE,
+    BUCKET,
     BY,
     BYTEA,
     CACHE,
@@ -113,17 +123,21 @@ define_keywords!(
     CAST,
     CEIL,
     CEILING,
+    CENTURY,
     CHAIN,
     CHANGE,
+    CHANGES,
     CHAR,
     CHARACTER,
     CHARACTER_LENGTH,
+    CHARSET,
     CHAR_LENGTH,
     CHECK,
     CLOB,
     CLOSE,
     CLUSTER,
     COALESCE,
+    CODEC,
     COLLATE,
     COLLECT,
     COLUMN,
@@ -162,15 +176,23 @@ define_keywords!(
     CURRENT_USER,
     CURSOR,
     CYCLE,
+    DATA,
     DATABASE,
     DATE,
     DATETIME,
     DAY,
+    DAY_HOUR,
+    DAY_MICROSECOND,
+    DAY_MINUTE,
+    DAY_SECOND,
     DEALLOCATE,
     DEC,
+    DECADE,
     DECIMAL,
     DECLARE,
     DEFAULT,
+    DEFINE,
+    DEFINER,
     DELETE,
     DELIMITED,
     DENSE_RANK,
@@ -179,21 +201,29 @@ define_keywords!(
     DESCRIBE,
     DETERMINISTIC,
     DIRECTORY,
+    DISABLE,
     DISCONNECT,
     DISTINCT,
     DISTRIBUTE,
     DIV,
     DOUBLE,
+    DOW,
+    DOY,
     DROP,
     DUPLICATE,
     DYNAMIC,
     EACH,
     ELEMENT,
     ELSE,
+    EMPTY,
+    ENABLE,
     END,
     END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    ENGINE,
+    ENUM,
+    EPOCH,
     EQUALS,
     ERROR,
     ESCAPE,
@@ -204,6 +234,7 @@ define_keywords!(
     EXECUTE,
     EXISTS,
     EXP,
+    EXPANSION,
     EXPLAIN,
     EXTENDED,
     EXTERNAL,
@@ -212,10 +243,12 @@ define_keywords!(
     FALSE,
     FETCH,
     FIELDS,
+    FILE,
     FILTER,
     FIRST,
     FIRST_VALUE,
     FLOAT,
+    FLOAT64,
     FLOOR,
     FOLLOWING,
     FOR,
@@ -225,8 +258,11 @@ define_keywords!(
     FREE,
     FROM,
     FULL,
+    FULLTEXT,
     FUNCTION,
     FUSION,
+    GENERATED,
+    GEOMETRY,
     GET,
     GLOBAL,
     GRANT,
@@ -234,34 +270,52 @@ define_keywords!(
     GROUP,
     GROUPING,
     GROUPS,
+    HASH,
     HAVING,
     HEADER,
     HIVEVAR,
     HOLD,
     HOUR,
+    HOUR_MICROSECOND,
+    HOUR_MINUTE,
+    HOUR_SECOND,
     IDENTIFIED,
     IDENTITY,
     IF,
     IGNORE,
     ILIKE,
+    IMMUTABLE,
     IN,
+    INCREMENT,
     INDEX,
     INDICATOR,
+    INET,
+    INFORMATION,
+    INHERITS,
     INNER,
     INOUT,
+    INPUT,
     INPUTFORMAT,
     INSENSITIVE,
     INSERT,
     INT,
+    INT64,
     INTEGER,
     INTERSECT,
     INTERSECTION,
     INTERVAL,
     INTO,
+    INVOKER,
     IS,
+    ISODOW,
     ISOLATION,
+    ISOYEAR,
+    JAR,
     JOIN,
+    JSON,
+    JSONB,
     JSONFILE,
+    JULIAN,
     KEY,
     LAG,
     LANGUAGE,
@@ -272,6 +326,7 @@ define_keywords!(
     LEAD,
     LEADING,
     LEFT,
+    LESS,
     LEVEL,
     LIKE,
     LIKE_REGEX,
@@ -283,32 +338,60 @@ define_keywords!(
     LOCALTIME,
     LOCALTIMESTAMP,
     LOCATION,
+    LONGBLOB,
+    LONGTEXT,
     LOWER,
     MANAGEDLOCATION,
+    MAP,
+    MASKING,
     MATCH,
+    MATCHES,
+    MATCH_RECOGNIZE,
     MATERIALIZED,
     MAX,
+    MAXVALUE,
+    MEASURES,
+    MEDIUMBLOB,
+    MEDIUMINT,
+    MEDIUMTEXT,
     MEMBER,
     MERGE,
     METADATA,
     METHOD,
+    MICROSECOND,
+    MILLENNIUM,
+    MILLISECOND,
     MIN,
     MINUTE,
+    MINUTE_MICROSECOND,
+    MINUTE_SECOND,
+    MINVALUE,
     MOD,
+    MODE,
     MODIFIES,
+    MODIFY,
     MODULE,
+    MODULUS,
+    MONEY,
     MONTH,
     MSCK,
     MULTISET,
+    NAME,
+    NANOSECOND,
     NATIONAL,
     NATURAL,
     NCHAR,
     NCLOB,
     NEW,
     NEXT,
+    NFC,
+    NFD,
+    NFKC,
+    NFKD,
     NO,
     NONE,
     NORMALIZE,
+    NORMALIZED,
     NOSCAN,
     NOT,
     NTH_VALUE,
@@ -316,38 +399,53 @@ define_keywords!(
     NULL,
     NULLIF,
     NULLS,
+    NUMBER,
     NUMERIC,
+    NVARCHAR,
     OBJECT,
     OCCURRENCES_REGEX,
     OCTET_LENGTH,
     OF,
     OFFSET,
     OLD,
+    OMIT,
     ON,
+    ONE,
     ONLY,
     OPEN,
     OPTION,
     OR,
     ORC,
     ORDER,
+    ORDINALITY,
     OUT,
     OUTER,
+    OUTPUT,
     OUTPUTFORMAT,
     OVER,
     OVERFLOW,
     OVERLAPS,
     OVERLAY,
     OVERWRITE,
+    OWNED,
+    OWNER,
     PARAMETER,
     PARQUET,
     PARTITION,
     PARTITIONED,
     PARTITIONS,
+    PAST,
+    PATTERN,
+    PER,
     PERCENT,
     PERCENTILE_CONT,
     PERCENTILE_DISC,
     PERCENT_RANK,
     PERIOD,
+    PERMUTE,
+    PERSISTED,
+    PIVOT,
+    POLICY,
     PORTION,
     POSITION,
     POSITION_REGEX,
@@ -358,8 +456,11 @@ define_keywords!(
     PREPARE,
     PRIMARY,
     PRIVILEGES,
+    PROC,
     PROCEDURE,
     PURGE,
+    QUARTER,
+    QUERY,
     RANGE,
     RANK,
     RCFILE,
@@ -382,10 +483,12 @@ define_keywords!(
     REGR_SXY,
     REGR_SYY,
     RELEASE,
+    REMAINDER,
     RENAME,
     REPAIR,
     REPEATABLE,
     REPLACE,
+    RESTART,
     RESTRICT,
     RESULT,
     RETURN,
@@ -399,13 +502,19 @@ define_keywords!(
     ROW,
     ROWID,
     ROWS,
+    ROW_FORMAT,
     ROW_NUMBER,
+    SAFE_CAST,
+    SAMPLE,
     SAVEPOINT,
     SCHEMA,
     SCOPE,
     SCROLL,
     SEARCH,
     SECOND,
+    SECOND_MICROSECOND,
+    SECURITY,
+    SEED,
     SELECT,
     SENSITIVE,
     SEQUENCE,
@@ -416,13 +525,16 @@ define_keywords!(
     SESSION,
     SESSION_USER,
     SET,
+    SETOF,
     SETS,
     SHOW,
     SIMILAR,
+    SKIP,
     SMALLINT,
     SNAPSHOT,
     SOME,
     SORT,
+    SPATIAL,
     SPECIFIC,
     SPECIFICTYPE,
     SQL,
@@ -430,21 +542,27 @@ define_keywords!(
     SQLSTATE,
     SQLWARNING,
     SQRT,
+    STABLE,
     STAGE,
     START,
+    STATEMENT,
     STATIC,
     STATISTICS,
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
     STORED,
+    STREAM,
+    STRICT,
     STRING,
+    STRUCT,
     SUBMULTISET,
     SUBSTRING,
     SUBSTRING_REGEX,
     SUCCEEDS,
     SUM,
     SUPER,
+    SWAP,
     SYMMETRIC,
     SYNC,
     SYSTEM,
@@ -453,18 +571,27 @@ define_keywords!(
     TABLE,
     TABLES,
     TABLESAMPLE,
+    TABLESPACE,
     TBLPROPERTIES,
     TEMP,
     TEMPORARY,
     TEXT,
     TEXTFILE,
+    THAN,
     THEN,
     TIES,
     TIME,
     TIMESTAMP,
+    TIMESTAMPTZ,
+    TIMESTAMP_LTZ,
+    TIMESTAMP_NTZ,
+    TIMETZ,
+    TIMEZONE,
     TIMEZONE_HOUR,
     TIMEZONE_MINUTE,
+    TINYBLOB,
     TINYINT,
+    TINYTEXT,
     TO,
     TOP,
     TRAILING,
@@ -479,14 +606,18 @@ define_keywords!(
     TRUE,
     TRUNCATE,
     TRY_CAST,
+    TTL,
     TUPLE,
+    TYPE,
     UESCAPE,
     UNBOUNDED,
     UNCOMMITTED,
     UNION,
     UNIQUE,
     UNKNOWN,
+    UNMATCHED,
     UNNEST,
+    UNPIVOT,
     UNSIGNED,
     UPDATE,
     UPPER,
@@ -499,13 +630,18 @@ define_keywords!(
     VALUE_OF,
     VARBINARY,
     VARCHAR,
+    VARIADIC,
+    VARIANT,
     VARYING,
     VAR_POP,
     VAR_SAMP,
     VERBOSE,
+    VERSION,
     VERSIONING,
     VIEW,
     VIRTUAL,
+    VOLATILE,
+    WEEK,
     WHEN,
     WHENEVER,
     WHERE,
@@ -518,6 +654,7 @@ define_keywords!(
     WRITE,
     XOR,
     YEAR,
+    YEAR_MONTH,
     ZONE
 );
 
@@ -543,6 +680,8 @@ pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    // for the `END` of a MSSQL `BEGIN ... END` block
+    Keyword::END,
     // Reserved only as a table alias in the `FROM`/`JOIN` clauses:
     Keyword::ON,
     Keyword::JOIN,
@@ -585,6 +724,7 @@ pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    Keyword::END,
     Keyword::CLUSTER,
     Keyword::DISTRIBUTE,
     // Reserved only as a column alias in the `SELECT` clause
@@ -469,6 +469,22 @@ impl<'a> Parser<'a> {
                 Keyword::CASE => self.parse_case_expr(),
                 Keyword::CAST => self.parse_cast_expr(),
                 Keyword::TRY_CAST => self.parse_try_cast_expr(),
+                Keyword::SAFE_CAST => self.parse_safe_cast_expr(),
+                Keyword::MATCH
+                    if dialect_of!(self is MySqlDialect | GenericDialect)
+                        && self.peek_token() == Token::LParen =>
+                {
+                    match self.maybe_parse(|parser| parser.parse_match_against()) {
+                        Some(expr) => Ok(expr),
+                        None => self.parse_function(ObjectName(vec![w.to_ident()])),
+                    }
+                }
+                Keyword::CONVERT if self.peek_token() == Token::LParen => {
+                    match self.maybe_parse(|parser| parser.parse_convert_expr()) {
+                        Some(expr) => Ok(expr),
+                        None => self.parse_function(ObjectName(vec![w.to_ident()])),
+                    }
+                }
                 Keyword::EXISTS => self.parse_exists_expr(),
                 Keyword::EXTRACT => self.parse_extract_expr(),
                 Keyword::POSITION => self.parse_position_expr(),
@@ -480,9 +496,67 @@ impl<'a> Parser<'a> {
                     op: UnaryOperator::Not,
                     expr: Box::new(self.parse_subexpr(Self::UNARY_NOT_PREC)?),
                 }),
-                Keyword::ARRAY => Ok(Expr::Array(
-                    self.parse_token_wrapped_exprs(&Token::LParen, &Token::RParen)?,
-                )),
+                Keyword::CEIL | Keyword::FLOOR if self.peek_token() == Token::LParen => {
+                    let is_ceil = w.keyword == Keyword::CEIL;
+                    match self.maybe_parse(|parser| parser.parse_ceil_floor_to_expr(is_ceil)) {
+                        Some(expr) => Ok(expr),
+                        None => self.parse_function(ObjectName(vec![w.to_ident()])),
+                    }
+                }
+                Keyword::ROW if self.peek_token() == Token::LParen => {
+                    let values = self.parse_token_wrapped_exprs(&Token::LParen, &Token::RParen)?;
+                    Ok(Expr::Row(values))
+                }
+                Keyword::STRUCT
+                    if dialect_of!(self is GenericDialect)
+                        && matches!(self.peek_token(), Token::LParen | Token::Lt) =>
+                {
+                    self.parse_struct_literal()
+                }
+                Keyword::MAP
+                    if dialect_of!(self is GenericDialect)
+                        && self.peek_token() == Token::LBrace =>
+                {
+                    self.parse_map_literal()
+                }
+                Keyword::ARRAY if self.peek_token() == Token::LBracket => {
+                    self.parse_array_expr(true)
+                }
+                Keyword::ARRAY
+                    if self.peek_token() == Token::LParen
+                        && matches!(
+                            self.peek_nth_token(1),
+                            Token::Word(w) if w.keyword == Keyword::SELECT || w.keyword == Keyword::WITH
+                        ) =>
+                {
+                    self.expect_token(&Token::LParen)?;
+                    let query = self.parse_query()?;
+                    self.expect_token(&Token::RParen)?;
+                    Ok(Expr::ArraySubquery(Box::new(query)))
+                }
+                // Hive's `ARRAY(1, 2)` constructor
+                Keyword::ARRAY
+                    if self.peek_token() == Token::LParen && dialect_of!(self is HiveDialect) =>
+                {
+                    let elem = self.parse_token_wrapped_exprs(&Token::LParen, &Token::RParen)?;
+                    Ok(Expr::Array(Array { elem, named: false }))
+                }
+                // MySQL character set introducer e.g. `_utf8mb4'abc'`
+                _ if w.value.starts_with('_')
+                    && w.quote_style.is_none()
+                    && dialect_of!(self is MySqlDialect)
+                    && matches!(
+                        self.peek_token(),
+                        Token::SingleQuotedString(_)
+                            | Token::HexStringLiteral(_)
+                            | Token::BitStringLiteral(_)
+                    ) =>
+                {
+                    Ok(Expr::IntroducedString {
+                        introducer: w.value,
+                        value: self.parse_value()?,
+                    })
+                }
                 // Here `w` is a word, check if it's a part of a multi-part
                 // identifier, a function call, or a simple identifier:
                 _ => match self.peek_token() {
@@ -521,13 +595,13 @@ impl<'a> Parser<'a> {
 
                 let expr = self.parse_subexpr(Self::PLUS_MINUS_PREC)?;
 
-                if let Expr::Value(v) = &expr {
-                    if tok == Token::Plus {
-                        return Ok(expr);
-                    } else {
-                        return Ok(Expr::Value(v.clone().to_negative()));
-                    }
-                };
+                // a dollar-quoted string keeps its sign as a unary operator
+                match &expr {
+                    Expr::Value(Value::DollarQuotedString(_)) => {}
+                    Expr::Value(_) if tok == Token::Plus => return Ok(expr),
+                    Expr::Value(v) => return Ok(Expr::Value(v.clone().to_negative())),
+                    _ => {}
+                }
 
                 Ok(Expr::UnaryOp {
                     op,
@@ -557,7 +631,10 @@ impl<'a> Parser<'a> {
             Token::Number(_, _)
             | Token::SingleQuotedString(_)
             | Token::NationalStringLiteral(_)
-            | Token::HexStringLiteral(_) => {
+            | Token::HexStringLiteral(_)
+            | Token::BitStringLiteral(_)
+            | Token::DollarQuotedString(_)
+            | Token::Placeholder(_) => {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
@@ -583,22 +660,13 @@ impl<'a> Parser<'a> {
             }
             Token::LBracket => {
                 self.prev_token();
-                Ok(Expr::Array(self.parse_token_wrapped_exprs(
-                    &Token::LBracket,
-                    &Token::RBracket,
-                )?))
+                self.parse_array_expr(false)
             }
+            Token::LBrace if dialect_of!(self is GenericDialect) => self.parse_dictionary_literal(),
             unexpected => self.expected("an expression:", unexpected),
         }?;
 
-        if self.parse_keyword(Keyword::COLLATE) {
-            Ok(Expr::Collate {
-                expr: Box::new(expr),
-                collation: self.parse_object_name()?,
-            })
-        } else {
-            Ok(expr)
-        }
+        Ok(expr)
     }
 
     pub fn parse_function(&mut self, name: ObjectName) -> Result<Expr, ParserError> {
@@ -799,11 +867,13 @@ impl<'a> Parser<'a> {
             self.expect_keyword(Keyword::AS)?;
         }
         let data_type = self.parse_data_type()?;
+        let format = self.parse_optional_cast_format()?;
         self.expect_token(&Token::RParen)?;
         Ok(Expr::Cast {
             expr: Box::new(expr),
             data_type,
             pg_style: false,
+            format,
         })
     }
 
@@ -815,10 +885,123 @@ impl<'a> Parser<'a> {
             self.expect_keyword(Keyword::AS)?;
         }
         let data_type = self.parse_data_type()?;
+        let format = self.parse_optional_cast_format()?;
         self.expect_token(&Token::RParen)?;
         Ok(Expr::TryCast {
             expr: Box::new(expr),
             data_type,
+            format,
+        })
+    }
+
+    /// Parse a BigQuery SAFE_CAST function e.g. `SAFE_CAST(expr AS INT64)`
+    pub fn parse_safe_cast_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::AS)?;
+        let data_type = self.parse_data_type()?;
+        let format = self.parse_optional_cast_format()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::SafeCast {
+            expr: Box::new(expr),
+            data_type,
+            format,
+        })
+    }
+
+    /// Parse the optional `FORMAT <string> [AT TIME ZONE <string>]` of a cast
+    pub fn parse_optional_cast_format(&mut self) -> Result<Option<Box<CastFormat>>, ParserError> {
+        if !self.parse_keyword(Keyword::FORMAT) {
+            return Ok(None);
+        }
+        let value = self.parse_value()?;
+        let format = if self.parse_keywords(&[Keyword::AT, Keyword::TIME, Keyword::ZONE]) {
+            CastFormat::ValueAtTimeZone(value, self.parse_value()?)
+        } else {
+            CastFormat::Value(value)
+        };
+        Ok(Some(Box::new(format)))
+    }
+
+    /// Parse a CONVERT function, either MSSQL `CONVERT(VARCHAR(10), expr, 120)`
+    /// or MySQL `CONVERT(expr USING utf8mb4)` / `CONVERT(expr, CHAR)`
+    pub fn parse_convert_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        if dialect_of!(self is MsSqlDialect) {
+            let data_type = self.parse_data_type()?;
+            self.expect_token(&Token::Comma)?;
+            let expr = self.parse_expr()?;
+            let styles = if self.consume_token(&Token::Comma) {
+                self.parse_comma_separated(Parser::parse_expr)?
+            } else {
+                vec![]
+            };
+            self.expect_token(&Token::RParen)?;
+            return Ok(Expr::Convert {
+                expr: Box::new(expr),
+                data_type: Some(data_type),
+                charset: None,
+                target_before_value: true,
+                styles,
+            });
+        }
+        let expr = self.parse_expr()?;
+        if self.parse_keyword(Keyword::USING) {
+            let charset = self.parse_object_name()?;
+            self.expect_token(&Token::RParen)?;
+            return Ok(Expr::Convert {
+                expr: Box::new(expr),
+                data_type: None,
+                charset: Some(charset),
+                target_before_value: false,
+                styles: vec![],
+            });
+        }
+        self.expect_token(&Token::Comma)?;
+        let data_type = self.parse_data_type()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Convert {
+            expr: Box::new(expr),
+            data_type: Some(data_type),
+            charset: None,
+            target_before_value: false,
+            styles: vec![],
+        })
+    }
+
+    /// Parse a MySQL full-text search e.g.
+    /// `MATCH (title, body) AGAINST ('term' IN BOOLEAN MODE)`
+    pub fn parse_match_against(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let columns = self.parse_comma_separated(Parser::parse_object_name)?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_keyword(Keyword::AGAINST)?;
+        self.expect_token(&Token::LParen)?;
+        // Parse above `IN` so that it starts the search modifier rather than an IN list
+        let match_value = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let search_modifier = if self.parse_keywords(&[
+            Keyword::IN,
+            Keyword::NATURAL,
+            Keyword::LANGUAGE,
+            Keyword::MODE,
+        ]) {
+            if self.parse_keywords(&[Keyword::WITH, Keyword::QUERY, Keyword::EXPANSION]) {
+                Some(SearchModifier::InNaturalLanguageModeWithQueryExpansion)
+            } else {
+                Some(SearchModifier::InNaturalLanguageMode)
+            }
+        } else if self.parse_keywords(&[Keyword::IN, Keyword::BOOLEAN, Keyword::MODE]) {
+            Some(SearchModifier::InBooleanMode)
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::QUERY, Keyword::EXPANSION]) {
+            Some(SearchModifier::WithQueryExpansion)
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::MatchAgainst {
+            columns,
+            match_value: Box::new(match_value),
+            search_modifier,
         })
     }
 
@@ -992,19 +1175,73 @@ impl<'a> Parser<'a> {
     // be split in two.
     pub fn parse_date_time_field(&mut self) -> Result<DateTimeField, ParserError> {
         match self.next_token() {
-            Token::Word(w) => match w.keyword {
-                Keyword::YEAR => Ok(DateTimeField::Year),
-                Keyword::MONTH => Ok(DateTimeField::Month),
-                Keyword::DAY => Ok(DateTimeField::Day),
-                Keyword::HOUR => Ok(DateTimeField::Hour),
-                Keyword::MINUTE => Ok(DateTimeField::Minute),
-                Keyword::SECOND => Ok(DateTimeField::Second),
-                _ => self.expected("date/time field", Token::Word(w))?,
+            Token::Word(w) => match Self::date_time_field_from_keyword(w.keyword) {
+                Some(field) => Ok(field),
+                // Snowflake accepts many abbreviations and synonyms, e.g. `yy` or `mm`
+                None if dialect_of!(self is SnowflakeDialect | GenericDialect) => {
+                    Ok(DateTimeField::Custom(w.to_ident()))
+                }
+                None => self.expected("date/time field", Token::Word(w)),
             },
             unexpected => self.expected("date/time field", unexpected),
         }
     }
 
+    fn date_time_field_from_keyword(keyword: Keyword) -> Option<DateTimeField> {
+        Some(match keyword {
+            Keyword::YEAR => DateTimeField::Year,
+            Keyword::MONTH => DateTimeField::Month,
+            Keyword::WEEK => DateTimeField::Week,
+            Keyword::DAY => DateTimeField::Day,
+            Keyword::HOUR => DateTimeField::Hour,
+            Keyword::MINUTE => DateTimeField::Minute,
+            Keyword::SECOND => DateTimeField::Second,
+            Keyword::CENTURY => DateTimeField::Century,
+            Keyword::DECADE => DateTimeField::Decade,
+            Keyword::DOW => DateTimeField::Dow,
+            Keyword::DOY => DateTimeField::Doy,
+            Keyword::EPOCH => DateTimeField::Epoch,
+            Keyword::ISODOW => DateTimeField::Isodow,
+            Keyword::ISOYEAR => DateTimeField::Isoyear,
+            Keyword::JULIAN => DateTimeField::Julian,
+            Keyword::MICROSECOND => DateTimeField::Microsecond,
+            Keyword::MILLENNIUM => DateTimeField::Millennium,
+            Keyword::MILLISECOND => DateTimeField::Millisecond,
+            Keyword::NANOSECOND => DateTimeField::Nanosecond,
+            Keyword::QUARTER => DateTimeField::Quarter,
+            Keyword::TIMEZONE => DateTimeField::Timezone,
+            Keyword::TIMEZONE_HOUR => DateTimeField::TimezoneHour,
+            Keyword::TIMEZONE_MINUTE => DateTimeField::TimezoneMinute,
+            Keyword::DAY_HOUR => DateTimeField::DayHour,
+            Keyword::DAY_MINUTE => DateTimeField::DayMinute,
+            Keyword::DAY_SECOND => DateTimeField::DaySecond,
+            Keyword::DAY_MICROSECOND => DateTimeField::DayMicrosecond,
+            Keyword::HOUR_MINUTE => DateTimeField::HourMinute,
+            Keyword::HOUR_SECOND => DateTimeField::HourSecond,
+            Keyword::HOUR_MICROSECOND => DateTimeField::HourMicrosecond,
+            Keyword::MINUTE_SECOND => DateTimeField::MinuteSecond,
+            Keyword::MINUTE_MICROSECOND => DateTimeField::MinuteMicrosecond,
+            Keyword::SECOND_MICROSECOND => DateTimeField::SecondMicrosecond,
+            Keyword::YEAR_MONTH => DateTimeField::YearMonth,
+            _ => return None,
+        })
+    }
+
+    /// Parse `CEIL(<expr> TO <field>)` or `FLOOR(<expr> TO <field>)`, assuming
+    /// the function name was already consumed
+    pub fn parse_ceil_floor_to_expr(&mut self, is_ceil: bool) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = Box::new(self.parse_expr()?);
+        self.expect_keyword(Keyword::TO)?;
+        let field = self.parse_date_time_field()?;
+        self.expect_token(&Token::RParen)?;
+        if is_ceil {
+            Ok(Expr::Ceil { expr, field })
+        } else {
+            Ok(Expr::Floor { expr, field })
+        }
+    }
+
     /// Parse an INTERVAL literal.
     ///
     /// Some syntactically valid intervals:
@@ -1024,8 +1261,15 @@ impl<'a> Parser<'a> {
         // inside the value string.)
 
         // The first token in an interval is a string literal which specifies
-        // the duration of the interval.
-        let value = self.parse_literal_string()?;
+        // the duration of the interval. MySQL and ClickHouse also accept an unquoted
+        // expression, e.g. `INTERVAL 1 DAY`, which must be followed by a unit.
+        let unquoted = dialect_of!(self is MySqlDialect | ClickHouseDialect | GenericDialect)
+            && !matches!(self.peek_token(), Token::SingleQuotedString(_));
+        let value = if unquoted {
+            self.parse_expr()?
+        } else {
+            Expr::Value(Value::SingleQuotedString(self.parse_literal_string()?))
+        };
 
         // Following the string literal is a qualifier which indicates the units
         // of the duration specified in the string literal.
@@ -1033,20 +1277,10 @@ impl<'a> Parser<'a> {
         // Note that PostgreSQL allows omitting the qualifier, so we provide
         // this more general implemenation.
         let leading_field = match self.peek_token() {
-            Token::Word(kw)
-                if [
-                    Keyword::YEAR,
-                    Keyword::MONTH,
-                    Keyword::DAY,
-                    Keyword::HOUR,
-                    Keyword::MINUTE,
-                    Keyword::SECOND,
-                ]
-                .iter()
-                .any(|d| kw.keyword == *d) =>
-            {
+            Token::Word(kw) if Self::date_time_field_from_keyword(kw.keyword).is_some() => {
                 Some(self.parse_date_time_field()?)
             }
+            _ if unquoted => return self.expected("date/time field", self.peek_token()),
             _ => None,
         };
 
@@ -1075,13 +1309,13 @@ impl<'a> Parser<'a> {
                 }
             };
 
-        Ok(Expr::Value(Value::Interval {
-            value,
+        Ok(Expr::Interval {
+            value: Box::new(value),
             leading_field,
             leading_precision,
             last_field,
             fractional_seconds_precision: fsec_precision,
-        }))
+        })
     }
 
     /// Parse an operator following an expression
@@ -1118,20 +1352,25 @@ impl<'a> Parser<'a> {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::Arrow),
+            Token::LongArrow => Some(BinaryOperator::LongArrow),
+            Token::HashArrow => Some(BinaryOperator::HashArrow),
+            Token::HashLongArrow => Some(BinaryOperator::HashLongArrow),
+            Token::AtArrow => Some(BinaryOperator::AtArrow),
+            Token::ArrowAt => Some(BinaryOperator::ArrowAt),
+            Token::Question => Some(BinaryOperator::Question),
+            Token::QuestionPipe => Some(BinaryOperator::QuestionPipe),
+            Token::QuestionAnd => Some(BinaryOperator::QuestionAnd),
+            Token::AtQuestion => Some(BinaryOperator::AtQuestion),
+            Token::Assignment => Some(BinaryOperator::Assignment),
             Token::Word(w) => match w.keyword {
                 Keyword::DIV => Some(BinaryOperator::Div),
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
-                Keyword::LIKE => Some(BinaryOperator::Like),
-                Keyword::ILIKE => Some(BinaryOperator::ILike),
                 Keyword::REGEXP => Some(BinaryOperator::Regexp),
                 Keyword::RLIKE => Some(BinaryOperator::RLike),
                 Keyword::NOT => {
-                    if self.parse_keyword(Keyword::LIKE) {
-                        Some(BinaryOperator::NotLike)
-                    } else if self.parse_keyword(Keyword::ILIKE) {
-                        Some(BinaryOperator::NotILike)
-                    } else if self.parse_keyword(Keyword::REGEXP) {
+                    if self.parse_keyword(Keyword::REGEXP) {
                         Some(BinaryOperator::NotRegexp)
                     } else if self.parse_keyword(Keyword::RLIKE) {
                         Some(BinaryOperator::NotRLike)
@@ -1146,18 +1385,69 @@ impl<'a> Parser<'a> {
         };
 
         if let Some(op) = regular_binary_operator {
+            if matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+            ) && self.peek_nth_token(1) == Token::LParen
+            {
+                if let Some(keyword) =
+                    self.parse_one_of_keywords(&[Keyword::ANY, Keyword::SOME, Keyword::ALL])
+                {
+                    let right = Box::new(self.parse_quantified_operand()?);
+                    return Ok(match keyword {
+                        Keyword::ALL => Expr::AllOp {
+                            left: Box::new(expr),
+                            compare_op: op,
+                            right,
+                        },
+                        _ => Expr::AnyOp {
+                            left: Box::new(expr),
+                            compare_op: op,
+                            right,
+                            is_some: keyword == Keyword::SOME,
+                        },
+                    });
+                }
+            }
+            // `:=` is right-associative, so `@a := @b := 1` assigns to both
+            let right_precedence = if op == BinaryOperator::Assignment {
+                precedence - 1
+            } else {
+                precedence
+            };
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(self.parse_subexpr(right_precedence)?),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
+                Keyword::COLLATE => Ok(Expr::Collate {
+                    expr: Box::new(expr),
+                    collation: self.parse_object_name()?,
+                }),
                 Keyword::IS => {
                     if self.parse_keyword(Keyword::NULL) {
                         Ok(Expr::IsNull(Box::new(expr)))
                     } else if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
                         Ok(Expr::IsNotNull(Box::new(expr)))
+                    } else if self.parse_keyword(Keyword::TRUE) {
+                        Ok(Expr::IsTrue(Box::new(expr)))
+                    } else if self.parse_keywords(&[Keyword::NOT, Keyword::TRUE]) {
+                        Ok(Expr::IsNotTrue(Box::new(expr)))
+                    } else if self.parse_keyword(Keyword::FALSE) {
+                        Ok(Expr::IsFalse(Box::new(expr)))
+                    } else if self.parse_keywords(&[Keyword::NOT, Keyword::FALSE]) {
+                        Ok(Expr::IsNotFalse(Box::new(expr)))
+                    } else if self.parse_keyword(Keyword::UNKNOWN) {
+                        Ok(Expr::IsUnknown(Box::new(expr)))
+                    } else if self.parse_keywords(&[Keyword::NOT, Keyword::UNKNOWN]) {
+                        Ok(Expr::IsNotUnknown(Box::new(expr)))
                     } else if self.parse_keywords(&[Keyword::DISTINCT, Keyword::FROM]) {
                         let expr2 = self.parse_expr()?;
                         Ok(Expr::IsDistinctFrom(Box::new(expr), Box::new(expr2)))
@@ -1166,21 +1456,70 @@ impl<'a> Parser<'a> {
                         let expr2 = self.parse_expr()?;
                         Ok(Expr::IsNotDistinctFrom(Box::new(expr), Box::new(expr2)))
                     } else {
-                        self.expected(
-                            "[NOT] NULL or [NOT] DISTINCT FROM after IS",
-                            self.peek_token(),
-                        )
+                        let index = self.index;
+                        let negated = self.parse_keyword(Keyword::NOT);
+                        let form = match self.parse_one_of_keywords(&[
+                            Keyword::NFC,
+                            Keyword::NFD,
+                            Keyword::NFKC,
+                            Keyword::NFKD,
+                        ]) {
+                            Some(Keyword::NFC) => Some(NormalizationForm::NFC),
+                            Some(Keyword::NFD) => Some(NormalizationForm::NFD),
+                            Some(Keyword::NFKC) => Some(NormalizationForm::NFKC),
+                            Some(Keyword::NFKD) => Some(NormalizationForm::NFKD),
+                            _ => None,
+                        };
+                        if self.parse_keyword(Keyword::NORMALIZED) {
+                            Ok(Expr::IsNormalized {
+                                expr: Box::new(expr),
+                                form,
+                                negated,
+                            })
+                        } else {
+                            self.index = index;
+                            self.expected(
+                                "[NOT] NULL, TRUE, FALSE, UNKNOWN, NORMALIZED or [NOT] DISTINCT FROM after IS",
+                                self.peek_token(),
+                            )
+                        }
                     }
                 }
-                Keyword::NOT | Keyword::IN | Keyword::BETWEEN => {
+                Keyword::AT => {
+                    self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                    Ok(Expr::AtTimeZone {
+                        timestamp: Box::new(expr),
+                        time_zone: Box::new(self.parse_subexpr(precedence)?),
+                    })
+                }
+                Keyword::NOT
+                | Keyword::IN
+                | Keyword::BETWEEN
+                | Keyword::LIKE
+                | Keyword::ILIKE
+                | Keyword::SIMILAR => {
                     self.prev_token();
                     let negated = self.parse_keyword(Keyword::NOT);
                     if self.parse_keyword(Keyword::IN) {
                         self.parse_in(expr, negated)
                     } else if self.parse_keyword(Keyword::BETWEEN) {
                         self.parse_between(expr, negated)
+                    } else if self.parse_keyword(Keyword::LIKE) {
+                        self.parse_like(expr, negated, false)
+                    } else if self.parse_keyword(Keyword::ILIKE) {
+                        self.parse_like(expr, negated, true)
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Ok(Expr::SimilarTo {
+                            negated,
+                            expr: Box::new(expr),
+                            pattern: Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?),
+                            escape_char: self.parse_escape_char()?,
+                        })
                     } else {
-                        self.expected("IN or BETWEEN after NOT", self.peek_token())
+                        self.expected(
+                            "IN, BETWEEN, LIKE, ILIKE or SIMILAR TO after NOT",
+                            self.peek_token(),
+                        )
                     }
                 }
                 // Can only happen if `get_next_precedence` got out of sync with this function
@@ -1195,10 +1534,63 @@ impl<'a> Parser<'a> {
                 expr: Box::new(expr),
             })
         } else if Token::LBracket == tok {
-            self.prev_token();
-            self.parse_map_access(expr)
+            if dialect_of!(
+                self is PostgreSqlDialect | GenericDialect | SnowflakeDialect | HiveDialect
+            ) {
+                // literal keys keep their `MapAccess` form e.g. `foo[0]['key']`, any
+                // other subscript is an array index or slice
+                match expr {
+                    Expr::Identifier(_)
+                    | Expr::CompoundIdentifier(_)
+                    | Expr::Array(_)
+                    | Expr::Nested(_)
+                    | Expr::Function(_)
+                        if self.peek_literal_subscript_key() =>
+                    {
+                        self.prev_token();
+                        let mut keys = vec![];
+                        while self.peek_token() == Token::LBracket {
+                            self.next_token();
+                            if !self.peek_literal_subscript_key() {
+                                self.prev_token();
+                                break;
+                            }
+                            keys.push(self.parse_map_key()?);
+                            self.expect_token(&Token::RBracket)?;
+                        }
+                        Ok(Expr::MapAccess {
+                            column: Box::new(expr),
+                            keys,
+                        })
+                    }
+                    _ => self.parse_array_subscript(expr),
+                }
+            } else {
+                self.prev_token();
+                self.parse_map_access(expr)
+            }
+        } else if Token::Period == tok {
+            // only a parenthesized expression, a function call, a struct literal or a
+            // (composite) column may have its fields accessed, e.g. `(item).price`
+            match expr {
+                Expr::Nested(_)
+                | Expr::Function(_)
+                | Expr::Struct { .. }
+                | Expr::Identifier(_)
+                | Expr::CompositeAccess { .. } => Ok(Expr::CompositeAccess {
+                    expr: Box::new(expr),
+                    key: self.parse_identifier()?,
+                }),
+                _ => parser_err!(format!(
+                    "Expected a composite value before '.', found: {}",
+                    expr
+                )),
+            }
         } else if Token::Colon == tok {
-            self.parse_map_access(expr)
+            Ok(Expr::JsonAccess {
+                value: Box::new(expr),
+                path: self.parse_json_path()?,
+            })
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             parser_err!(format!("No infix parser for token {:?}", tok))
@@ -1236,6 +1628,173 @@ impl<'a> Parser<'a> {
         Ok(key_parts)
     }
 
+    /// Parse a path into a semi-structured value, such as `a.b[0]` in Snowflake
+    /// `col:a.b[0]`, assuming the leading `:` was already consumed
+    pub fn parse_json_path(&mut self) -> Result<JsonPath, ParserError> {
+        let mut path = vec![self.parse_json_path_object_key()?];
+        loop {
+            if self.consume_token(&Token::Period) {
+                path.push(self.parse_json_path_object_key()?);
+            } else if self.consume_token(&Token::LBracket) {
+                let key = self.parse_expr()?;
+                self.expect_token(&Token::RBracket)?;
+                path.push(JsonPathElem::Bracket { key });
+            } else {
+                break;
+            }
+        }
+        Ok(JsonPath { path })
+    }
+
+    fn parse_json_path_object_key(&mut self) -> Result<JsonPathElem, ParserError> {
+        match self.next_token() {
+            Token::Word(w) => Ok(JsonPathElem::Dot {
+                key: w.value,
+                quoted: w.quote_style.is_some(),
+            }),
+            Token::DoubleQuotedString(key) => Ok(JsonPathElem::Dot { key, quoted: true }),
+            unexpected => self.expected("literal string", unexpected),
+        }
+    }
+
+    /// Parse a struct constructor, `STRUCT(1 AS a)` or `STRUCT<a INT64>(1)`,
+    /// assuming the `STRUCT` keyword was already consumed
+    pub fn parse_struct_literal(&mut self) -> Result<Expr, ParserError> {
+        let fields = if self.peek_token() == Token::Lt {
+            let (fields, trailing_bracket) = self.parse_struct_type_def()?;
+            if trailing_bracket {
+                return parser_err!("unmatched > after STRUCT field types");
+            }
+            fields
+        } else {
+            vec![]
+        };
+        self.expect_token(&Token::LParen)?;
+        let values = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let values = self.parse_comma_separated(|parser| {
+                let expr = parser.parse_expr()?;
+                if parser.parse_keyword(Keyword::AS) {
+                    Ok(Expr::Named {
+                        expr: Box::new(expr),
+                        name: parser.parse_identifier()?,
+                    })
+                } else {
+                    Ok(expr)
+                }
+            })?;
+            self.expect_token(&Token::RParen)?;
+            values
+        };
+        Ok(Expr::Struct { values, fields })
+    }
+
+    /// Parse a DuckDB struct literal `{'a': 1}`, assuming the `{` was already consumed
+    pub fn parse_dictionary_literal(&mut self) -> Result<Expr, ParserError> {
+        if self.consume_token(&Token::RBrace) {
+            return Ok(Expr::Dictionary(vec![]));
+        }
+        let fields = self.parse_comma_separated(|parser| {
+            let key = parser.parse_identifier()?;
+            parser.expect_token(&Token::Colon)?;
+            Ok(DictionaryField {
+                key,
+                value: Box::new(parser.parse_expr()?),
+            })
+        })?;
+        self.expect_token(&Token::RBrace)?;
+        Ok(Expr::Dictionary(fields))
+    }
+
+    /// Parse a DuckDB map literal `MAP {'k': v}`, assuming the `MAP` keyword
+    /// was already consumed
+    pub fn parse_map_literal(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LBrace)?;
+        if self.consume_token(&Token::RBrace) {
+            return Ok(Expr::Map(vec![]));
+        }
+        let entries = self.parse_comma_separated(|parser| {
+            // the key stops before `:`, which would otherwise be parsed as
+            // the start of a semi-structured path
+            let key = parser.parse_subexpr(Self::COLON_PREC)?;
+            parser.expect_token(&Token::Colon)?;
+            Ok(MapEntry {
+                key: Box::new(key),
+                value: Box::new(parser.parse_expr()?),
+            })
+        })?;
+        self.expect_token(&Token::RBrace)?;
+        Ok(Expr::Map(entries))
+    }
+
+    /// Parse an array literal, `[1, 2]` or with `named` set `ARRAY[1, 2]`,
+    /// assuming the `ARRAY` keyword was already consumed
+    pub fn parse_array_expr(&mut self, named: bool) -> Result<Expr, ParserError> {
+        let elem = self.parse_token_wrapped_exprs(&Token::LBracket, &Token::RBracket)?;
+        Ok(Expr::Array(Array { elem, named }))
+    }
+
+    /// Parse an array subscript `[index]` or slice `[lower:upper]` applied to
+    /// `expr`, assuming the `[` was already consumed
+    pub fn parse_array_subscript(&mut self, expr: Expr) -> Result<Expr, ParserError> {
+        let subscript = if self.consume_token(&Token::Colon) {
+            self.parse_array_slice(expr, None)?
+        } else {
+            let index = Box::new(self.parse_subscript_expr()?);
+            if self.consume_token(&Token::Colon) {
+                self.parse_array_slice(expr, Some(index))?
+            } else {
+                Expr::ArrayIndex {
+                    obj: Box::new(expr),
+                    index,
+                }
+            }
+        };
+        self.expect_token(&Token::RBracket)?;
+        Ok(subscript)
+    }
+
+    /// Whether the tokens following a `[` are a literal key and the closing `]`
+    fn peek_literal_subscript_key(&self) -> bool {
+        let literal = match self.peek_token() {
+            Token::Number(_, _) | Token::SingleQuotedString(_) => true,
+            Token::Word(w) => w.quote_style == Some('"'),
+            _ => false,
+        };
+        literal && self.peek_nth_token(1) == Token::RBracket
+    }
+
+    /// Parse an arbitrary expression inside a subscript, stopping before a
+    /// top-level `:` which would otherwise start a semi-structured path
+    fn parse_subscript_expr(&mut self) -> Result<Expr, ParserError> {
+        let mut expr = self.parse_prefix()?;
+        loop {
+            let next_precedence = self.get_next_precedence()?;
+            if next_precedence == 0 || self.peek_token() == Token::Colon {
+                return Ok(expr);
+            }
+            expr = self.parse_infix(expr, next_precedence)?;
+        }
+    }
+
+    fn parse_array_slice(
+        &mut self,
+        obj: Expr,
+        lower_bound: Option<Box<Expr>>,
+    ) -> Result<Expr, ParserError> {
+        let upper_bound = if self.peek_token() == Token::RBracket {
+            None
+        } else {
+            Some(Box::new(self.parse_subscript_expr()?))
+        };
+        Ok(Expr::ArraySlice {
+            obj: Box::new(obj),
+            lower_bound,
+            upper_bound,
+        })
+    }
+
     pub fn parse_map_access(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         let key_parts = self.parse_map_keys()?;
         match expr {
@@ -1251,6 +1810,73 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the pattern of `[NOT] LIKE` or `[NOT] ILIKE`, including the
+    /// multi-pattern `LIKE ANY (...)` / `LIKE ALL (...)` form
+    pub fn parse_like(
+        &mut self,
+        expr: Expr,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> Result<Expr, ParserError> {
+        let quantifier = match self.peek_token() {
+            Token::Word(w) if self.peek_nth_token(1) == Token::LParen => match w.keyword {
+                Keyword::ANY => Some(LikeQuantifier::Any),
+                Keyword::ALL => Some(LikeQuantifier::All),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some(quantifier) = quantifier {
+            self.next_token();
+            self.expect_token(&Token::LParen)?;
+            let patterns = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(Expr::LikeQuantified {
+                negated,
+                case_insensitive,
+                expr: Box::new(expr),
+                quantifier,
+                patterns,
+                escape_char: self.parse_escape_char()?,
+            })
+        } else {
+            Ok(Expr::Like {
+                negated,
+                case_insensitive,
+                expr: Box::new(expr),
+                pattern: Box::new(self.parse_subexpr(Self::BETWEEN_PREC)?),
+                escape_char: self.parse_escape_char()?,
+            })
+        }
+    }
+
+    /// Parses an optional `ESCAPE '<char>'` clause following a pattern
+    pub fn parse_escape_char(&mut self) -> Result<Option<String>, ParserError> {
+        if !self.parse_keyword(Keyword::ESCAPE) {
+            return Ok(None);
+        }
+        match self.next_token() {
+            Token::SingleQuotedString(s) if s.chars().count() <= 1 => Ok(Some(s)),
+            // the canonical form of a backslash, in dialects without backslash escapes
+            Token::SingleQuotedString(s) if s == "\\\\" => Ok(Some("\\".to_string())),
+            unexpected => self.expected("a single character escape string", unexpected),
+        }
+    }
+
+    /// Parses the parenthesized operand of `ANY`, `SOME` or `ALL`: either a
+    /// subquery or an array-valued expression
+    pub fn parse_quantified_operand(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let operand = if self.parse_keyword(Keyword::SELECT) || self.parse_keyword(Keyword::WITH) {
+            self.prev_token();
+            Expr::Subquery(Box::new(self.parse_query()?))
+        } else {
+            self.parse_expr()?
+        };
+        self.expect_token(&Token::RParen)?;
+        Ok(operand)
+    }
+
     /// Parses the parens following the `[ NOT ] IN` operator
     pub fn parse_in(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
@@ -1293,11 +1919,13 @@ impl<'a> Parser<'a> {
             expr: Box::new(expr),
             data_type: self.parse_data_type()?,
             pg_style: true,
+            format: None,
         })
     }
 
     const UNARY_NOT_PREC: u8 = 15;
     const BETWEEN_PREC: u8 = 20;
+    const COLON_PREC: u8 = 25;
     const PLUS_MINUS_PREC: u8 = 30;
 
     /// Get the precedence of the next token
@@ -1310,14 +1938,15 @@ impl<'a> Parser<'a> {
             Token::Word(w) if w.keyword == Keyword::XOR => Ok(24),
             Token::Word(w) if w.keyword == Keyword::NOT => match self.peek_nth_token(1) {
                 // The precedence of NOT varies depending on keyword that
-                // follows it. If it is followed by IN, BETWEEN, LIKE, REGEXP, or RLIKE
-                // it takes on the precedence of those tokens. Otherwise it
+                // follows it. If it is followed by IN, BETWEEN, LIKE, ILIKE, SIMILAR, REGEXP,
+                // or RLIKE it takes on the precedence of those tokens. Otherwise it
                 // is not an infix operator, and therefore has zero
                 // precedence.
                 Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::REGEXP => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::RLIKE => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
@@ -1327,9 +1956,26 @@ impl<'a> Parser<'a> {
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w)
+                if w.keyword == Keyword::SIMILAR
+                    && matches!(self.peek_nth_token(1), Token::Word(w) if w.keyword == Keyword::TO) =>
+            {
+                Ok(Self::BETWEEN_PREC)
+            }
             Token::Word(w) if w.keyword == Keyword::REGEXP => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::RLIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::DIV => Ok(40),
+            // COLLATE applies to the operand immediately before it, e.g. a cast or a function call
+            Token::Word(w) if w.keyword == Keyword::COLLATE => Ok(48),
+            // AT TIME ZONE binds tighter than arithmetic but looser than `::`,
+            // so that `ts AT TIME ZONE 'UTC' + i` adds to the converted value
+            Token::Word(w)
+                if w.keyword == Keyword::AT
+                    && matches!(self.peek_nth_token(1), Token::Word(w) if w.keyword == Keyword::TIME)
+                    && matches!(self.peek_nth_token(2), Token::Word(w) if w.keyword == Keyword::ZONE) =>
+            {
+                Ok(45)
+            }
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1347,10 +1993,25 @@ impl<'a> Parser<'a> {
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Divide | Token::Mod | Token::StringConcat => Ok(40),
+            // JSON operators bind like `||`, the other PostgreSQL "any other operator"
+            // supported here, so that e.g. `a->>'b' = 'c'` compares the extracted value
+            Token::Arrow
+            | Token::LongArrow
+            | Token::HashArrow
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::ArrowAt
+            | Token::Question
+            | Token::QuestionPipe
+            | Token::QuestionAnd
+            | Token::AtQuestion => Ok(40),
             Token::DoubleColon => Ok(50),
+            // `(composite).field`; a following `*` is left to the select list
+            Token::Period if self.peek_nth_token(1) != Token::Mul => Ok(50),
             Token::ExclamationMark => Ok(50),
-            Token::LBracket => Ok(10),
-            Token::Colon => Ok(25),
+            Token::LBracket => Ok(50),
+            Token::Colon => Ok(Self::COLON_PREC),
+            Token::Assignment => Ok(1),
             _ => Ok(0),
         }
     }
@@ -1556,6 +2217,10 @@ impl<'a> Parser<'a> {
 
     /// Parse a SQL CREATE statement
     pub fn parse_create(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keywords(&[Keyword::OR, Keyword::ALTER]) {
+            self.expect_one_of_keywords(&[Keyword::PROCEDURE, Keyword::PROC])?;
+            return self.parse_create_procedure(true);
+        }
         let or_replace = self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
         let temporary = self
             .parse_one_of_keywords(&[Keyword::TEMP, Keyword::TEMPORARY])
@@ -1567,9 +2232,11 @@ impl<'a> Parser<'a> {
             self.parse_create_view(or_replace)
         } else if self.parse_keyword(Keyword::EXTERNAL) {
             self.parse_create_external_table(or_replace)
+        } else if self.parse_keyword(Keyword::FUNCTION) {
+            self.parse_create_function(or_replace, temporary)
         } else if or_replace {
             self.expected(
-                "[EXTERNAL] TABLE or [MATERIALIZED] VIEW after CREATE OR REPLACE",
+                "[EXTERNAL] TABLE, [MATERIALIZED] VIEW or FUNCTION after CREATE OR REPLACE",
                 self.peek_token(),
             )
         } else if self.parse_keyword(Keyword::INDEX) {
@@ -1580,20 +2247,359 @@ impl<'a> Parser<'a> {
             self.parse_create_virtual_table()
         } else if self.parse_keyword(Keyword::SCHEMA) {
             self.parse_create_schema()
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            self.parse_create_sequence(temporary)
+        } else if self
+            .parse_one_of_keywords(&[Keyword::PROCEDURE, Keyword::PROC])
+            .is_some()
+        {
+            self.parse_create_procedure(false)
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
     }
 
-    /// SQLite-specific `CREATE VIRTUAL TABLE`
-    pub fn parse_create_virtual_table(&mut self) -> Result<Statement, ParserError> {
-        self.expect_keyword(Keyword::TABLE)?;
+    /// Parse `CREATE SEQUENCE`, after `CREATE [ TEMPORARY ] SEQUENCE`
+    pub fn parse_create_sequence(&mut self, temporary: bool) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
-        let table_name = self.parse_object_name()?;
-        self.expect_keyword(Keyword::USING)?;
-        let module_name = self.parse_identifier()?;
-        // SQLite docs note that module "arguments syntax is sufficiently
-        // general that the arguments can be made to appear as column
+        let name = self.parse_object_name()?;
+        let data_type = if self.parse_keyword(Keyword::AS) {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+        let sequence_options = self.parse_sequence_options()?;
+        Ok(Statement::CreateSequence {
+            temporary,
+            if_not_exists,
+            name,
+            data_type,
+            sequence_options,
+        })
+    }
+
+    /// Parse `ALTER SEQUENCE`, after `ALTER SEQUENCE`
+    pub fn parse_alter_sequence(&mut self) -> Result<Statement, ParserError> {
+        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        let name = self.parse_object_name()?;
+        let data_type = if self.parse_keyword(Keyword::AS) {
+            Some(self.parse_data_type()?)
+        } else {
+            None
+        };
+        let sequence_options = self.parse_sequence_options()?;
+        Ok(Statement::AlterSequence {
+            if_exists,
+            name,
+            data_type,
+            sequence_options,
+        })
+    }
+
+    /// Parse the options of a sequence or identity column e.g.
+    /// `START WITH 1 INCREMENT BY 1 NO MINVALUE CACHE 10 OWNED BY t.id`
+    pub fn parse_sequence_options(&mut self) -> Result<Vec<SequenceOptions>, ParserError> {
+        let mut options = vec![];
+        loop {
+            let option = if self.parse_keyword(Keyword::INCREMENT) {
+                let _ = self.parse_keyword(Keyword::BY);
+                SequenceOptions::IncrementBy(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::MINVALUE) {
+                SequenceOptions::MinValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
+                SequenceOptions::MinValue(None)
+            } else if self.parse_keyword(Keyword::MAXVALUE) {
+                SequenceOptions::MaxValue(Some(self.parse_expr()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
+                SequenceOptions::MaxValue(None)
+            } else if self.parse_keyword(Keyword::START) {
+                let _ = self.parse_keyword(Keyword::WITH);
+                SequenceOptions::StartWith(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::RESTART) {
+                if self.parse_keyword(Keyword::WITH) {
+                    SequenceOptions::Restart(Some(self.parse_expr()?))
+                } else if let Token::Number(_, _) | Token::Minus = self.peek_token() {
+                    SequenceOptions::Restart(Some(self.parse_expr()?))
+                } else {
+                    SequenceOptions::Restart(None)
+                }
+            } else if self.parse_keyword(Keyword::CACHE) {
+                SequenceOptions::Cache(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::CYCLE) {
+                SequenceOptions::Cycle(true)
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
+                SequenceOptions::Cycle(false)
+            } else if self.parse_keywords(&[Keyword::OWNED, Keyword::BY]) {
+                if self.parse_keyword(Keyword::NONE) {
+                    SequenceOptions::OwnedBy(None)
+                } else {
+                    SequenceOptions::OwnedBy(Some(self.parse_object_name()?))
+                }
+            } else if self.parse_keywords(&[Keyword::SEQUENCE, Keyword::NAME]) {
+                SequenceOptions::SequenceName(self.parse_object_name()?)
+            } else {
+                return Ok(options);
+            };
+            options.push(option);
+        }
+    }
+
+    /// Parse `CREATE FUNCTION`, after `CREATE [ OR REPLACE ] [ TEMPORARY ] FUNCTION`
+    pub fn parse_create_function(
+        &mut self,
+        or_replace: bool,
+        temporary: bool,
+    ) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        let args = if self.consume_token(&Token::LParen) {
+            if self.consume_token(&Token::RParen) {
+                Some(vec![])
+            } else {
+                let args = self.parse_comma_separated(Parser::parse_function_arg)?;
+                self.expect_token(&Token::RParen)?;
+                Some(args)
+            }
+        } else {
+            None
+        };
+        // `RETURNS NULL ON NULL INPUT` is an option of the body, not a return type
+        let return_type = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::Word(w), Token::Word(next))
+                if w.keyword == Keyword::RETURNS && next.keyword == Keyword::NULL =>
+            {
+                None
+            }
+            _ if self.parse_keyword(Keyword::RETURNS) => Some(self.parse_function_return_type()?),
+            _ => None,
+        };
+        let body = self.parse_create_function_body()?;
+        Ok(Statement::CreateFunction {
+            or_replace,
+            temporary,
+            name,
+            args,
+            return_type,
+            body,
+        })
+    }
+
+    /// Parse the return type of `CREATE FUNCTION`, after `RETURNS`
+    pub fn parse_function_return_type(&mut self) -> Result<FunctionReturnType, ParserError> {
+        if self.parse_keyword(Keyword::SETOF) {
+            Ok(FunctionReturnType::SetOf(self.parse_data_type()?))
+        } else if self.parse_keyword(Keyword::TABLE) {
+            self.expect_token(&Token::LParen)?;
+            let columns = self.parse_comma_separated(|parser| {
+                Ok(ColumnDef {
+                    name: parser.parse_identifier()?,
+                    data_type: parser.parse_data_type()?,
+                    collation: None,
+                    options: vec![],
+                })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            Ok(FunctionReturnType::Table(columns))
+        } else {
+            Ok(FunctionReturnType::DataType(self.parse_data_type()?))
+        }
+    }
+
+    /// Parse an argument of `CREATE FUNCTION` e.g. `IN a INTEGER DEFAULT 1`
+    pub fn parse_function_arg(&mut self) -> Result<OperateFunctionArg, ParserError> {
+        let mode = match self.parse_one_of_keywords(&[
+            Keyword::IN,
+            Keyword::OUT,
+            Keyword::INOUT,
+            Keyword::VARIADIC,
+        ]) {
+            Some(Keyword::IN) => Some(ArgMode::In),
+            Some(Keyword::OUT) => Some(ArgMode::Out),
+            Some(Keyword::INOUT) => Some(ArgMode::InOut),
+            Some(Keyword::VARIADIC) => Some(ArgMode::Variadic),
+            _ => None,
+        };
+        // the name is optional, so first try parsing the argument as just a type
+        let unnamed = self.maybe_parse(|parser| {
+            let data_type = parser.parse_data_type()?;
+            match parser.peek_token() {
+                Token::Comma | Token::RParen | Token::Eq => Ok(data_type),
+                Token::Word(w) if w.keyword == Keyword::DEFAULT => Ok(data_type),
+                unexpected => parser.expected("end of argument", unexpected),
+            }
+        });
+        let (name, data_type) = match unnamed {
+            Some(data_type) => (None, data_type),
+            None => (Some(self.parse_identifier()?), self.parse_data_type()?),
+        };
+        let default_expr = if self.parse_keyword(Keyword::DEFAULT) || self.consume_token(&Token::Eq)
+        {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        Ok(OperateFunctionArg {
+            mode,
+            name,
+            data_type,
+            default_expr,
+        })
+    }
+
+    /// Parse the options following the signature of `CREATE FUNCTION`, in any order
+    pub fn parse_create_function_body(&mut self) -> Result<CreateFunctionBody, ParserError> {
+        fn ensure_not_set<T>(field: &Option<T>, name: &str) -> Result<(), ParserError> {
+            if field.is_some() {
+                return parser_err!(format!("{} specified more than once", name));
+            }
+            Ok(())
+        }
+        let mut body = CreateFunctionBody::default();
+        loop {
+            if self.parse_keyword(Keyword::LANGUAGE) {
+                ensure_not_set(&body.language, "LANGUAGE")?;
+                body.language = Some(self.parse_identifier()?);
+            } else if let Some(keyword) = self.parse_one_of_keywords(&[
+                Keyword::IMMUTABLE,
+                Keyword::STABLE,
+                Keyword::VOLATILE,
+            ]) {
+                ensure_not_set(&body.behavior, "IMMUTABLE | STABLE | VOLATILE")?;
+                body.behavior = Some(match keyword {
+                    Keyword::IMMUTABLE => FunctionBehavior::Immutable,
+                    Keyword::STABLE => FunctionBehavior::Stable,
+                    _ => FunctionBehavior::Volatile,
+                });
+            } else if self.parse_keywords(&[
+                Keyword::CALLED,
+                Keyword::ON,
+                Keyword::NULL,
+                Keyword::INPUT,
+            ]) {
+                ensure_not_set(&body.called_on_null, "null input behavior")?;
+                body.called_on_null = Some(FunctionCalledOnNull::CalledOnNullInput);
+            } else if self.parse_keywords(&[
+                Keyword::RETURNS,
+                Keyword::NULL,
+                Keyword::ON,
+                Keyword::NULL,
+                Keyword::INPUT,
+            ]) {
+                ensure_not_set(&body.called_on_null, "null input behavior")?;
+                body.called_on_null = Some(FunctionCalledOnNull::ReturnsNullOnNullInput);
+            } else if self.parse_keyword(Keyword::STRICT) {
+                ensure_not_set(&body.called_on_null, "null input behavior")?;
+                body.called_on_null = Some(FunctionCalledOnNull::Strict);
+            } else if self.parse_keyword(Keyword::SECURITY) {
+                ensure_not_set(&body.security, "SECURITY")?;
+                body.security = Some(
+                    match self.expect_one_of_keywords(&[Keyword::INVOKER, Keyword::DEFINER])? {
+                        Keyword::INVOKER => FunctionSecurity::Invoker,
+                        _ => FunctionSecurity::Definer,
+                    },
+                );
+            } else if self.parse_keyword(Keyword::AS) {
+                ensure_not_set(&body.as_, "AS")?;
+                body.as_ = Some(self.parse_function_definition()?);
+            } else if self.parse_keyword(Keyword::USING) {
+                if !body.using.is_empty() {
+                    return parser_err!("USING specified more than once");
+                }
+                body.using = self.parse_comma_separated(Parser::parse_create_function_using)?;
+            } else {
+                return Ok(body);
+            }
+        }
+    }
+
+    /// Parse the definition of a function following `AS`
+    pub fn parse_function_definition(&mut self) -> Result<FunctionDefinition, ParserError> {
+        match self.next_token() {
+            Token::SingleQuotedString(s) => Ok(FunctionDefinition::SingleQuotedDef(s)),
+            Token::DollarQuotedString(s) => Ok(FunctionDefinition::DollarQuotedDef(s)),
+            Token::LParen => {
+                let expr = self.parse_expr()?;
+                self.expect_token(&Token::RParen)?;
+                Ok(FunctionDefinition::Expr(expr))
+            }
+            unexpected => self.expected("a function definition", unexpected),
+        }
+    }
+
+    /// Parse a Hive function resource e.g. `JAR 'hdfs:///udf.jar'`
+    pub fn parse_create_function_using(&mut self) -> Result<CreateFunctionUsing, ParserError> {
+        let keyword =
+            self.expect_one_of_keywords(&[Keyword::JAR, Keyword::FILE, Keyword::ARCHIVE])?;
+        let uri = self.parse_literal_string()?;
+        Ok(match keyword {
+            Keyword::JAR => CreateFunctionUsing::Jar(uri),
+            Keyword::FILE => CreateFunctionUsing::File(uri),
+            _ => CreateFunctionUsing::Archive(uri),
+        })
+    }
+
+    /// Parse MSSQL `CREATE PROCEDURE`, after `CREATE [ OR ALTER ] { PROC | PROCEDURE }`
+    pub fn parse_create_procedure(&mut self, or_alter: bool) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        let params = if self.consume_token(&Token::LParen) {
+            let params = self.parse_comma_separated(Parser::parse_procedure_param)?;
+            self.expect_token(&Token::RParen)?;
+            params
+        } else if matches!(self.peek_token(), Token::Word(w) if w.keyword == Keyword::AS) {
+            vec![]
+        } else {
+            self.parse_comma_separated(Parser::parse_procedure_param)?
+        };
+        self.expect_keyword(Keyword::AS)?;
+        let body = if self.parse_keyword(Keyword::BEGIN) {
+            let mut body = vec![];
+            loop {
+                while self.consume_token(&Token::SemiColon) {}
+                if self.parse_keyword(Keyword::END) {
+                    break;
+                }
+                body.push(self.parse_statement()?);
+            }
+            body
+        } else {
+            vec![self.parse_statement()?]
+        };
+        Ok(Statement::CreateProcedure {
+            or_alter,
+            name,
+            params,
+            body,
+        })
+    }
+
+    /// Parse a parameter of MSSQL `CREATE PROCEDURE` e.g. `@id INT = 0 OUTPUT`
+    pub fn parse_procedure_param(&mut self) -> Result<ProcedureParam, ParserError> {
+        let name = self.parse_identifier()?;
+        let data_type = self.parse_data_type()?;
+        let default_expr = if self.consume_token(&Token::Eq) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        let output = self
+            .parse_one_of_keywords(&[Keyword::OUTPUT, Keyword::OUT])
+            .is_some();
+        Ok(ProcedureParam {
+            name,
+            data_type,
+            default_expr,
+            output,
+        })
+    }
+
+    /// SQLite-specific `CREATE VIRTUAL TABLE`
+    pub fn parse_create_virtual_table(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keyword(Keyword::TABLE)?;
+        let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let table_name = self.parse_object_name()?;
+        self.expect_keyword(Keyword::USING)?;
+        let module_name = self.parse_identifier()?;
+        // SQLite docs note that module "arguments syntax is sufficiently
+        // general that the arguments can be made to appear as column
         // definitions in a traditional CREATE TABLE statement", but
         // we don't implement that.
         let module_args = self.parse_parenthesized_column_list(Optional)?;
@@ -1675,6 +2681,11 @@ impl<'a> Parser<'a> {
             query: None,
             without_rowid: false,
             like: None,
+            partition_of: None,
+            inherits: vec![],
+            table_options: vec![],
+            partition_by: None,
+            tablespace: None,
         })
     }
 
@@ -1724,8 +2735,13 @@ impl<'a> Parser<'a> {
             ObjectType::Index
         } else if self.parse_keyword(Keyword::SCHEMA) {
             ObjectType::Schema
+        } else if self.parse_keyword(Keyword::SEQUENCE) {
+            ObjectType::Sequence
         } else {
-            return self.expected("TABLE, VIEW, INDEX or SCHEMA after DROP", self.peek_token());
+            return self.expected(
+                "TABLE, VIEW, INDEX, SCHEMA or SEQUENCE after DROP",
+                self.peek_token(),
+            );
         };
         // Many dialects support the non standard `IF EXISTS` clause and allow
         // specifying multiple objects to delete in a single statement
@@ -1754,12 +2770,20 @@ impl<'a> Parser<'a> {
         self.expect_token(&Token::LParen)?;
         let columns = self.parse_comma_separated(Parser::parse_order_by_expr)?;
         self.expect_token(&Token::RParen)?;
+        let with = self.parse_options(Keyword::WITH)?;
+        let tablespace = if self.parse_keyword(Keyword::TABLESPACE) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
         Ok(Statement::CreateIndex {
             name: index_name,
             table_name,
             columns,
             unique,
             if_not_exists,
+            with,
+            tablespace,
         })
     }
 
@@ -1831,8 +2855,40 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let parent = if self.parse_keywords(&[Keyword::PARTITION, Keyword::OF]) {
+            Some(self.parse_object_name()?)
+        } else {
+            None
+        };
         // parse optional column list (schema)
         let (columns, constraints) = self.parse_columns()?;
+        let partition_of = match parent {
+            Some(parent) => Some(Box::new(PartitionOf {
+                parent,
+                bound: self.parse_partition_bound()?,
+            })),
+            None => None,
+        };
+
+        // PostgreSQL supports `INHERITS ( parent [, ...] )`
+        let inherits = if self.parse_keyword(Keyword::INHERITS) {
+            self.expect_token(&Token::LParen)?;
+            let inherits = self.parse_comma_separated(Parser::parse_object_name)?;
+            self.expect_token(&Token::RParen)?;
+            inherits
+        } else {
+            vec![]
+        };
+        let table_options = if dialect_of!(self is MySqlDialect | GenericDialect) {
+            self.parse_table_options()?
+        } else {
+            vec![]
+        };
+        let partition_by = if self.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
+            Some(Box::new(self.parse_partition_by()?))
+        } else {
+            None
+        };
 
         // SQLite supports `WITHOUT ROWID` at the end of `CREATE TABLE`
         let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
@@ -1842,6 +2898,11 @@ impl<'a> Parser<'a> {
         // PostgreSQL supports `WITH ( options )`, before `AS`
         let with_options = self.parse_options(Keyword::WITH)?;
         let table_properties = self.parse_options(Keyword::TBLPROPERTIES)?;
+        let tablespace = if self.parse_keyword(Keyword::TABLESPACE) {
+            Some(self.parse_identifier()?)
+        } else {
+            None
+        };
         // Parse optional `AS ( query )`
         let query = if self.parse_keyword(Keyword::AS) {
             Some(Box::new(self.parse_query()?))
@@ -1866,9 +2927,131 @@ impl<'a> Parser<'a> {
             query,
             without_rowid,
             like,
+            partition_of,
+            inherits,
+            table_options,
+            partition_by,
+            tablespace,
+        })
+    }
+
+    /// Parse the `<method> ( <expr> [, ...] )` of a partitioned table, after
+    /// `PARTITION BY`, followed by MySQL's optional partition count and definitions
+    pub fn parse_partition_by(&mut self) -> Result<PartitionBy, ParserError> {
+        let method = match self.expect_one_of_keywords(&[
+            Keyword::RANGE,
+            Keyword::LIST,
+            Keyword::HASH,
+            Keyword::KEY,
+        ])? {
+            Keyword::RANGE if self.parse_keyword(Keyword::COLUMNS) => PartitionMethod::RangeColumns,
+            Keyword::RANGE => PartitionMethod::Range,
+            Keyword::LIST if self.parse_keyword(Keyword::COLUMNS) => PartitionMethod::ListColumns,
+            Keyword::LIST => PartitionMethod::List,
+            Keyword::HASH => PartitionMethod::Hash,
+            _ => PartitionMethod::Key,
+        };
+        self.expect_token(&Token::LParen)?;
+        // MySQL `PARTITION BY KEY ()` partitions by the primary key
+        let columns = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let columns = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            columns
+        };
+        let partitions = if self.parse_keyword(Keyword::PARTITIONS) {
+            Some(self.parse_literal_uint()?)
+        } else {
+            None
+        };
+        let definitions = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::LParen, Token::Word(w)) if w.keyword == Keyword::PARTITION => {
+                self.expect_token(&Token::LParen)?;
+                let definitions = self.parse_comma_separated(Parser::parse_partition_definition)?;
+                self.expect_token(&Token::RParen)?;
+                definitions
+            }
+            _ => vec![],
+        };
+        Ok(PartitionBy {
+            method,
+            columns,
+            partitions,
+            definitions,
         })
     }
 
+    /// Parse a MySQL partition definition, `PARTITION <name> [ VALUES ... ] [ <option> ... ]`
+    pub fn parse_partition_definition(&mut self) -> Result<PartitionDefinition, ParserError> {
+        self.expect_keyword(Keyword::PARTITION)?;
+        let name = self.parse_identifier()?;
+        let values = if self.parse_keyword(Keyword::VALUES) {
+            if self.parse_keywords(&[Keyword::LESS, Keyword::THAN]) {
+                if self.parse_keyword(Keyword::MAXVALUE) {
+                    Some(PartitionValues::LessThanMaxValue)
+                } else {
+                    self.expect_token(&Token::LParen)?;
+                    let values = self.parse_comma_separated(Parser::parse_expr)?;
+                    self.expect_token(&Token::RParen)?;
+                    Some(PartitionValues::LessThan(values))
+                }
+            } else if self.parse_keyword(Keyword::IN) {
+                self.expect_token(&Token::LParen)?;
+                let values = self.parse_comma_separated(Parser::parse_expr)?;
+                self.expect_token(&Token::RParen)?;
+                Some(PartitionValues::In(values))
+            } else {
+                return self.expected("LESS THAN or IN after VALUES", self.peek_token());
+            }
+        } else {
+            None
+        };
+        let mut options = vec![];
+        while let Some(option) = self.parse_optional_table_option()? {
+            options.push(option);
+        }
+        Ok(PartitionDefinition {
+            name,
+            values,
+            options,
+        })
+    }
+
+    /// Parse the bound of a PostgreSQL partition e.g. `FOR VALUES IN (1, 2)` or `DEFAULT`
+    pub fn parse_partition_bound(&mut self) -> Result<PartitionBound, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(PartitionBound::Default);
+        }
+        self.expect_keywords(&[Keyword::FOR, Keyword::VALUES])?;
+        if self.parse_keyword(Keyword::IN) {
+            self.expect_token(&Token::LParen)?;
+            let values = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(PartitionBound::In(values))
+        } else if self.parse_keyword(Keyword::FROM) {
+            self.expect_token(&Token::LParen)?;
+            let from = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            self.expect_keyword(Keyword::TO)?;
+            self.expect_token(&Token::LParen)?;
+            let to = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(PartitionBound::FromTo { from, to })
+        } else if self.parse_keyword(Keyword::WITH) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword(Keyword::MODULUS)?;
+            let modulus = self.parse_literal_uint()?;
+            self.expect_token(&Token::Comma)?;
+            self.expect_keyword(Keyword::REMAINDER)?;
+            let remainder = self.parse_literal_uint()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(PartitionBound::With { modulus, remainder })
+        } else {
+            self.expected("IN, FROM or WITH after FOR VALUES", self.peek_token())
+        }
+    }
+
     fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
@@ -1901,15 +3084,29 @@ impl<'a> Parser<'a> {
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
         let name = self.parse_identifier()?;
-        let data_type = self.parse_data_type()?;
-        let collation = if self.parse_keyword(Keyword::COLLATE) {
-            Some(self.parse_object_name()?)
+        // MSSQL computed columns have no data type e.g. `c AS (a + b)`
+        let mut data_type = if dialect_of!(self is MsSqlDialect | GenericDialect)
+            && matches!(self.peek_token(), Token::Word(w) if w.keyword == Keyword::AS)
+        {
+            DataType::Unspecified
         } else {
-            None
+            self.parse_data_type()?
         };
+        let mut collation = None;
         let mut options = vec![];
         loop {
-            if self.parse_keyword(Keyword::CONSTRAINT) {
+            // a character set or collation may also follow other column options, e.g.
+            // MySQL `TEXT NOT NULL CHARACTER SET utf8mb4` or SQLite `TEXT NOT NULL COLLATE NOCASE`
+            if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+                || self.parse_keyword(Keyword::CHARSET)
+            {
+                data_type = self.parse_column_character_set(data_type)?;
+            } else if self.parse_keyword(Keyword::COLLATE) {
+                if collation.is_some() {
+                    return parser_err!(format!("Duplicate COLLATE for column {}", name));
+                }
+                collation = Some(self.parse_object_name()?);
+            } else if self.parse_keyword(Keyword::CONSTRAINT) {
                 let name = Some(self.parse_identifier()?);
                 if let Some(option) = self.parse_optional_column_option()? {
                     options.push(ColumnOptionDef { name, option });
@@ -1933,6 +3130,18 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the name after `CHARACTER SET` following a column's data type or
+    /// options, and apply it to the data type
+    fn parse_column_character_set(&mut self, data_type: DataType) -> Result<DataType, ParserError> {
+        if let DataType::CharacterSet(..) = data_type {
+            return parser_err!(format!("Duplicate CHARACTER SET for type {}", data_type));
+        }
+        Ok(DataType::CharacterSet(
+            Box::new(data_type),
+            self.parse_object_name()?,
+        ))
+    }
+
     pub fn parse_optional_column_option(&mut self) -> Result<Option<ColumnOption>, ParserError> {
         if self.parse_keywords(&[Keyword::NOT, Keyword::NULL]) {
             Ok(Some(ColumnOption::NotNull))
@@ -1973,32 +3182,99 @@ impl<'a> Parser<'a> {
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
             Ok(Some(ColumnOption::Check(expr)))
-        } else if self.parse_keyword(Keyword::COMMENT)
-            && dialect_of!(self is MySqlDialect | GenericDialect)
+        } else if self.parse_keyword(Keyword::GENERATED) {
+            let generated_as = if self.parse_keyword(Keyword::ALWAYS) {
+                GeneratedAs::Always
+            } else {
+                self.expect_keywords(&[Keyword::BY, Keyword::DEFAULT])?;
+                GeneratedAs::ByDefault
+            };
+            self.expect_keyword(Keyword::AS)?;
+            if generated_as == GeneratedAs::Always && self.peek_token() == Token::LParen {
+                return Ok(Some(self.parse_generated_column_option(true)?));
+            }
+            self.expect_keyword(Keyword::IDENTITY)?;
+            let sequence_options = if self.consume_token(&Token::LParen) {
+                let sequence_options = self.parse_sequence_options()?;
+                self.expect_token(&Token::RParen)?;
+                sequence_options
+            } else {
+                vec![]
+            };
+            Ok(Some(ColumnOption::Identity {
+                generated_as,
+                sequence_options,
+            }))
+        } else if matches!(self.peek_token(), Token::Word(w) if w.keyword == Keyword::AS)
+            && self.peek_nth_token(1) == Token::LParen
+        {
+            self.next_token();
+            Ok(Some(self.parse_generated_column_option(false)?))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
+        {
+            Ok(Some(ColumnOption::OnUpdate(self.parse_expr()?)))
+        } else if self.parse_keyword(Keyword::COMMENT) {
+            Ok(Some(ColumnOption::Comment(self.parse_literal_string()?)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::AUTO_INCREMENT)
         {
-            Ok(Some(ColumnOption::DialectSpecific(vec![
-                Token::make_keyword("COMMENT"),
-                Token::make_word(&self.parse_literal_string()?, Some('\'')),
-            ])))
-        } else if self.parse_keyword(Keyword::AUTO_INCREMENT)
-            && dialect_of!(self is MySqlDialect |  GenericDialect)
+            Ok(Some(ColumnOption::AutoIncrement))
+        } else if dialect_of!(self is SQLiteDialect | GenericDialect)
+            && self.parse_keyword(Keyword::AUTOINCREMENT)
         {
-            // Support AUTO_INCREMENT for MySQL
-            Ok(Some(ColumnOption::DialectSpecific(vec![
-                Token::make_keyword("AUTO_INCREMENT"),
-            ])))
-        } else if self.parse_keyword(Keyword::AUTOINCREMENT)
-            && dialect_of!(self is SQLiteDialect |  GenericDialect)
+            Ok(Some(ColumnOption::Autoincrement))
+        } else if self.parse_keywords(&[Keyword::MASKING, Keyword::POLICY])
+            || self.parse_keywords(&[Keyword::WITH, Keyword::MASKING, Keyword::POLICY])
         {
-            // Support AUTOINCREMENT for SQLite
-            Ok(Some(ColumnOption::DialectSpecific(vec![
-                Token::make_keyword("AUTOINCREMENT"),
-            ])))
+            let policy = self.parse_object_name()?;
+            let using_columns = if self.parse_keyword(Keyword::USING) {
+                self.parse_parenthesized_column_list(Mandatory)?
+            } else {
+                vec![]
+            };
+            Ok(Some(ColumnOption::MaskingPolicy {
+                policy,
+                using_columns,
+            }))
+        } else if self.parse_keyword(Keyword::CODEC) {
+            self.expect_token(&Token::LParen)?;
+            let codecs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(Some(ColumnOption::Codec(codecs)))
+        } else if self.parse_keyword(Keyword::TTL) {
+            Ok(Some(ColumnOption::Ttl(self.parse_expr()?)))
         } else {
             Ok(None)
         }
     }
 
+    /// Parse the `(<expr>) [ STORED | VIRTUAL | PERSISTED ]` of a generated column,
+    /// after `[ GENERATED ALWAYS ] AS`
+    fn parse_generated_column_option(
+        &mut self,
+        generated_keyword: bool,
+    ) -> Result<ColumnOption, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        let mode = match self.parse_one_of_keywords(&[
+            Keyword::STORED,
+            Keyword::VIRTUAL,
+            Keyword::PERSISTED,
+        ]) {
+            Some(Keyword::STORED) => Some(GeneratedColumnMode::Stored),
+            Some(Keyword::VIRTUAL) => Some(GeneratedColumnMode::Virtual),
+            Some(Keyword::PERSISTED) => Some(GeneratedColumnMode::Persisted),
+            _ => None,
+        };
+        Ok(ColumnOption::Generated {
+            generated_keyword,
+            expr,
+            mode,
+        })
+    }
+
     pub fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParserError> {
         if self.parse_keyword(Keyword::RESTRICT) {
             Ok(ReferentialAction::Restrict)
@@ -2029,14 +3305,27 @@ impl<'a> Parser<'a> {
         match self.next_token() {
             Token::Word(w) if w.keyword == Keyword::PRIMARY || w.keyword == Keyword::UNIQUE => {
                 let is_primary = w.keyword == Keyword::PRIMARY;
-                if is_primary {
+                let index_type_display = if is_primary {
                     self.expect_keyword(Keyword::KEY)?;
-                }
-                let columns = self.parse_parenthesized_column_list(Mandatory)?;
+                    KeyOrIndexDisplay::None
+                } else {
+                    self.parse_key_or_index_display()
+                };
+                // MySQL allows naming the index of a `UNIQUE` constraint
+                let index_name = if !is_primary && !matches!(self.peek_token(), Token::LParen) {
+                    Some(self.parse_identifier()?)
+                } else {
+                    None
+                };
+                let columns = self.parse_key_parts()?;
+                let index_type = self.parse_optional_index_type()?;
                 Ok(Some(TableConstraint::Unique {
                     name,
+                    index_name,
+                    index_type_display,
                     columns,
                     is_primary,
+                    index_type,
                 }))
             }
             Token::Word(w) if w.keyword == Keyword::FOREIGN => {
@@ -2073,6 +3362,48 @@ impl<'a> Parser<'a> {
                 self.expect_token(&Token::RParen)?;
                 Ok(Some(TableConstraint::Check { name, expr }))
             }
+            Token::Word(w)
+                if (w.keyword == Keyword::INDEX || w.keyword == Keyword::KEY)
+                    && name.is_none()
+                    && dialect_of!(self is MySqlDialect) =>
+            {
+                let index_type_display = if w.keyword == Keyword::KEY {
+                    KeyOrIndexDisplay::Key
+                } else {
+                    KeyOrIndexDisplay::Index
+                };
+                let index_name = match self.peek_token() {
+                    Token::LParen => None,
+                    _ => Some(self.parse_identifier()?),
+                };
+                let columns = self.parse_key_parts()?;
+                let index_type = self.parse_optional_index_type()?;
+                Ok(Some(TableConstraint::Index {
+                    index_type_display,
+                    index_name,
+                    columns,
+                    index_type,
+                }))
+            }
+            Token::Word(w)
+                if (w.keyword == Keyword::FULLTEXT || w.keyword == Keyword::SPATIAL)
+                    && name.is_none()
+                    && dialect_of!(self is MySqlDialect) =>
+            {
+                let fulltext = w.keyword == Keyword::FULLTEXT;
+                let index_type_display = self.parse_key_or_index_display();
+                let index_name = match self.peek_token() {
+                    Token::LParen => None,
+                    _ => Some(self.parse_identifier()?),
+                };
+                let columns = self.parse_key_parts()?;
+                Ok(Some(TableConstraint::FulltextOrSpatial {
+                    fulltext,
+                    index_type_display,
+                    index_name,
+                    columns,
+                }))
+            }
             unexpected => {
                 if name.is_some() {
                     self.expected("PRIMARY, UNIQUE, FOREIGN, or CHECK", unexpected)
@@ -2084,6 +3415,128 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the optional MySQL `KEY` or `INDEX` keyword of an index definition
+    fn parse_key_or_index_display(&mut self) -> KeyOrIndexDisplay {
+        match self.parse_one_of_keywords(&[Keyword::KEY, Keyword::INDEX]) {
+            Some(Keyword::KEY) => KeyOrIndexDisplay::Key,
+            Some(Keyword::INDEX) => KeyOrIndexDisplay::Index,
+            _ => KeyOrIndexDisplay::None,
+        }
+    }
+
+    /// Parse the parenthesized columns of an index or key constraint, with MySQL
+    /// prefix lengths and sort orders e.g. `(a(10), b DESC)`
+    pub fn parse_key_parts(&mut self) -> Result<Vec<KeyPart>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let key_parts = self.parse_comma_separated(|parser| {
+            let column = parser.parse_identifier()?;
+            let length = if parser.consume_token(&Token::LParen) {
+                let length = parser.parse_literal_uint()?;
+                parser.expect_token(&Token::RParen)?;
+                Some(length)
+            } else {
+                None
+            };
+            let asc = if parser.parse_keyword(Keyword::ASC) {
+                Some(true)
+            } else if parser.parse_keyword(Keyword::DESC) {
+                Some(false)
+            } else {
+                None
+            };
+            Ok(KeyPart {
+                column,
+                length,
+                asc,
+            })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(key_parts)
+    }
+
+    /// Parse an optional MySQL `USING { BTREE | HASH }`
+    fn parse_optional_index_type(&mut self) -> Result<Option<IndexType>, ParserError> {
+        if !self.parse_keyword(Keyword::USING) {
+            return Ok(None);
+        }
+        match self.expect_one_of_keywords(&[Keyword::BTREE, Keyword::HASH])? {
+            Keyword::BTREE => Ok(Some(IndexType::BTree)),
+            _ => Ok(Some(IndexType::Hash)),
+        }
+    }
+
+    /// Parse the MySQL table options following the column list of `CREATE TABLE`,
+    /// which may optionally be separated by commas
+    pub fn parse_table_options(&mut self) -> Result<Vec<TableOption>, ParserError> {
+        let mut options = vec![];
+        let mut comma = false;
+        loop {
+            match self.parse_optional_table_option()? {
+                Some(option) => options.push(option),
+                None if comma => return self.expected("table option after ','", self.peek_token()),
+                None => break,
+            }
+            comma = self.consume_token(&Token::Comma);
+        }
+        Ok(options)
+    }
+
+    /// Parse a single MySQL table option e.g. `ENGINE=InnoDB`, where the `=` is optional
+    pub fn parse_optional_table_option(&mut self) -> Result<Option<TableOption>, ParserError> {
+        let default = self.parse_keyword(Keyword::DEFAULT);
+        if self.parse_keyword(Keyword::CHARSET)
+            || self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+        {
+            let _ = self.consume_token(&Token::Eq);
+            let name = self.parse_identifier()?;
+            return Ok(Some(TableOption::CharacterSet { default, name }));
+        }
+        if self.parse_keyword(Keyword::COLLATE) {
+            let _ = self.consume_token(&Token::Eq);
+            let name = self.parse_identifier()?;
+            return Ok(Some(TableOption::Collate { default, name }));
+        }
+        if default {
+            return self.expected(
+                "CHARSET, CHARACTER SET or COLLATE after DEFAULT",
+                self.peek_token(),
+            );
+        }
+        let option = match self.parse_one_of_keywords(&[
+            Keyword::ENGINE,
+            Keyword::AUTO_INCREMENT,
+            Keyword::ROW_FORMAT,
+            Keyword::COMMENT,
+        ]) {
+            Some(Keyword::ENGINE) => {
+                let _ = self.consume_token(&Token::Eq);
+                TableOption::Engine(self.parse_identifier()?)
+            }
+            Some(Keyword::AUTO_INCREMENT) => {
+                let _ = self.consume_token(&Token::Eq);
+                TableOption::AutoIncrement(self.parse_literal_uint()?)
+            }
+            Some(Keyword::ROW_FORMAT) => {
+                let _ = self.consume_token(&Token::Eq);
+                TableOption::RowFormat(self.parse_identifier()?)
+            }
+            Some(Keyword::COMMENT) => {
+                let _ = self.consume_token(&Token::Eq);
+                TableOption::Comment(self.parse_literal_string()?)
+            }
+            _ => match (self.peek_token(), self.peek_nth_token(1)) {
+                (Token::Word(_), Token::Eq) => {
+                    let name = self.parse_identifier()?;
+                    self.expect_token(&Token::Eq)?;
+                    let value = self.parse_expr()?;
+                    TableOption::Other { name, value }
+                }
+                _ => return Ok(None),
+            },
+        };
+        Ok(Some(option))
+    }
+
     pub fn parse_options(&mut self, keyword: Keyword) -> Result<Vec<SqlOption>, ParserError> {
         if self.parse_keyword(keyword) {
             self.expect_token(&Token::LParen)?;
@@ -2103,9 +3556,21 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword(Keyword::SEQUENCE) {
+            return self.parse_alter_sequence();
+        }
         self.expect_keyword(Keyword::TABLE)?;
         let _ = self.parse_keyword(Keyword::ONLY);
         let table_name = self.parse_object_name()?;
+        let operations = self.parse_comma_separated(Parser::parse_alter_table_operation)?;
+        Ok(Statement::AlterTable {
+            name: table_name,
+            operations,
+        })
+    }
+
+    /// Parse a single operation of `ALTER TABLE`, e.g. `ADD COLUMN c INT`
+    pub fn parse_alter_table_operation(&mut self) -> Result<AlterTableOperation, ParserError> {
         let operation = if self.parse_keyword(Keyword::ADD) {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 AlterTableOperation::AddConstraint(constraint)
@@ -2123,13 +3588,33 @@ impl<'a> Parser<'a> {
                 } else {
                     let _ = self.parse_keyword(Keyword::COLUMN);
                     let column_def = self.parse_column_def()?;
-                    AlterTableOperation::AddColumn { column_def }
+                    let position = self.parse_column_position()?;
+                    AlterTableOperation::AddColumn {
+                        column_def,
+                        position,
+                    }
                 }
             }
         } else if self.parse_keyword(Keyword::RENAME) {
+            let rename_index = match (self.peek_token(), self.peek_nth_token(1)) {
+                // `RENAME key TO k` renames a column named `key`
+                (Token::Word(w), Token::Word(next))
+                    if (w.keyword == Keyword::INDEX || w.keyword == Keyword::KEY)
+                        && next.keyword != Keyword::TO =>
+                {
+                    true
+                }
+                _ => false,
+            };
             if self.parse_keyword(Keyword::TO) {
                 let table_name = self.parse_object_name()?;
                 AlterTableOperation::RenameTable { table_name }
+            } else if rename_index {
+                self.next_token();
+                let old_name = self.parse_identifier()?;
+                self.expect_keyword(Keyword::TO)?;
+                let new_name = self.parse_identifier()?;
+                AlterTableOperation::RenameIndex { old_name, new_name }
             } else {
                 let _ = self.parse_keyword(Keyword::COLUMN);
                 let old_column_name = self.parse_identifier()?;
@@ -2185,28 +3670,140 @@ impl<'a> Parser<'a> {
             let _ = self.parse_keyword(Keyword::COLUMN);
             let old_name = self.parse_identifier()?;
             let new_name = self.parse_identifier()?;
-            let data_type = self.parse_data_type()?;
+            let mut data_type = self.parse_data_type()?;
             let mut options = vec![];
-            while let Some(option) = self.parse_optional_column_option()? {
-                options.push(option);
+            loop {
+                if self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+                    || self.parse_keyword(Keyword::CHARSET)
+                {
+                    data_type = self.parse_column_character_set(data_type)?;
+                } else if let Some(option) = self.parse_optional_column_option()? {
+                    options.push(option);
+                } else {
+                    break;
+                }
             }
+            let position = self.parse_column_position()?;
 
             AlterTableOperation::ChangeColumn {
                 old_name,
                 new_name,
                 data_type,
                 options,
+                position,
             }
+        } else if self.parse_keyword(Keyword::MODIFY) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_def = self.parse_column_def()?;
+            let position = self.parse_column_position()?;
+            AlterTableOperation::ModifyColumn {
+                column_def,
+                position,
+            }
+        } else if self.parse_keyword(Keyword::ALTER) {
+            let _ = self.parse_keyword(Keyword::COLUMN);
+            let column_name = self.parse_identifier()?;
+            let op = self.parse_alter_column_operation()?;
+            AlterTableOperation::AlterColumn { column_name, op }
+        } else if self.parse_keyword(Keyword::SET) {
+            if self.parse_keyword(Keyword::SCHEMA) {
+                let schema_name = self.parse_object_name()?;
+                AlterTableOperation::SetSchema { schema_name }
+            } else {
+                let table_properties = self.parse_options(Keyword::TBLPROPERTIES)?;
+                if table_properties.is_empty() {
+                    return self.expected("SCHEMA or TBLPROPERTIES after SET", self.peek_token());
+                }
+                AlterTableOperation::SetTblProperties { table_properties }
+            }
+        } else if self.parse_keywords(&[Keyword::OWNER, Keyword::TO]) {
+            let new_owner = self.parse_identifier()?;
+            AlterTableOperation::OwnerTo { new_owner }
+        } else if self.parse_keywords(&[Keyword::ENABLE, Keyword::TRIGGER]) {
+            let name = self.parse_identifier()?;
+            AlterTableOperation::EnableTrigger { name }
+        } else if self.parse_keywords(&[Keyword::DISABLE, Keyword::TRIGGER]) {
+            let name = self.parse_identifier()?;
+            AlterTableOperation::DisableTrigger { name }
+        } else if self.parse_keywords(&[Keyword::CLUSTER, Keyword::BY]) {
+            self.expect_token(&Token::LParen)?;
+            let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            AlterTableOperation::ClusterBy { exprs }
+        } else if self.parse_keywords(&[Keyword::SWAP, Keyword::WITH]) {
+            let table_name = self.parse_object_name()?;
+            AlterTableOperation::SwapWith { table_name }
         } else {
             return self.expected(
-                "ADD, RENAME, PARTITION or DROP after ALTER TABLE",
+                "ADD, RENAME, PARTITION, DROP, CHANGE, MODIFY, ALTER, SET, OWNER TO, \
+                 ENABLE, DISABLE, CLUSTER BY or SWAP WITH after ALTER TABLE",
                 self.peek_token(),
             );
         };
-        Ok(Statement::AlterTable {
-            name: table_name,
-            operation,
-        })
+        Ok(operation)
+    }
+
+    /// Parse the operation of `ALTER TABLE ... ALTER [ COLUMN ] <column_name>`
+    pub fn parse_alter_column_operation(&mut self) -> Result<AlterColumnOperation, ParserError> {
+        let op = if self.parse_keywords(&[Keyword::SET, Keyword::NOT, Keyword::NULL]) {
+            AlterColumnOperation::SetNotNull
+        } else if self.parse_keywords(&[Keyword::DROP, Keyword::NOT, Keyword::NULL]) {
+            AlterColumnOperation::DropNotNull
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::DEFAULT]) {
+            AlterColumnOperation::SetDefault {
+                value: self.parse_expr()?,
+            }
+        } else if self.parse_keywords(&[Keyword::DROP, Keyword::DEFAULT]) {
+            AlterColumnOperation::DropDefault
+        } else if self.parse_keywords(&[Keyword::SET, Keyword::DATA, Keyword::TYPE])
+            || self.parse_keyword(Keyword::TYPE)
+        {
+            let data_type = self.parse_data_type()?;
+            let using = if self.parse_keyword(Keyword::USING) {
+                Some(self.parse_expr()?)
+            } else {
+                None
+            };
+            AlterColumnOperation::SetDataType { data_type, using }
+        } else if self.parse_keywords(&[Keyword::ADD, Keyword::GENERATED]) {
+            let generated_as = if self.parse_keyword(Keyword::ALWAYS) {
+                GeneratedAs::Always
+            } else {
+                self.expect_keywords(&[Keyword::BY, Keyword::DEFAULT])?;
+                GeneratedAs::ByDefault
+            };
+            self.expect_keywords(&[Keyword::AS, Keyword::IDENTITY])?;
+            let sequence_options = if self.consume_token(&Token::LParen) {
+                let sequence_options = self.parse_sequence_options()?;
+                self.expect_token(&Token::RParen)?;
+                sequence_options
+            } else {
+                vec![]
+            };
+            AlterColumnOperation::AddGenerated {
+                generated_as,
+                sequence_options,
+            }
+        } else {
+            return self.expected(
+                "SET/DROP NOT NULL, SET DEFAULT, DROP DEFAULT, SET DATA TYPE \
+                 or ADD GENERATED after ALTER COLUMN",
+                self.peek_token(),
+            );
+        };
+        Ok(op)
+    }
+
+    /// Parse the optional MySQL `FIRST` or `AFTER <column_name>` of a column
+    /// added or modified by `ALTER TABLE`
+    fn parse_column_position(&mut self) -> Result<Option<ColumnPosition>, ParserError> {
+        if self.parse_keyword(Keyword::FIRST) {
+            Ok(Some(ColumnPosition::First))
+        } else if self.parse_keyword(Keyword::AFTER) {
+            Ok(Some(ColumnPosition::After(self.parse_identifier()?)))
+        } else {
+            Ok(None)
+        }
     }
 
     /// Parse a copy statement
@@ -2287,6 +3884,9 @@ impl<'a> Parser<'a> {
             }
             Token::NationalStringLiteral(ref s) => Ok(Value::NationalStringLiteral(s.to_string())),
             Token::HexStringLiteral(ref s) => Ok(Value::HexStringLiteral(s.to_string())),
+            Token::BitStringLiteral(ref s) => Ok(Value::BitStringLiteral(s.to_string())),
+            Token::DollarQuotedString(ref s) => Ok(Value::DollarQuotedString(s.clone())),
+            Token::Placeholder(ref s) => Ok(Value::Placeholder(s.to_string())),
             unexpected => self.expected("a value", unexpected),
         }
     }
@@ -2361,15 +3961,107 @@ impl<'a> Parser<'a> {
 
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example)
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
+        let (data_type, trailing_bracket) = self.parse_data_type_helper()?;
+        if trailing_bracket {
+            return parser_err!(format!("unmatched > after parsing data type {}", data_type));
+        }
+        Ok(data_type)
+    }
+
+    /// Parse a data type, returning whether it consumed the `>` of an enclosing
+    /// type as part of a `>>` token e.g. in `STRUCT<a STRUCT<b INT>>`
+    fn parse_data_type_helper(&mut self) -> Result<(DataType, bool), ParserError> {
+        let (mut data_type, trailing_bracket) = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::Word(w), Token::Lt) if w.keyword == Keyword::STRUCT => {
+                self.next_token();
+                let (fields, trailing_bracket) = self.parse_struct_type_def()?;
+                (DataType::Struct(fields), trailing_bracket)
+            }
+            _ => (self.parse_simple_data_type()?, false),
+        };
+        if matches!(
+            data_type,
+            DataType::Char(_)
+                | DataType::Varchar(_)
+                | DataType::Nvarchar(_)
+                | DataType::Text
+                | DataType::TinyText
+                | DataType::MediumText
+                | DataType::LongText
+                | DataType::Enum(_)
+                | DataType::Set(_)
+        ) && (self.parse_keywords(&[Keyword::CHARACTER, Keyword::SET])
+            || self.parse_keyword(Keyword::CHARSET))
+        {
+            data_type = DataType::CharacterSet(Box::new(data_type), self.parse_object_name()?);
+        }
+        // PostgreSQL arrays e.g. `INT[][]` or `VARCHAR(20)[3]`
+        while !trailing_bracket && self.peek_token() == Token::LBracket {
+            let size = match (self.peek_nth_token(1), self.peek_nth_token(2)) {
+                (Token::RBracket, _) => None,
+                (Token::Number(_, _), Token::RBracket) => {
+                    self.next_token();
+                    Some(self.parse_literal_uint()?)
+                }
+                _ => break,
+            };
+            if size.is_none() {
+                self.next_token();
+            }
+            self.expect_token(&Token::RBracket)?;
+            data_type = DataType::BracketArray(Box::new(data_type), size);
+        }
+        Ok((data_type, trailing_bracket))
+    }
+
+    /// Parse the fields of a struct type e.g. `<a INT, b STRING>`, returning
+    /// whether a `>>` token also closed the enclosing type
+    fn parse_struct_type_def(&mut self) -> Result<(Vec<StructField>, bool), ParserError> {
+        self.expect_token(&Token::Lt)?;
+        let mut fields = vec![];
+        if self.consume_token(&Token::Gt) {
+            return Ok((fields, false));
+        }
+        loop {
+            // fields are `a INT64` or just `INT64`
+            let field_name = match (self.peek_token(), self.peek_nth_token(1)) {
+                (Token::Word(_), Token::Word(_)) => Some(self.parse_identifier()?),
+                _ => None,
+            };
+            let (field_type, trailing_bracket) = self.parse_data_type_helper()?;
+            fields.push(StructField {
+                field_name,
+                field_type,
+            });
+            // a nested `>>` closed this struct as well
+            if trailing_bracket {
+                return Ok((fields, false));
+            }
+            if !self.consume_token(&Token::Comma) {
+                break;
+            }
+        }
+        match self.next_token() {
+            Token::Gt => Ok((fields, false)),
+            Token::ShiftRight => Ok((fields, true)),
+            unexpected => self.expected(">", unexpected),
+        }
+    }
+
+    fn parse_simple_data_type(&mut self) -> Result<DataType, ParserError> {
         match self.next_token() {
             Token::Word(w) => match w.keyword {
                 Keyword::BOOLEAN => Ok(DataType::Boolean),
                 Keyword::FLOAT => Ok(DataType::Float(self.parse_optional_precision()?)),
                 Keyword::REAL => Ok(DataType::Real),
                 Keyword::DOUBLE => {
-                    let _ = self.parse_keyword(Keyword::PRECISION);
-                    Ok(DataType::Double)
+                    if self.parse_keyword(Keyword::PRECISION) {
+                        Ok(DataType::DoublePrecision)
+                    } else {
+                        Ok(DataType::Double)
+                    }
                 }
+                Keyword::FLOAT64 => Ok(DataType::Float64),
                 Keyword::TINYINT => {
                     let optional_precision = self.parse_optional_precision();
                     if self.parse_keyword(Keyword::UNSIGNED) {
@@ -2386,6 +4078,14 @@ impl<'a> Parser<'a> {
                         Ok(DataType::SmallInt(optional_precision?))
                     }
                 }
+                Keyword::MEDIUMINT => {
+                    let optional_precision = self.parse_optional_precision();
+                    if self.parse_keyword(Keyword::UNSIGNED) {
+                        Ok(DataType::UnsignedMediumInt(optional_precision?))
+                    } else {
+                        Ok(DataType::MediumInt(optional_precision?))
+                    }
+                }
                 Keyword::INT | Keyword::INTEGER => {
                     let optional_precision = self.parse_optional_precision();
                     if self.parse_keyword(Keyword::UNSIGNED) {
@@ -2402,10 +4102,38 @@ impl<'a> Parser<'a> {
                         Ok(DataType::BigInt(optional_precision?))
                     }
                 }
-                Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_optional_precision()?)),
+                Keyword::INT64 => Ok(DataType::Int64),
+                Keyword::NUMBER => {
+                    if self.peek_token() == Token::LParen && self.peek_nth_token(1) == Token::Mul {
+                        self.expect_token(&Token::LParen)?;
+                        self.expect_token(&Token::Mul)?;
+                        self.expect_token(&Token::Comma)?;
+                        let scale = self.parse_literal_uint()?;
+                        self.expect_token(&Token::RParen)?;
+                        Ok(DataType::Number(None, Some(scale)))
+                    } else {
+                        let (precision, scale) = self.parse_optional_precision_scale()?;
+                        Ok(DataType::Number(precision, scale))
+                    }
+                }
+                Keyword::BIT => {
+                    if self.parse_keyword(Keyword::VARYING) {
+                        Ok(DataType::BitVarying(self.parse_optional_precision()?))
+                    } else {
+                        Ok(DataType::Bit(self.parse_optional_precision()?))
+                    }
+                }
+                Keyword::VARCHAR => Ok(DataType::Varchar(self.parse_optional_character_length()?)),
+                Keyword::NVARCHAR => {
+                    Ok(DataType::Nvarchar(self.parse_optional_character_length()?))
+                }
                 Keyword::CHAR | Keyword::CHARACTER => {
                     if self.parse_keyword(Keyword::VARYING) {
-                        Ok(DataType::Varchar(self.parse_optional_precision()?))
+                        Ok(DataType::Varchar(self.parse_optional_character_length()?))
+                    } else if self.parse_keywords(&[Keyword::LARGE, Keyword::OBJECT]) {
+                        Ok(DataType::CharacterLargeObject(
+                            self.parse_optional_precision()?,
+                        ))
                     } else {
                         Ok(DataType::Char(self.parse_optional_precision()?))
                     }
@@ -2413,22 +4141,32 @@ impl<'a> Parser<'a> {
                 Keyword::UUID => Ok(DataType::Uuid),
                 Keyword::DATE => Ok(DataType::Date),
                 Keyword::TIMESTAMP => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Timestamp(self.parse_optional_precision()?))
+                    let precision = self.parse_optional_precision()?;
+                    Ok(DataType::Timestamp(precision, self.parse_timezone_info()?))
                 }
+                Keyword::TIMESTAMPTZ => Ok(DataType::Timestamp(
+                    self.parse_optional_precision()?,
+                    TimezoneInfo::Tz,
+                )),
+                Keyword::TIMESTAMP_LTZ => Ok(DataType::Timestamp(
+                    self.parse_optional_precision()?,
+                    TimezoneInfo::LocalTz,
+                )),
+                Keyword::TIMESTAMP_NTZ => Ok(DataType::Timestamp(
+                    self.parse_optional_precision()?,
+                    TimezoneInfo::NoTz,
+                )),
 
                 Keyword::DATETIME => Ok(DataType::DateTime(self.parse_optional_precision()?)),
 
                 Keyword::TIME => {
-                    // TBD: we throw away "with/without timezone" information
-                    if self.parse_keyword(Keyword::WITH) || self.parse_keyword(Keyword::WITHOUT) {
-                        self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
-                    }
-                    Ok(DataType::Time)
+                    let precision = self.parse_optional_precision()?;
+                    Ok(DataType::Time(precision, self.parse_timezone_info()?))
                 }
+                Keyword::TIMETZ => Ok(DataType::Time(
+                    self.parse_optional_precision()?,
+                    TimezoneInfo::Tz,
+                )),
                 // Interval types can be followed by a complicated interval
                 // qualifier that we don't currently support. See
                 // parse_interval_literal for a taste.
@@ -2436,7 +4174,34 @@ impl<'a> Parser<'a> {
                 Keyword::REGCLASS => Ok(DataType::Regclass),
                 Keyword::STRING => Ok(DataType::String),
                 Keyword::TEXT => Ok(DataType::Text),
+                Keyword::TINYTEXT => Ok(DataType::TinyText),
+                Keyword::MEDIUMTEXT => Ok(DataType::MediumText),
+                Keyword::LONGTEXT => Ok(DataType::LongText),
+                Keyword::TINYBLOB => Ok(DataType::TinyBlob),
+                Keyword::MEDIUMBLOB => Ok(DataType::MediumBlob),
+                Keyword::LONGBLOB => Ok(DataType::LongBlob),
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::VARIANT => Ok(DataType::Variant),
+                Keyword::OBJECT => Ok(DataType::Object),
+                Keyword::JSON => Ok(DataType::Json),
+                Keyword::JSONB => Ok(DataType::Jsonb),
+                Keyword::GEOMETRY if self.peek_token() != Token::LParen => Ok(DataType::Geometry),
+                Keyword::INET => Ok(DataType::Inet),
+                Keyword::MONEY => Ok(DataType::Money),
+                Keyword::ENUM if self.peek_token() == Token::LParen => {
+                    Ok(DataType::Enum(self.parse_enum_members()?))
+                }
+                Keyword::SET if self.peek_token() == Token::LParen => {
+                    Ok(DataType::Set(self.parse_string_values()?))
+                }
+                Keyword::MAP if self.peek_token() == Token::LParen => {
+                    self.expect_token(&Token::LParen)?;
+                    let key = self.parse_data_type()?;
+                    self.expect_token(&Token::Comma)?;
+                    let value = self.parse_data_type()?;
+                    self.expect_token(&Token::RParen)?;
+                    Ok(DataType::Map(Box::new(key), Box::new(value)))
+                }
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2454,7 +4219,7 @@ impl<'a> Parser<'a> {
                     } else {
                         self.prev_token();
                         let type_name = self.parse_object_name()?;
-                        Ok(DataType::Custom(type_name))
+                        Ok(DataType::Custom(type_name, vec![]))
                     }
                 }
                 Keyword::TUPLE => {
@@ -2490,15 +4255,81 @@ impl<'a> Parser<'a> {
                     }
                 }
                 _ => {
+                    if self.peek_token() == Token::LParen
+                        && w.quote_style.is_none()
+                        && dialect_of!(self is ClickHouseDialect | GenericDialect)
+                    {
+                        if let Some(data_type) = self.parse_clickhouse_data_type(&w.value)? {
+                            return Ok(data_type);
+                        }
+                    }
                     self.prev_token();
                     let type_name = self.parse_object_name()?;
-                    Ok(DataType::Custom(type_name))
+                    let modifiers = self.parse_optional_type_modifiers()?;
+                    Ok(DataType::Custom(type_name, modifiers))
                 }
             },
             unexpected => self.expected("a data type name", unexpected),
         }
     }
 
+    /// Parse the parenthesized arguments of a ClickHouse wrapper or parameterized
+    /// type such as `Nullable(String)` or `DateTime64(3, 'UTC')`, returning `None`
+    /// if `name` is not one of these types
+    fn parse_clickhouse_data_type(&mut self, name: &str) -> Result<Option<DataType>, ParserError> {
+        let data_type = match name.to_uppercase().as_str() {
+            "NULLABLE" | "LOWCARDINALITY" => {
+                self.expect_token(&Token::LParen)?;
+                let inner = Box::new(self.parse_data_type()?);
+                self.expect_token(&Token::RParen)?;
+                if name.eq_ignore_ascii_case("Nullable") {
+                    DataType::Nullable(inner)
+                } else {
+                    DataType::LowCardinality(inner)
+                }
+            }
+            "NESTED" => {
+                self.expect_token(&Token::LParen)?;
+                let fields = self.parse_comma_separated(|parser| {
+                    let (name, data_type) = parser.parse_named_tuple()?;
+                    Ok(StructField {
+                        field_name: Some(name),
+                        field_type: data_type,
+                    })
+                })?;
+                self.expect_token(&Token::RParen)?;
+                DataType::Nested(fields)
+            }
+            "FIXEDSTRING" => DataType::FixedString(self.parse_parenthesized_uint()?),
+            "DECIMAL32" => DataType::Decimal32(self.parse_parenthesized_uint()?),
+            "DECIMAL64" => DataType::Decimal64(self.parse_parenthesized_uint()?),
+            "DECIMAL128" => DataType::Decimal128(self.parse_parenthesized_uint()?),
+            "DECIMAL256" => DataType::Decimal256(self.parse_parenthesized_uint()?),
+            "DATETIME64" => {
+                self.expect_token(&Token::LParen)?;
+                let precision = self.parse_literal_uint()?;
+                let time_zone = if self.consume_token(&Token::Comma) {
+                    Some(self.parse_literal_string()?)
+                } else {
+                    None
+                };
+                self.expect_token(&Token::RParen)?;
+                DataType::DateTime64(precision, time_zone)
+            }
+            "ENUM8" => DataType::Enum8(self.parse_enum_members()?),
+            "ENUM16" => DataType::Enum16(self.parse_enum_members()?),
+            _ => return Ok(None),
+        };
+        Ok(Some(data_type))
+    }
+
+    fn parse_parenthesized_uint(&mut self) -> Result<u64, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let n = self.parse_literal_uint()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(n)
+    }
+
     /// Parse `AS identifier` (or simply `identifier` if it's not a reserved keyword)
     /// Some examples with aliases: `SELECT 1 foo`, `SELECT COUNT(*) AS cnt`,
     /// `SELECT ... FROM t1 foo, t2 bar`, `SELECT ... FROM (...) AS bar`
@@ -2548,15 +4379,35 @@ impl<'a> Parser<'a> {
         &mut self,
         reserved_kwds: &[Keyword],
     ) -> Result<Option<TableAlias>, ParserError> {
+        if self.peek_table_factor_clause() {
+            return Ok(None);
+        }
         match self.parse_optional_alias(reserved_kwds)? {
             Some(name) => {
-                let columns = self.parse_parenthesized_column_list(Optional)?;
+                let columns = if self.consume_token(&Token::LParen) {
+                    let columns =
+                        self.parse_comma_separated(Parser::parse_table_alias_column_def)?;
+                    self.expect_token(&Token::RParen)?;
+                    columns
+                } else {
+                    vec![]
+                };
                 Ok(Some(TableAlias { name, columns }))
             }
             None => Ok(None),
         }
     }
 
+    /// Parse a column of a table alias, with an optional type: `name [ <data type> ]`
+    pub fn parse_table_alias_column_def(&mut self) -> Result<TableAliasColumnDef, ParserError> {
+        let name = self.parse_identifier()?;
+        let data_type = match self.peek_token() {
+            Token::Comma | Token::RParen => None,
+            _ => Some(self.parse_data_type()?),
+        };
+        Ok(TableAliasColumnDef { name, data_type })
+    }
+
     /// Parse a possibly qualified, possibly quoted identifier, e.g.
     /// `foo` or `myschema."table"
     pub fn parse_object_name(&mut self) -> Result<ObjectName, ParserError> {
@@ -2579,11 +4430,16 @@ impl<'a> Parser<'a> {
 
     /// Parse a possible time travel point, e.g.
     ///   `select * from t at(snapshot => xxxxxxxx);`
+    ///   `select * from t before(statement => 'xxxxxxxx');`
+    ///   `select * from t for system_time as of '2021-01-01';`
+    ///   `select * from t version as of 3;`
     pub fn parse_at(&mut self) -> Result<Option<Instant>, ParserError> {
         if self.parse_keyword(Keyword::AT) {
+            if !matches!(self.peek_nth_token(1), Token::Word(w) if w.keyword == Keyword::SNAPSHOT) {
+                let (point, value) = self.parse_instant_point()?;
+                return Ok(Some(Instant::At { point, value }));
+            }
             self.expect_token(&Token::LParen)?;
-
-            // only supports instant specified by snapshot id currently
             self.expect_keyword(Keyword::SNAPSHOT)?;
             self.expect_token(&Token::RArrow)?;
             let snapshot = match self.next_token() {
@@ -2596,11 +4452,75 @@ impl<'a> Parser<'a> {
 
             self.expect_token(&Token::RParen)?;
             Ok(Some(Instant::SnapshotID(snapshot)))
+        } else if self.parse_keywords(&[
+            Keyword::FOR,
+            Keyword::SYSTEM_TIME,
+            Keyword::AS,
+            Keyword::OF,
+        ]) {
+            Ok(Some(Instant::SystemTimeAsOf(Box::new(self.parse_expr()?))))
+        } else if self.parse_keywords(&[Keyword::VERSION, Keyword::AS, Keyword::OF]) {
+            Ok(Some(Instant::VersionAsOf(Box::new(self.parse_expr()?))))
+        } else if self.parse_keywords(&[Keyword::TIMESTAMP, Keyword::AS, Keyword::OF]) {
+            Ok(Some(Instant::TimestampAsOf(Box::new(self.parse_expr()?))))
+        } else if self.peek_nth_token(1) == Token::LParen && self.parse_keyword(Keyword::BEFORE) {
+            let (point, value) = self.parse_instant_point()?;
+            Ok(Some(Instant::Before { point, value }))
+        } else if self.peek_nth_token(1) == Token::LParen && self.parse_keyword(Keyword::CHANGES) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword(Keyword::INFORMATION)?;
+            self.expect_token(&Token::RArrow)?;
+            let information = self.parse_identifier()?;
+            self.expect_token(&Token::RParen)?;
+            let start = match self.parse_one_of_keywords(&[Keyword::AT, Keyword::BEFORE]) {
+                Some(Keyword::AT) => {
+                    let (point, value) = self.parse_instant_point()?;
+                    Instant::At { point, value }
+                }
+                Some(_) => {
+                    let (point, value) = self.parse_instant_point()?;
+                    Instant::Before { point, value }
+                }
+                None => return self.expected("AT or BEFORE after CHANGES", self.peek_token()),
+            };
+            let end = if self.peek_nth_token(1) == Token::LParen && self.parse_keyword(Keyword::END)
+            {
+                let (point, value) = self.parse_instant_point()?;
+                Some(Box::new(Instant::End { point, value }))
+            } else {
+                None
+            };
+            Ok(Some(Instant::Changes {
+                information,
+                start: Box::new(start),
+                end,
+            }))
         } else {
             Ok(None)
         }
     }
 
+    /// Parse the `(<point> => <expr>)` part of a Snowflake `AT`, `BEFORE` or `END` instant
+    fn parse_instant_point(&mut self) -> Result<(InstantPoint, Box<Expr>), ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let point = match self.expect_one_of_keywords(&[
+            Keyword::TIMESTAMP,
+            Keyword::OFFSET,
+            Keyword::STATEMENT,
+            Keyword::STREAM,
+        ])? {
+            Keyword::TIMESTAMP => InstantPoint::Timestamp,
+            Keyword::OFFSET => InstantPoint::Offset,
+            Keyword::STATEMENT => InstantPoint::Statement,
+            Keyword::STREAM => InstantPoint::Stream,
+            _ => unreachable!(),
+        };
+        self.expect_token(&Token::RArrow)?;
+        let value = Box::new(self.parse_expr()?);
+        self.expect_token(&Token::RParen)?;
+        Ok((point, value))
+    }
+
     /// Parse identifiers strictly i.e. don't parse keywords
     pub fn parse_identifiers_non_keywords(&mut self) -> Result<Vec<Ident>, ParserError> {
         let mut idents = vec![];
@@ -2686,6 +4606,20 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the optional `WITH TIME ZONE` / `WITHOUT TIME ZONE` suffix of a
+    /// `TIME` or `TIMESTAMP` type
+    pub fn parse_timezone_info(&mut self) -> Result<TimezoneInfo, ParserError> {
+        if self.parse_keyword(Keyword::WITH) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            Ok(TimezoneInfo::WithTimeZone)
+        } else if self.parse_keyword(Keyword::WITHOUT) {
+            self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+            Ok(TimezoneInfo::WithoutTimeZone)
+        } else {
+            Ok(TimezoneInfo::None)
+        }
+    }
+
     pub fn parse_optional_precision(&mut self) -> Result<Option<u64>, ParserError> {
         if self.consume_token(&Token::LParen) {
             let n = self.parse_literal_uint()?;
@@ -2696,6 +4630,80 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse an optional character length e.g. `(10)` or MSSQL `(MAX)`
+    pub fn parse_optional_character_length(
+        &mut self,
+    ) -> Result<Option<CharacterLength>, ParserError> {
+        if self.consume_token(&Token::LParen) {
+            let length = if self.parse_keyword(Keyword::MAX) {
+                CharacterLength::Max
+            } else {
+                CharacterLength::Length(self.parse_literal_uint()?)
+            };
+            self.expect_token(&Token::RParen)?;
+            Ok(Some(length))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Parse the optional modifiers of a custom type e.g. the `(Point, 4326)` of
+    /// `geometry(Point, 4326)`. String modifiers keep their quotes.
+    pub fn parse_optional_type_modifiers(&mut self) -> Result<Vec<String>, ParserError> {
+        if !self.consume_token(&Token::LParen) {
+            return Ok(vec![]);
+        }
+        let modifiers = self.parse_comma_separated(|parser| match parser.next_token() {
+            Token::Word(w) => Ok(w.to_string()),
+            Token::Number(n, _) => Ok(n.to_string()),
+            Token::Minus => match parser.next_token() {
+                Token::Number(n, _) => Ok(format!("-{}", n)),
+                unexpected => parser.expected("a number", unexpected),
+            },
+            Token::SingleQuotedString(s) => Ok(Value::SingleQuotedString(s).to_string()),
+            unexpected => parser.expected("a type modifier", unexpected),
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(modifiers)
+    }
+
+    /// Parse a parenthesized list of string literals e.g. the `('a', 'b')` of `ENUM('a', 'b')`
+    pub fn parse_string_values(&mut self) -> Result<Vec<String>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let values = self.parse_comma_separated(|parser| match parser.next_token() {
+            Token::SingleQuotedString(value) => Ok(value),
+            unexpected => parser.expected("a string", unexpected),
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(values)
+    }
+
+    /// Parse the members of an enumeration type, with optional ClickHouse values
+    /// e.g. the `('a' = 1, 'b' = -1)` of `Enum8('a' = 1, 'b' = -1)`
+    pub fn parse_enum_members(&mut self) -> Result<Vec<EnumMember>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let members = self.parse_comma_separated(|parser| {
+            let name = match parser.next_token() {
+                Token::SingleQuotedString(name) => name,
+                unexpected => return parser.expected("a string", unexpected),
+            };
+            let value = if parser.consume_token(&Token::Eq) {
+                let negative = parser.consume_token(&Token::Minus);
+                let magnitude = i128::from(parser.parse_literal_uint()?);
+                let value = if negative { -magnitude } else { magnitude };
+                match i64::try_from(value) {
+                    Ok(value) => Some(value),
+                    Err(_) => return parser_err!(format!("Enum value {} is out of range", value)),
+                }
+            } else {
+                None
+            };
+            Ok(EnumMember { name, value })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(members)
+    }
+
     pub fn parse_optional_precision_scale(
         &mut self,
     ) -> Result<(Option<u64>, Option<u64>), ParserError> {
@@ -2802,6 +4810,10 @@ impl<'a> Parser<'a> {
                     Some("CSV".to_string())
                 } else if self.parse_keyword(Keyword::PARQUET) {
                     Some("PARQUET".to_string())
+                } else if let Token::Word(w) = self.peek_token() {
+                    // format names such as JSON may also be keywords
+                    self.next_token();
+                    Some(w.value)
                 } else {
                     Some(self.parse_literal_string()?)
                 }
@@ -2856,7 +4868,16 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::LParen)?;
             let query = self.parse_query()?;
             self.expect_token(&Token::RParen)?;
-            let alias = TableAlias { name, columns };
+            let alias = TableAlias {
+                name,
+                columns: columns
+                    .into_iter()
+                    .map(|name| TableAliasColumnDef {
+                        name,
+                        data_type: None,
+                    })
+                    .collect(),
+            };
             Cte {
                 alias,
                 query,
@@ -3243,13 +5264,74 @@ impl<'a> Parser<'a> {
     }
 
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
+    /// and any number of table operators such as `PIVOT` or `MATCH_RECOGNIZE`
     pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
+        let mut table = self.parse_base_table_factor()?;
+        loop {
+            table = if self.parse_keyword(Keyword::PIVOT) {
+                self.parse_pivot_table_factor(table)?
+            } else if self.parse_keyword(Keyword::UNPIVOT) {
+                self.parse_unpivot_table_factor(table)?
+            } else if self.parse_keyword(Keyword::MATCH_RECOGNIZE) {
+                self.parse_match_recognize(table)?
+            } else {
+                return Ok(table);
+            };
+        }
+    }
+
+    /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
+    fn parse_base_table_factor(&mut self) -> Result<TableFactor, ParserError> {
         if self.parse_keyword(Keyword::LATERAL) {
-            // LATERAL must always be followed by a subquery.
+            // LATERAL must always be followed by a subquery or a table function.
+            if self.consume_token(&Token::LParen) {
+                return self.parse_derived_table_factor(Lateral);
+            }
+            let name = self.parse_object_name()?;
             if !self.consume_token(&Token::LParen) {
-                self.expected("subquery after LATERAL", self.peek_token())?;
+                self.expected("subquery or function after LATERAL", self.peek_token())?;
             }
-            self.parse_derived_table_factor(Lateral)
+            let args = self.parse_optional_args()?;
+            self.parse_table_function_factor(true, name, args)
+        } else if matches!(self.peek_token(), Token::Word(w) if w.keyword == Keyword::UNNEST)
+            && self.peek_nth_token(1) == Token::LParen
+        {
+            self.next_token();
+            self.expect_token(&Token::LParen)?;
+            let array_exprs = self.parse_comma_separated(Parser::parse_expr)?;
+            self.expect_token(&Token::RParen)?;
+            let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            let with_offset = self.parse_keywords(&[Keyword::WITH, Keyword::OFFSET]);
+            let with_offset_alias = if with_offset {
+                self.parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)?
+            } else {
+                None
+            };
+            Ok(TableFactor::UNNEST {
+                array_exprs,
+                with_ordinality,
+                alias,
+                with_offset,
+                with_offset_alias,
+            })
+        } else if self.parse_keywords(&[Keyword::ROWS, Keyword::FROM]) {
+            self.expect_token(&Token::LParen)?;
+            let functions = self.parse_comma_separated(|p| {
+                let name = p.parse_object_name()?;
+                match p.parse_function(name)? {
+                    Expr::Function(function) => Ok(function),
+                    _ => unreachable!(),
+                }
+            })?;
+            self.expect_token(&Token::RParen)?;
+            let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            Ok(TableFactor::RowsFrom {
+                functions,
+                with_ordinality,
+                alias,
+            })
         } else if self.parse_keyword(Keyword::TABLE) {
             // parse table function (SELECT * FROM TABLE (<expr>) [ AS <alias> ])
             self.expect_token(&Token::LParen)?;
@@ -3316,7 +5398,13 @@ impl<'a> Parser<'a> {
                     match &mut table_and_joins.relation {
                         TableFactor::Derived { alias, .. }
                         | TableFactor::Table { alias, .. }
-                        | TableFactor::TableFunction { alias, .. } => {
+                        | TableFactor::TableFunction { alias, .. }
+                        | TableFactor::Pivot { alias, .. }
+                        | TableFactor::Unpivot { alias, .. }
+                        | TableFactor::MatchRecognize { alias, .. }
+                        | TableFactor::Function { alias, .. }
+                        | TableFactor::UNNEST { alias, .. }
+                        | TableFactor::RowsFrom { alias, .. } => {
                             // but not `FROM (mytable AS alias1) AS alias2`.
                             if let Some(inner_alias) = alias {
                                 return Err(ParserError::ParserError(format!(
@@ -3343,12 +5431,24 @@ impl<'a> Parser<'a> {
             let name = self.parse_object_name()?;
             let instant: Option<Instant> = self.parse_at()?;
             // Postgres, MSSQL: table-valued functions:
-            let args = if self.consume_token(&Token::LParen) {
+            let is_function = self.consume_token(&Token::LParen);
+            let args = if is_function {
                 self.parse_optional_args()?
             } else {
                 vec![]
             };
-            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            // Postgres: `f() WITH ORDINALITY` or `f() AS (<column definitions>)`
+            if is_function && instant.is_none() {
+                let is_keyword = |token: Token, keyword: Keyword| matches!(token, Token::Word(w) if w.keyword == keyword);
+                let (first, second) = (self.peek_token(), self.peek_nth_token(1));
+                if (is_keyword(first.clone(), Keyword::WITH)
+                    && is_keyword(second.clone(), Keyword::ORDINALITY))
+                    || (is_keyword(first, Keyword::AS) && second == Token::LParen)
+                {
+                    return self.parse_table_function_factor(false, name, args);
+                }
+            }
+            let (alias, sample) = self.parse_table_alias_and_sample()?;
             // MSSQL-specific table hints:
             let mut with_hints = vec![];
             if self.parse_keyword(Keyword::WITH) {
@@ -3366,17 +5466,424 @@ impl<'a> Parser<'a> {
                 args,
                 with_hints,
                 instant,
+                sample,
             })
         }
     }
 
+    /// Parse the options following the arguments of a table-valued function:
+    /// `[ WITH ORDINALITY ] [ AS (<column definitions>) | [AS] <alias> ]`
+    fn parse_table_function_factor(
+        &mut self,
+        lateral: bool,
+        name: ObjectName,
+        args: Vec<FunctionArg>,
+    ) -> Result<TableFactor, ParserError> {
+        let with_ordinality = self.parse_keywords(&[Keyword::WITH, Keyword::ORDINALITY]);
+        let column_defs = if self.peek_nth_token(1) == Token::LParen
+            && self.parse_keyword(Keyword::AS)
+        {
+            self.expect_token(&Token::LParen)?;
+            let column_defs = self.parse_comma_separated(Parser::parse_table_alias_column_def)?;
+            self.expect_token(&Token::RParen)?;
+            column_defs
+        } else {
+            vec![]
+        };
+        let alias = if column_defs.is_empty() {
+            self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?
+        } else {
+            None
+        };
+        Ok(TableFactor::Function {
+            lateral,
+            name,
+            args,
+            with_ordinality,
+            column_defs,
+            alias,
+        })
+    }
+
+    /// Parse the parenthesized part of `<table> PIVOT(<aggregate> FOR <column> IN (...))`,
+    /// assuming the `PIVOT` keyword was already consumed
+    pub fn parse_pivot_table_factor(
+        &mut self,
+        table: TableFactor,
+    ) -> Result<TableFactor, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let aggregate_function = self.parse_expr()?;
+        self.expect_keyword(Keyword::FOR)?;
+        let value_column = self.parse_object_name()?.0;
+        self.expect_keyword(Keyword::IN)?;
+        self.expect_token(&Token::LParen)?;
+        let pivot_values = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        Ok(TableFactor::Pivot {
+            table: Box::new(table),
+            aggregate_function,
+            value_column,
+            pivot_values,
+            alias,
+        })
+    }
+
+    /// Parse the parenthesized part of `<table> UNPIVOT(<value> FOR <name> IN (...))`,
+    /// assuming the `UNPIVOT` keyword was already consumed
+    pub fn parse_unpivot_table_factor(
+        &mut self,
+        table: TableFactor,
+    ) -> Result<TableFactor, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let value = self.parse_identifier()?;
+        self.expect_keyword(Keyword::FOR)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(Keyword::IN)?;
+        let columns = self.parse_parenthesized_column_list(Mandatory)?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        Ok(TableFactor::Unpivot {
+            table: Box::new(table),
+            value,
+            name,
+            columns,
+            alias,
+        })
+    }
+
+    /// Parse the parenthesized part of `<table> MATCH_RECOGNIZE(...)`,
+    /// assuming the `MATCH_RECOGNIZE` keyword was already consumed
+    pub fn parse_match_recognize(
+        &mut self,
+        table: TableFactor,
+    ) -> Result<TableFactor, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let partition_by = if self.parse_keywords(&[Keyword::PARTITION, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_expr)?
+        } else {
+            vec![]
+        };
+        let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
+            self.parse_comma_separated(Parser::parse_order_by_expr)?
+        } else {
+            vec![]
+        };
+        let measures = if self.parse_keyword(Keyword::MEASURES) {
+            self.parse_comma_separated(|p| {
+                let expr = p.parse_expr()?;
+                let _ = p.parse_keyword(Keyword::AS);
+                let alias = p.parse_identifier()?;
+                Ok(Measure { expr, alias })
+            })?
+        } else {
+            vec![]
+        };
+        let rows_per_match =
+            if self.parse_keywords(&[Keyword::ONE, Keyword::ROW, Keyword::PER, Keyword::MATCH]) {
+                Some(RowsPerMatch::OneRow)
+            } else if self.parse_keywords(&[
+                Keyword::ALL,
+                Keyword::ROWS,
+                Keyword::PER,
+                Keyword::MATCH,
+            ]) {
+                let mode =
+                    if self.parse_keywords(&[Keyword::SHOW, Keyword::EMPTY, Keyword::MATCHES]) {
+                        Some(EmptyMatchesMode::Show)
+                    } else if self.parse_keywords(&[
+                        Keyword::OMIT,
+                        Keyword::EMPTY,
+                        Keyword::MATCHES,
+                    ]) {
+                        Some(EmptyMatchesMode::Omit)
+                    } else if self.parse_keywords(&[
+                        Keyword::WITH,
+                        Keyword::UNMATCHED,
+                        Keyword::ROWS,
+                    ]) {
+                        Some(EmptyMatchesMode::WithUnmatched)
+                    } else {
+                        None
+                    };
+                Some(RowsPerMatch::AllRows(mode))
+            } else {
+                None
+            };
+        let after_match_skip =
+            if self.parse_keywords(&[Keyword::AFTER, Keyword::MATCH, Keyword::SKIP]) {
+                if self.parse_keywords(&[Keyword::PAST, Keyword::LAST, Keyword::ROW]) {
+                    Some(AfterMatchSkip::PastLastRow)
+                } else if self.parse_keywords(&[Keyword::TO, Keyword::NEXT, Keyword::ROW]) {
+                    Some(AfterMatchSkip::ToNextRow)
+                } else if self.parse_keywords(&[Keyword::TO, Keyword::FIRST]) {
+                    Some(AfterMatchSkip::ToFirst(self.parse_identifier()?))
+                } else if self.parse_keywords(&[Keyword::TO, Keyword::LAST]) {
+                    Some(AfterMatchSkip::ToLast(self.parse_identifier()?))
+                } else {
+                    return self.expected(
+                        "PAST LAST ROW, TO NEXT ROW, TO FIRST or TO LAST after AFTER MATCH SKIP",
+                        self.peek_token(),
+                    );
+                }
+            } else {
+                None
+            };
+        self.expect_keyword(Keyword::PATTERN)?;
+        self.expect_token(&Token::LParen)?;
+        let pattern = self.parse_pattern()?;
+        self.expect_token(&Token::RParen)?;
+        self.expect_keyword(Keyword::DEFINE)?;
+        let symbols = self.parse_comma_separated(|p| {
+            let symbol = p.parse_identifier()?;
+            p.expect_keyword(Keyword::AS)?;
+            let definition = p.parse_expr()?;
+            Ok(SymbolDefinition { symbol, definition })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        Ok(TableFactor::MatchRecognize {
+            table: Box::new(table),
+            partition_by,
+            order_by,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            pattern,
+            symbols,
+            alias,
+        })
+    }
+
+    /// Parse a `MATCH_RECOGNIZE` row pattern: alternatives separated by `|`
+    pub fn parse_pattern(&mut self) -> Result<MatchRecognizePattern, ParserError> {
+        let pattern = self.parse_concat_pattern()?;
+        if self.peek_token() != Token::Pipe {
+            return Ok(pattern);
+        }
+        let mut patterns = vec![pattern];
+        while self.consume_token(&Token::Pipe) {
+            patterns.push(self.parse_concat_pattern()?);
+        }
+        Ok(MatchRecognizePattern::Alternation(patterns))
+    }
+
+    fn parse_concat_pattern(&mut self) -> Result<MatchRecognizePattern, ParserError> {
+        let mut patterns = vec![self.parse_repetition_pattern()?];
+        while !matches!(self.peek_token(), Token::RParen | Token::Pipe) {
+            patterns.push(self.parse_repetition_pattern()?);
+        }
+        if patterns.len() == 1 {
+            Ok(patterns.remove(0))
+        } else {
+            Ok(MatchRecognizePattern::Concat(patterns))
+        }
+    }
+
+    fn parse_repetition_pattern(&mut self) -> Result<MatchRecognizePattern, ParserError> {
+        let mut pattern = self.parse_base_pattern()?;
+        loop {
+            let quantifier = match self.next_token() {
+                Token::Mul => RepetitionQuantifier::ZeroOrMore,
+                Token::Plus => RepetitionQuantifier::OneOrMore,
+                // only PostgreSQL and generic tokenize a lone `?` as an operator
+                Token::Question | Token::Char('?') => RepetitionQuantifier::AtMostOne,
+                // `{-` starts an exclusion rather than a quantifier
+                Token::LBrace if self.peek_token() != Token::Minus => {
+                    let quantifier = if self.consume_token(&Token::Comma) {
+                        RepetitionQuantifier::AtMost(self.parse_literal_uint()?)
+                    } else {
+                        let n = self.parse_literal_uint()?;
+                        if !self.consume_token(&Token::Comma) {
+                            RepetitionQuantifier::Exactly(n)
+                        } else if self.peek_token() == Token::RBrace {
+                            RepetitionQuantifier::AtLeast(n)
+                        } else {
+                            RepetitionQuantifier::Range(n, self.parse_literal_uint()?)
+                        }
+                    };
+                    self.expect_token(&Token::RBrace)?;
+                    quantifier
+                }
+                _ => {
+                    self.prev_token();
+                    return Ok(pattern);
+                }
+            };
+            // a `?` right after a quantifier makes it reluctant rather than optional
+            let reluctant = matches!(self.peek_token(), Token::Question | Token::Char('?'));
+            if reluctant {
+                self.next_token();
+            }
+            pattern = MatchRecognizePattern::Repetition {
+                pattern: Box::new(pattern),
+                quantifier,
+                reluctant,
+            };
+        }
+    }
+
+    fn parse_base_pattern(&mut self) -> Result<MatchRecognizePattern, ParserError> {
+        match self.next_token() {
+            Token::Caret => Ok(MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Start)),
+            Token::Char('$') => Ok(MatchRecognizePattern::Symbol(MatchRecognizeSymbol::End)),
+            // Hive tokenizes a lone `$` as a word, since it may start an identifier
+            Token::Word(w) if w.value == "$" && w.quote_style.is_none() => {
+                Ok(MatchRecognizePattern::Symbol(MatchRecognizeSymbol::End))
+            }
+            Token::LBrace => {
+                self.expect_token(&Token::Minus)?;
+                let symbol = MatchRecognizeSymbol::Named(self.parse_identifier()?);
+                self.expect_token(&Token::Minus)?;
+                self.expect_token(&Token::RBrace)?;
+                Ok(MatchRecognizePattern::Exclude(symbol))
+            }
+            Token::Word(w)
+                if w.keyword == Keyword::PERMUTE && self.peek_token() == Token::LParen =>
+            {
+                self.expect_token(&Token::LParen)?;
+                let symbols = self.parse_comma_separated(|p| {
+                    p.parse_identifier().map(MatchRecognizeSymbol::Named)
+                })?;
+                self.expect_token(&Token::RParen)?;
+                Ok(MatchRecognizePattern::Permute(symbols))
+            }
+            Token::Word(w) => Ok(MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(
+                w.to_ident(),
+            ))),
+            Token::LParen => {
+                let pattern = self.parse_pattern()?;
+                self.expect_token(&Token::RParen)?;
+                Ok(MatchRecognizePattern::Group(Box::new(pattern)))
+            }
+            unexpected => self.expected("a pattern symbol", unexpected),
+        }
+    }
+
+    /// Parse the optional alias of a table factor together with an optional
+    /// sampling clause, which Hive allows before the alias and other dialects after it
+    fn parse_table_alias_and_sample(
+        &mut self,
+    ) -> Result<(Option<TableAlias>, Option<TableSampleKind>), ParserError> {
+        if let Some(sample) = self.parse_optional_table_sample()? {
+            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            // without an alias both positions are the same, so prefer the common one
+            let sample = if alias.is_some() {
+                TableSampleKind::BeforeTableAlias(sample)
+            } else {
+                TableSampleKind::AfterTableAlias(sample)
+            };
+            return Ok((alias, Some(sample)));
+        }
+        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let sample = self
+            .parse_optional_table_sample()?
+            .map(TableSampleKind::AfterTableAlias);
+        Ok((alias, sample))
+    }
+
+    /// Whether the next tokens start a clause following a table factor, such as
+    /// `TABLESAMPLE (...)` or `PIVOT (...)`, rather than a table alias. These
+    /// keywords are not reserved, so e.g. `FROM t sample` still aliases `t`.
+    fn peek_table_factor_clause(&self) -> bool {
+        let keyword = match self.peek_token() {
+            Token::Word(w) => w.keyword,
+            _ => return false,
+        };
+        let next = self.peek_nth_token(1);
+        let starts_sample = match &next {
+            Token::LParen => true,
+            Token::Word(w) => matches!(
+                w.keyword,
+                Keyword::BERNOULLI | Keyword::SYSTEM | Keyword::ROW | Keyword::BLOCK
+            ),
+            _ => false,
+        };
+        match keyword {
+            Keyword::TABLESAMPLE => starts_sample,
+            Keyword::SAMPLE => starts_sample && dialect_of!(self is SnowflakeDialect),
+            Keyword::PIVOT | Keyword::UNPIVOT | Keyword::MATCH_RECOGNIZE => next == Token::LParen,
+            _ => false,
+        }
+    }
+
+    /// Parse an optional `TABLESAMPLE [ <method> ] (<size>) [ REPEATABLE (<seed>) ]`
+    /// clause, or its Snowflake `SAMPLE` / `SEED` equivalent
+    pub fn parse_optional_table_sample(&mut self) -> Result<Option<TableSample>, ParserError> {
+        let modifier = match self.peek_token() {
+            Token::Word(w) if self.peek_table_factor_clause() => match w.keyword {
+                Keyword::TABLESAMPLE => TableSampleModifier::TableSample,
+                Keyword::SAMPLE => TableSampleModifier::Sample,
+                _ => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        self.next_token();
+        let method = match self.parse_one_of_keywords(&[
+            Keyword::BERNOULLI,
+            Keyword::SYSTEM,
+            Keyword::ROW,
+            Keyword::BLOCK,
+        ]) {
+            Some(Keyword::BERNOULLI) => Some(TableSampleMethod::Bernoulli),
+            Some(Keyword::SYSTEM) => Some(TableSampleMethod::System),
+            Some(Keyword::ROW) => Some(TableSampleMethod::Row),
+            Some(Keyword::BLOCK) => Some(TableSampleMethod::Block),
+            _ => None,
+        };
+        self.expect_token(&Token::LParen)?;
+        let size = if self.parse_keyword(Keyword::BUCKET) {
+            let bucket = self.parse_number_value()?;
+            self.expect_keywords(&[Keyword::OUT, Keyword::OF])?;
+            let total = self.parse_number_value()?;
+            let on = if self.parse_keyword(Keyword::ON) {
+                Some(Box::new(self.parse_expr()?))
+            } else {
+                None
+            };
+            TableSampleSize::Bucket { bucket, total, on }
+        } else {
+            let value = self.parse_expr()?;
+            let unit = match self.parse_one_of_keywords(&[Keyword::ROWS, Keyword::PERCENT]) {
+                Some(Keyword::ROWS) => Some(TableSampleUnit::Rows),
+                Some(Keyword::PERCENT) => Some(TableSampleUnit::Percent),
+                _ => None,
+            };
+            TableSampleSize::Quantity { value, unit }
+        };
+        self.expect_token(&Token::RParen)?;
+        let seed = match self.parse_one_of_keywords(&[Keyword::REPEATABLE, Keyword::SEED]) {
+            Some(keyword) => {
+                self.expect_token(&Token::LParen)?;
+                let value = self.parse_number_value()?;
+                self.expect_token(&Token::RParen)?;
+                Some(TableSampleSeed {
+                    modifier: if keyword == Keyword::REPEATABLE {
+                        TableSampleSeedModifier::Repeatable
+                    } else {
+                        TableSampleSeedModifier::Seed
+                    },
+                    value,
+                })
+            }
+            None => None,
+        };
+        Ok(Some(TableSample {
+            modifier,
+            method,
+            size,
+            seed,
+        }))
+    }
+
     pub fn parse_derived_table_factor(
         &mut self,
         lateral: IsLateral,
     ) -> Result<TableFactor, ParserError> {
         let subquery = Box::new(self.parse_query()?);
         self.expect_token(&Token::RParen)?;
-        let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+        let (alias, sample) = self.parse_table_alias_and_sample()?;
         Ok(TableFactor::Derived {
             lateral: match lateral {
                 Lateral => true,
@@ -3384,6 +5891,7 @@ impl<'a> Parser<'a> {
             },
             subquery,
             alias,
+            sample,
         })
     }
 
@@ -3690,11 +6198,49 @@ impl<'a> Parser<'a> {
             let arg = self.parse_wildcard_expr()?.into();
 
             Ok(FunctionArg::Named { name, arg })
+        } else if let Some(lambda) = self.parse_optional_lambda()? {
+            Ok(FunctionArg::Unnamed(FunctionArgExpr::Expr(lambda)))
         } else {
             Ok(FunctionArg::Unnamed(self.parse_wildcard_expr()?.into()))
         }
     }
 
+    /// Parse a lambda `x -> expr` or `(x, y) -> expr` passed to a higher-order
+    /// function, returning `None` if the next tokens do not start a lambda.
+    /// Only dialects with lambdas parse them, as elsewhere `->` is a JSON operator.
+    fn parse_optional_lambda(&mut self) -> Result<Option<Expr>, ParserError> {
+        if !dialect_of!(self is HiveDialect | SnowflakeDialect | ClickHouseDialect) {
+            return Ok(None);
+        }
+        let params = match self.peek_token() {
+            Token::Word(w) if w.quote_style.is_none() && self.peek_nth_token(1) == Token::Arrow => {
+                vec![self.parse_identifier()?]
+            }
+            Token::LParen => {
+                let params = self.maybe_parse(|parser| {
+                    parser.expect_token(&Token::LParen)?;
+                    let params = parser.parse_comma_separated(Parser::parse_identifier)?;
+                    parser.expect_token(&Token::RParen)?;
+                    if parser.peek_token() == Token::Arrow {
+                        Ok(params)
+                    } else {
+                        parser.expected("->", parser.peek_token())
+                    }
+                });
+                match params {
+                    Some(params) => params,
+                    None => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+        self.expect_token(&Token::Arrow)?;
+        Ok(Some(Expr::Lambda {
+            params,
+            body: Box::new(self.parse_expr()?),
+        }))
+    }
+
     pub fn parse_optional_args(&mut self) -> Result<Vec<FunctionArg>, ParserError> {
         if self.consume_token(&Token::RParen) {
             Ok(vec![])
@@ -3722,6 +6268,13 @@ impl<'a> Parser<'a> {
     /// Parse a comma-delimited list of projections after SELECT
     pub fn parse_select_item(&mut self) -> Result<SelectItem, ParserError> {
         match self.parse_wildcard_expr()? {
+            WildcardExpr::Expr(expr)
+                if self.peek_token() == Token::Period && self.peek_nth_token(1) == Token::Mul =>
+            {
+                self.next_token();
+                self.next_token();
+                Ok(SelectItem::ExprWildcard(expr))
+            }
             WildcardExpr::Expr(expr) => self
                 .parse_optional_alias(keywords::RESERVED_FOR_COLUMN_ALIAS)
                 .map(|alias| match alias {
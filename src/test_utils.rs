@@ -154,6 +154,25 @@ pub fn only<T>(v: impl IntoIterator<Item = T>) -> T {
     }
 }
 
+/// Returns the only operation of an `ALTER TABLE` statement, after checking
+/// the name of the altered table
+pub fn alter_table_op_with_name(stmt: Statement, expected_name: &str) -> AlterTableOperation {
+    match stmt {
+        Statement::AlterTable { name, operations } => {
+            assert_eq!(name.to_string(), expected_name);
+            only(operations)
+        }
+        _ => panic!("Expected ALTER TABLE statement"),
+    }
+}
+
+pub fn alter_table_op(stmt: Statement) -> AlterTableOperation {
+    match stmt {
+        Statement::AlterTable { operations, .. } => only(operations),
+        _ => panic!("Expected ALTER TABLE statement"),
+    }
+}
+
 pub fn expr_from_projection(item: &SelectItem) -> &Expr {
     match item {
         SelectItem::UnnamedExpr(expr) => expr,
@@ -179,6 +198,7 @@ pub fn table(name: impl Into<String>) -> TableFactor {
         args: vec![],
         with_hints: vec![],
         instant: None,
+        sample: None,
     }
 }
 
@@ -32,8 +32,11 @@ use hashbrown::HashMap;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::ast::DollarQuotedString;
 use crate::dialect::SnowflakeDialect;
-use crate::dialect::{Dialect, MySqlDialect};
+use crate::dialect::{
+    ClickHouseDialect, Dialect, GenericDialect, HiveDialect, MySqlDialect, PostgreSqlDialect,
+};
 use crate::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
 
 /// SQL Token enumeration
@@ -57,6 +60,12 @@ pub enum Token {
     NationalStringLiteral(String),
     /// Hexadecimal string literal: i.e.: X'deadbeef'
     HexStringLiteral(String),
+    /// Bit string literal: i.e.: B'0101'
+    BitStringLiteral(String),
+    /// Dollar-quoted string: i.e: $$string$$ or $tag$string$tag$
+    DollarQuotedString(DollarQuotedString),
+    /// Positional parameter placeholder: i.e. $1
+    Placeholder(String),
     /// Comma
     Comma,
     /// Whitespace (space, tab, etc)
@@ -99,6 +108,8 @@ pub enum Token {
     Colon,
     /// DoubleColon `::` (used for casting in postgresql)
     DoubleColon,
+    /// Assignment `:=` (used for variable assignment in MySQL)
+    Assignment,
     /// SemiColon `;` used as separator for COPY and payload
     SemiColon,
     /// Backslash `\` used in terminating the COPY payload with `\.`
@@ -143,6 +154,26 @@ pub enum Token {
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
     PGCubeRoot,
+    /// `->`, used as a JSON field access operator in PostgreSQL and MySQL
+    Arrow,
+    /// `->>`, used as a JSON field access-as-text operator in PostgreSQL and MySQL
+    LongArrow,
+    /// `#>`, a JSON path access operator in PostgreSQL
+    HashArrow,
+    /// `#>>`, a JSON path access-as-text operator in PostgreSQL
+    HashLongArrow,
+    /// `@>`, a containment operator in PostgreSQL
+    AtArrow,
+    /// `<@`, a contained-by operator in PostgreSQL
+    ArrowAt,
+    /// `?`, a JSONB key existence operator in PostgreSQL
+    Question,
+    /// `?|`, a JSONB any-key existence operator in PostgreSQL
+    QuestionPipe,
+    /// `?&`, a JSONB all-keys existence operator in PostgreSQL
+    QuestionAnd,
+    /// `@?`, a JSONB path existence operator in PostgreSQL
+    AtQuestion,
 }
 
 impl fmt::Display for Token {
@@ -158,6 +189,9 @@ impl fmt::Display for Token {
             Token::AtString(ref s) => write!(f, "@{}", s),
             Token::NationalStringLiteral(ref s) => write!(f, "N'{}'", s),
             Token::HexStringLiteral(ref s) => write!(f, "X'{}'", s),
+            Token::BitStringLiteral(ref s) => write!(f, "B'{}'", s),
+            Token::DollarQuotedString(ref s) => write!(f, "{}", s),
+            Token::Placeholder(ref s) => write!(f, "{}", s),
             Token::Comma => f.write_str(","),
             Token::Whitespace(ws) => write!(f, "{}", ws),
             Token::DoubleEq => f.write_str("=="),
@@ -179,6 +213,7 @@ impl fmt::Display for Token {
             Token::Period => f.write_str("."),
             Token::Colon => f.write_str(":"),
             Token::DoubleColon => f.write_str("::"),
+            Token::Assignment => f.write_str(":="),
             Token::SemiColon => f.write_str(";"),
             Token::Backslash => f.write_str("\\"),
             Token::LBracket => f.write_str("["),
@@ -201,6 +236,16 @@ impl fmt::Display for Token {
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
             Token::PGCubeRoot => f.write_str("||/"),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::HashLongArrow => f.write_str("#>>"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::ArrowAt => f.write_str("<@"),
+            Token::Question => f.write_str("?"),
+            Token::QuestionPipe => f.write_str("?|"),
+            Token::QuestionAnd => f.write_str("?&"),
+            Token::AtQuestion => f.write_str("@?"),
         }
     }
 }
@@ -476,6 +521,32 @@ impl<'a> Tokenizer<'a> {
                             }
                         }
                     }
+                    // PostgreSQL and MySQL bit strings e.g. B'0101' or b'0101'
+                    b @ 'b' | b @ 'B' if dialect_of!(self is MySqlDialect | PostgreSqlDialect | GenericDialect) =>
+                    {
+                        chars.next(); // consume, to check the next char
+                        match chars.peek() {
+                            Some((_, '\'')) => {
+                                let s = self.tokenize_single_quoted_string(chars)?;
+                                Ok(Some(Token::BitStringLiteral(s)))
+                            }
+                            _ => {
+                                // regular identifier starting with a "B"
+                                let s = self.tokenize_word(b, chars);
+
+                                let token = Token::make_word(&s, None);
+                                Self::save_position_if_necessary(
+                                    position_map,
+                                    &token,
+                                    token_idx,
+                                    chars,
+                                    pos as u64,
+                                );
+
+                                Ok(Some(Token::make_word(&s, None)))
+                            }
+                        }
+                    }
                     // identifier or keyword
                     ch if self.dialect.is_identifier_start(ch) => {
                         chars.next(); // consume the first char
@@ -501,6 +572,8 @@ impl<'a> Tokenizer<'a> {
 
                         Ok(Some(token))
                     }
+                    // positional parameter placeholder, e.g. $1, or dollar-quoted string
+                    '$' => Ok(Some(self.tokenize_dollar_preceded_value(chars)?)),
                     // string
                     '\'' => {
                         let s = self.tokenize_single_quoted_string(chars)?;
@@ -600,6 +673,15 @@ impl<'a> Tokenizer<'a> {
                                     comment,
                                 })))
                             }
+                            Some((_, '>')) => {
+                                chars.next(); // consume the '>'
+                                match chars.peek() {
+                                    Some((_, '>')) => {
+                                        self.consume_and_return(chars, Token::LongArrow)
+                                    }
+                                    _ => Ok(Some(Token::Arrow)),
+                                }
+                            }
                             // a regular '-' operator
                             _ => Ok(Some(Token::Minus)),
                         }
@@ -684,6 +766,10 @@ impl<'a> Tokenizer<'a> {
                             }
                             Some((_, '>')) => self.consume_and_return(chars, Token::Neq),
                             Some((_, '<')) => self.consume_and_return(chars, Token::ShiftLeft),
+                            // MSSQL `a<@b` compares with a variable
+                            Some((_, '@')) if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                                self.consume_and_return(chars, Token::ArrowAt)
+                            }
                             _ => Ok(Some(Token::Lt)),
                         }
                     }
@@ -699,6 +785,7 @@ impl<'a> Tokenizer<'a> {
                         chars.next();
                         match chars.peek() {
                             Some((_, ':')) => self.consume_and_return(chars, Token::DoubleColon),
+                            Some((_, '=')) => self.consume_and_return(chars, Token::Assignment),
                             _ => Ok(Some(Token::Colon)),
                         }
                     }
@@ -736,8 +823,38 @@ impl<'a> Tokenizer<'a> {
                             _ => Ok(Some(Token::Tilde)),
                         }
                     }
-                    '#' => self.consume_and_return(chars, Token::Sharp),
+                    '#' => {
+                        chars.next(); // consume the '#'
+                        match chars.peek() {
+                            Some((_, '>')) => {
+                                chars.next(); // consume the '>'
+                                match chars.peek() {
+                                    Some((_, '>')) => {
+                                        self.consume_and_return(chars, Token::HashLongArrow)
+                                    }
+                                    _ => Ok(Some(Token::HashArrow)),
+                                }
+                            }
+                            _ => Ok(Some(Token::Sharp)),
+                        }
+                    }
+                    '@' if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                        chars.next(); // consume the '@'
+                        match chars.peek() {
+                            Some((_, '>')) => self.consume_and_return(chars, Token::AtArrow),
+                            Some((_, '?')) => self.consume_and_return(chars, Token::AtQuestion),
+                            _ => Ok(Some(Token::AtSign)),
+                        }
+                    }
                     '@' => self.consume_and_return(chars, Token::AtSign),
+                    '?' if dialect_of!(self is PostgreSqlDialect | GenericDialect) => {
+                        chars.next(); // consume the '?'
+                        match chars.peek() {
+                            Some((_, '|')) => self.consume_and_return(chars, Token::QuestionPipe),
+                            Some((_, '&')) => self.consume_and_return(chars, Token::QuestionAnd),
+                            _ => Ok(Some(Token::Question)),
+                        }
+                    }
                     other => self.consume_and_return(chars, Token::Char(other)),
                 }
             }
@@ -772,6 +889,40 @@ impl<'a> Tokenizer<'a> {
         s
     }
 
+    /// Tokenize a placeholder such as `$1` or a dollar-quoted string such as
+    /// `$$abc$$` or `$tag$abc$tag$`, starting with the first `$`.
+    fn tokenize_dollar_preceded_value(
+        &self,
+        chars: &mut Peekable<CharIndices<'_>>,
+    ) -> Result<Token, TokenizerError> {
+        chars.next(); // consume the '$'
+        let s = peeking_take_while(chars, |ch| ch.is_ascii_digit());
+        if !s.is_empty() {
+            return Ok(Token::Placeholder(format!("${}", s)));
+        }
+        // look ahead for the closing `$` of the opening tag without consuming
+        // anything, so that a lone `$` is still returned as a character
+        let mut lookahead = chars.clone();
+        let tag = peeking_take_while(&mut lookahead, |ch| ch.is_alphanumeric() || ch == '_');
+        if !matches!(lookahead.next(), Some((_, '$'))) {
+            return Ok(Token::Char('$'));
+        }
+        *chars = lookahead;
+        let delimiter = format!("${}$", tag);
+        let mut value = String::new();
+        for (_, ch) in chars.by_ref() {
+            value.push(ch);
+            if value.ends_with(&delimiter) {
+                value.truncate(value.len() - delimiter.len());
+                return Ok(Token::DollarQuotedString(DollarQuotedString {
+                    value,
+                    tag: if tag.is_empty() { None } else { Some(tag) },
+                }));
+            }
+        }
+        self.tokenizer_error("Unterminated dollar-quoted string")
+    }
+
     /// Read a single quoted string, starting with the opening quote.
     fn tokenize_single_quoted_string(
         &self,
@@ -790,7 +941,10 @@ impl<'a> Tokenizer<'a> {
                         return Ok(s);
                     }
                 }
-                '\\' => {
+                '\\' if dialect_of!(
+                    self is MySqlDialect | HiveDialect | SnowflakeDialect | ClickHouseDialect
+                ) =>
+                {
                     if let Some((_, c)) = chars.next() {
                         match c {
                             'n' => s.push('\n'),
@@ -985,7 +1139,7 @@ fn peeking_take_while(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dialect::{GenericDialect, MsSqlDialect};
+    use crate::dialect::{GenericDialect, MsSqlDialect, MySqlDialect, PostgreSqlDialect};
 
     #[test]
     fn tokenizer_error_impl() {
@@ -1560,6 +1714,139 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_json_operators() {
+        let sql = "a->b->>c#>d#>>e@>f<@g?h?|i?&j@?k-l#m@n<o";
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let (tokens, _) = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a", None),
+            Token::Arrow,
+            Token::make_word("b", None),
+            Token::LongArrow,
+            Token::make_word("c", None),
+            Token::HashArrow,
+            Token::make_word("d", None),
+            Token::HashLongArrow,
+            Token::make_word("e", None),
+            Token::AtArrow,
+            Token::make_word("f", None),
+            Token::ArrowAt,
+            Token::make_word("g", None),
+            Token::Question,
+            Token::make_word("h", None),
+            Token::QuestionPipe,
+            Token::make_word("i", None),
+            Token::QuestionAnd,
+            Token::make_word("j", None),
+            Token::AtQuestion,
+            Token::make_word("k", None),
+            Token::Minus,
+            Token::make_word("l", None),
+            Token::Sharp,
+            Token::make_word("m", None),
+            Token::AtSign,
+            Token::make_word("n", None),
+            Token::Lt,
+            Token::make_word("o", None),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_placeholder() {
+        let sql = "SELECT $1, $23 $";
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let (tokens, _) = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$1".to_string()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::Placeholder("$23".to_string()),
+            Token::Whitespace(Whitespace::Space),
+            Token::Char('$'),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_dollar_quoted_string() {
+        let sql = "SELECT $$it's $1$$, $fn$a $$ b$fn$, $x";
+        let dialect = PostgreSqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let (tokens, _) = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::DollarQuotedString(DollarQuotedString {
+                value: "it's $1".to_string(),
+                tag: None,
+            }),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::DollarQuotedString(DollarQuotedString {
+                value: "a $$ b".to_string(),
+                tag: Some("fn".to_string()),
+            }),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::Char('$'),
+            Token::make_word("x", None),
+        ];
+        compare(expected, tokens);
+
+        let mut tokenizer = Tokenizer::new(&dialect, "SELECT $tag$abc$$");
+        assert_eq!(
+            Err(TokenizerError {
+                message: "Unterminated dollar-quoted string".to_string(),
+                line: 1,
+                col: 8,
+            }),
+            tokenizer.tokenize()
+        );
+    }
+
+    #[test]
+    fn tokenize_bit_string_and_assignment() {
+        let sql = "SELECT b'0101', @a := bar";
+        let dialect = MySqlDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let (tokens, _) = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::BitStringLiteral("0101".to_string()),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("@a", None),
+            Token::Whitespace(Whitespace::Space),
+            Token::Assignment,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("bar", None),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_bit_string_prefix_as_word() {
+        // dialects without bit strings keep `b'abc'` as an identifier and a string
+        let sql = "SELECT b'abc'";
+        let dialect = SnowflakeDialect {};
+        let mut tokenizer = Tokenizer::new(&dialect, sql);
+        let (tokens, _) = tokenizer.tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("b", None),
+            Token::SingleQuotedString("abc".to_string()),
+        ];
+        compare(expected, tokens);
+    }
+
     fn compare(expected: Vec<Token>, actual: Vec<Token>) {
         //println!("------------------------------");
         //println!("tokens   = {:?}", actual);
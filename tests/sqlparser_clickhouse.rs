@@ -0,0 +1,193 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![warn(clippy::all)]
+//! Test SQL syntax specific to ClickHouse. The parser based on the
+//! generic dialect is also tested (on the inputs it can handle).
+
+#[macro_use]
+mod test_utils;
+use test_utils::*;
+
+use sqlparser::ast::*;
+use sqlparser::dialect::{ClickHouseDialect, GenericDialect};
+use sqlparser::parser::ParserError;
+
+#[test]
+fn parse_wrapper_types() {
+    let sql = "CREATE TABLE t (\
+               a Nullable(String), \
+               b LowCardinality(Nullable(String)), \
+               c ARRAY(Nullable(Int32)), \
+               d Nested(id UInt8, name String))";
+    let data_types: Vec<DataType> = match clickhouse_and_generic().one_statement_parses_to(
+        sql,
+        "CREATE TABLE t (\
+         a Nullable(STRING), \
+         b LowCardinality(Nullable(STRING)), \
+         c ARRAY(Nullable(Int32)), \
+         d Nested(id UInt8, name STRING))",
+    ) {
+        Statement::CreateTable { columns, .. } => {
+            columns.into_iter().map(|c| c.data_type).collect()
+        }
+        _ => unreachable!(),
+    };
+    let custom = |name: &str| DataType::Custom(ObjectName(vec![Ident::new(name)]), vec![]);
+    assert_eq!(
+        vec![
+            DataType::Nullable(Box::new(DataType::String)),
+            DataType::LowCardinality(Box::new(DataType::Nullable(Box::new(DataType::String)))),
+            DataType::Array(
+                Box::new(DataType::Nullable(Box::new(custom("Int32")))),
+                false
+            ),
+            DataType::Nested(vec![
+                StructField {
+                    field_name: Some(Ident::new("id")),
+                    field_type: custom("UInt8"),
+                },
+                StructField {
+                    field_name: Some(Ident::new("name")),
+                    field_type: DataType::String,
+                },
+            ]),
+        ],
+        data_types
+    );
+}
+
+#[test]
+fn parse_parameterized_types() {
+    let sql = "CREATE TABLE t (\
+               a Decimal32(2), \
+               b Decimal64(4), \
+               c Decimal128(9), \
+               d Decimal256(18), \
+               e DateTime64(3), \
+               f DateTime64(3, 'UTC'), \
+               g FixedString(16), \
+               h Enum8('a' = 1, 'b' = -2), \
+               i Enum16('x' = 1000), \
+               j ENUM('a' = 1, 'b' = 2))";
+    let data_types: Vec<DataType> = match clickhouse_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            columns.into_iter().map(|c| c.data_type).collect()
+        }
+        _ => unreachable!(),
+    };
+    let member = |name: &str, value: i64| EnumMember {
+        name: name.to_string(),
+        value: Some(value),
+    };
+    assert_eq!(
+        vec![
+            DataType::Decimal32(2),
+            DataType::Decimal64(4),
+            DataType::Decimal128(9),
+            DataType::Decimal256(18),
+            DataType::DateTime64(3, None),
+            DataType::DateTime64(3, Some("UTC".to_string())),
+            DataType::FixedString(16),
+            DataType::Enum8(vec![member("a", 1), member("b", -2)]),
+            DataType::Enum16(vec![member("x", 1000)]),
+            DataType::Enum(vec![member("a", 1), member("b", 2)]),
+        ],
+        data_types
+    );
+
+    clickhouse().verified_stmt("SELECT CAST(x AS Nullable(Decimal128(9))) FROM t");
+    // a quoted name is never a ClickHouse wrapper type
+    match clickhouse().verified_stmt("CREATE TABLE t (a \"Nullable\"(Int32))") {
+        Statement::CreateTable { columns, .. } => assert_eq!(
+            DataType::Custom(
+                ObjectName(vec![Ident::with_quote('"', "Nullable")]),
+                vec!["Int32".to_string()]
+            ),
+            columns[0].data_type
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = clickhouse().parse_sql_statements("CREATE TABLE t (a FixedString(n))");
+    assert_eq!(
+        ParserError::ParserError("Expected literal int, found: n".to_string()),
+        res.unwrap_err()
+    );
+
+    clickhouse().verified_stmt(
+        "CREATE TABLE t (a Enum8('min' = -9223372036854775808, 'max' = 9223372036854775807))",
+    );
+    for value in ["18446744073709551615", "-9223372036854775809"] {
+        let sql = format!("CREATE TABLE t (a Enum8('a' = {}))", value);
+        assert_eq!(
+            ParserError::ParserError(format!("Enum value {} is out of range", value)),
+            clickhouse().parse_sql_statements(&sql).unwrap_err()
+        );
+    }
+}
+
+#[test]
+fn parse_codec_and_ttl_column_options() {
+    let sql = "CREATE TABLE t (\
+               d DATETIME CODEC(Delta, ZSTD(3)) TTL d + INTERVAL 1 DAY COMMENT 'expires')";
+    match clickhouse().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            let options: Vec<ColumnOption> = columns[0]
+                .options
+                .iter()
+                .map(|o| o.option.clone())
+                .collect();
+            assert!(matches!(&options[0], ColumnOption::Codec(codecs) if codecs.len() == 2));
+            assert!(matches!(
+                &options[1],
+                ColumnOption::Ttl(Expr::BinaryOp {
+                    op: BinaryOperator::Plus,
+                    ..
+                })
+            ));
+            assert_eq!(ColumnOption::Comment("expires".to_string()), options[2]);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_array_map_lambda() {
+    let select = clickhouse().verified_only_select("SELECT arrayMap(x -> x * 2, arr)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Lambda {
+                params: vec![Ident::new("x")],
+                body: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("x"))),
+                    op: BinaryOperator::Multiply,
+                    right: Box::new(Expr::Value(number("2"))),
+                }),
+            })),
+            args[0]
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn clickhouse() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {})],
+    }
+}
+
+fn clickhouse_and_generic() -> TestedDialects {
+    TestedDialects {
+        dialects: vec![Box::new(ClickHouseDialect {}), Box::new(GenericDialect {})],
+    }
+}
@@ -26,12 +26,16 @@ mod test_utils;
 use matches::assert_matches;
 use sqlparser::ast::Expr::BinaryOp;
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, PostgreSqlDialect, SQLiteDialect};
+use sqlparser::dialect::{
+    ClickHouseDialect, GenericDialect, HiveDialect, PostgreSqlDialect, SQLiteDialect,
+    SnowflakeDialect,
+};
 use sqlparser::keywords::ALL_KEYWORDS;
 use sqlparser::parser::{Parser, ParserError};
 use sqlparser::tokenizer::QueryOffset;
 use test_utils::{
-    all_dialects, expr_from_projection, join, number, only, table, table_alias, TestedDialects,
+    all_dialects, alter_table_op, alter_table_op_with_name, expr_from_projection, join, number,
+    only, table, table_alias, TestedDialects,
 };
 
 #[test]
@@ -378,6 +382,7 @@ fn parse_update_with_table_alias() {
                         args: vec![],
                         with_hints: vec![],
                         instant: None,
+                        sample: None,
                     },
                     joins: vec![]
                 },
@@ -537,6 +542,39 @@ fn parse_select_at() {
     }
 }
 
+#[test]
+fn parse_select_time_travel() {
+    let select =
+        verified_only_select("SELECT * FROM t FOR SYSTEM_TIME AS OF '2021-01-01 00:00:00' AS a");
+    match only(select.from).relation {
+        TableFactor::Table { instant, alias, .. } => {
+            assert_eq!(
+                instant,
+                Some(Instant::SystemTimeAsOf(Box::new(Expr::Value(
+                    Value::SingleQuotedString("2021-01-01 00:00:00".to_string())
+                ))))
+            );
+            assert_eq!(alias, table_alias("a"));
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    let select = verified_only_select("SELECT * FROM t VERSION AS OF 3");
+    match only(select.from).relation {
+        TableFactor::Table { instant, .. } => assert_eq!(
+            instant,
+            Some(Instant::VersionAsOf(Box::new(Expr::Value(number("3")))))
+        ),
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    verified_only_select(
+        "SELECT * FROM t TIMESTAMP AS OF CURRENT_TIMESTAMP() JOIN u ON t.id = u.id",
+    );
+    // without `AS OF` these remain table aliases
+    one_statement_parses_to("SELECT * FROM t version", "SELECT * FROM t AS version");
+}
+
 #[test]
 fn parse_select_wildcard() {
     let sql = "SELECT * FROM foo";
@@ -611,6 +649,22 @@ fn parse_column_aliases() {
     one_statement_parses_to("SELECT a.col + 1 newname FROM foo AS a", sql);
 }
 
+#[test]
+fn parse_underscore_column_with_quoted_alias() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {}), Box::new(PostgreSqlDialect {})],
+    };
+    let select = dialects.verified_only_select("SELECT _col AS 'alias'");
+    assert_eq!(
+        &SelectItem::ExprWithAlias {
+            expr: Expr::Identifier(Ident::new("_col")),
+            alias: Ident::with_quote('\'', "alias"),
+        },
+        only(&select.projection)
+    );
+    dialects.one_statement_parses_to("SELECT _col 'alias'", "SELECT _col AS 'alias'");
+}
+
 #[test]
 fn parse_column_aliases_with_back_quoted() {
     let sql = "SELECT a.col + 1 AS `newname` FROM foo AS a";
@@ -827,9 +881,14 @@ fn parse_select_where_map_access() {
             }),
             op: BinaryOperator::And,
             right: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::MapAccess {
-                    column: Box::new(Expr::Identifier(Ident::new("labels"))),
-                    keys: vec![Value::ColonString("email".into())],
+                left: Box::new(Expr::JsonAccess {
+                    value: Box::new(Expr::Identifier(Ident::new("labels"))),
+                    path: JsonPath {
+                        path: vec![JsonPathElem::Dot {
+                            key: "email".to_string(),
+                            quoted: false
+                        }]
+                    },
                 }),
                 op: BinaryOperator::Eq,
                 right: Box::new(Expr::Value(Value::SingleQuotedString(
@@ -854,9 +913,14 @@ fn parse_select_where_map_access() {
                     "abc@test.com".to_string()
                 ))),
                 op: BinaryOperator::Eq,
-                right: Box::new(Expr::MapAccess {
-                    column: Box::new(Expr::Identifier(Ident::new("labels"))),
-                    keys: vec![Value::ColonString("email".into())],
+                right: Box::new(Expr::JsonAccess {
+                    value: Box::new(Expr::Identifier(Ident::new("labels"))),
+                    path: JsonPath {
+                        path: vec![JsonPathElem::Dot {
+                            key: "email".to_string(),
+                            quoted: false
+                        }]
+                    },
                 })
             })
         }),
@@ -975,6 +1039,70 @@ fn parse_is_not_null() {
     );
 }
 
+#[test]
+fn parse_is_boolean() {
+    use self::Expr::*;
+    let a = || Box::new(Identifier(Ident::new("a")));
+    assert_eq!(IsTrue(a()), verified_expr("a IS TRUE"));
+    assert_eq!(IsNotTrue(a()), verified_expr("a IS NOT TRUE"));
+    assert_eq!(IsFalse(a()), verified_expr("a IS FALSE"));
+    assert_eq!(IsNotFalse(a()), verified_expr("a IS NOT FALSE"));
+    assert_eq!(IsUnknown(a()), verified_expr("a IS UNKNOWN"));
+    assert_eq!(IsNotUnknown(a()), verified_expr("a IS NOT UNKNOWN"));
+
+    // IS binds tighter than AND and NOT but looser than comparisons
+    let sql = "SELECT * FROM t WHERE NOT a = 1 IS NOT TRUE AND b";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        BinaryOp {
+            left: Box::new(UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(IsNotTrue(Box::new(BinaryOp {
+                    left: a(),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Value(number("1"))),
+                }))),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Identifier(Ident::new("b"))),
+        },
+        select.selection.unwrap()
+    );
+
+    let res = parse_sql_statements("SELECT a IS NOT 1");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected [NOT] NULL, TRUE, FALSE, UNKNOWN, NORMALIZED or [NOT] DISTINCT FROM after IS, found: NOT"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_is_normalized() {
+    use self::Expr::*;
+    let a = || Box::new(Identifier(Ident::new("a")));
+    assert_eq!(
+        IsNormalized {
+            expr: a(),
+            form: None,
+            negated: false,
+        },
+        verified_expr("a IS NORMALIZED")
+    );
+    assert_eq!(
+        IsNormalized {
+            expr: a(),
+            form: Some(NormalizationForm::NFKC),
+            negated: true,
+        },
+        verified_expr("a IS NOT NFKC NORMALIZED")
+    );
+    verified_expr("a IS NFC NORMALIZED");
+    verified_expr("a IS NOT NORMALIZED");
+}
+
 #[test]
 fn parse_is_distinct_from() {
     use self::Expr::*;
@@ -1043,10 +1171,12 @@ fn parse_not_precedence() {
         verified_expr(sql),
         Expr::UnaryOp {
             op: UnaryOperator::Not,
-            expr: Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
-                op: BinaryOperator::NotLike,
-                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            expr: Box::new(Expr::Like {
+                negated: true,
+                case_insensitive: false,
+                expr: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+                escape_char: None,
             }),
         },
     );
@@ -1075,14 +1205,12 @@ fn parse_like() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::Like {
+                negated,
+                case_insensitive: false,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             },
             select.selection.unwrap()
         );
@@ -1095,14 +1223,12 @@ fn parse_like() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::IsNull(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotLike
-                } else {
-                    BinaryOperator::Like
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::IsNull(Box::new(Expr::Like {
+                negated,
+                case_insensitive: false,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             })),
             select.selection.unwrap()
         );
@@ -1120,14 +1246,12 @@ fn parse_ilike() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotILike
-                } else {
-                    BinaryOperator::ILike
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::Like {
+                negated,
+                case_insensitive: true,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             },
             select.selection.unwrap()
         );
@@ -1140,14 +1264,12 @@ fn parse_ilike() {
         );
         let select = verified_only_select(sql);
         assert_eq!(
-            Expr::IsNull(Box::new(Expr::BinaryOp {
-                left: Box::new(Expr::Identifier(Ident::new("name"))),
-                op: if negated {
-                    BinaryOperator::NotILike
-                } else {
-                    BinaryOperator::ILike
-                },
-                right: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+            Expr::IsNull(Box::new(Expr::Like {
+                negated,
+                case_insensitive: true,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%a".to_string()))),
+                escape_char: None,
             })),
             select.selection.unwrap()
         );
@@ -1156,6 +1278,116 @@ fn parse_ilike() {
     chk(true);
 }
 
+#[test]
+fn parse_like_escape() {
+    let sql = "SELECT * FROM customers WHERE name NOT LIKE 'a!_%' ESCAPE '!'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::Like {
+            negated: true,
+            case_insensitive: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::SingleQuotedString("a!_%".to_string()))),
+            escape_char: Some("!".to_string()),
+        },
+        select.selection.unwrap()
+    );
+
+    verified_only_select("SELECT * FROM customers WHERE name LIKE 'a''_%' ESCAPE '''' IS NULL");
+
+    // without backslash escapes in strings `'\'` is a backslash, printed in a
+    // form that also reads back in dialects with backslash escapes
+    let standard = TestedDialects {
+        dialects: vec![
+            Box::new(GenericDialect {}),
+            Box::new(PostgreSqlDialect {}),
+            Box::new(SQLiteDialect {}),
+        ],
+    };
+    match standard.one_statement_parses_to(
+        r"SELECT * FROM customers WHERE name LIKE 'a\_%' ESCAPE '\'",
+        r"SELECT * FROM customers WHERE name LIKE 'a\_%' ESCAPE '\\'",
+    ) {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => assert_eq!(
+                Some(Expr::Like {
+                    negated: false,
+                    case_insensitive: false,
+                    expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                    pattern: Box::new(Expr::Value(Value::SingleQuotedString(r"a\_%".to_string()))),
+                    escape_char: Some("\\".to_string()),
+                }),
+                select.selection
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    // an empty escape string disables escaping
+    let select = standard.verified_only_select("SELECT * FROM t WHERE a LIKE 'a\\%' ESCAPE ''");
+    assert_matches!(
+        select.selection,
+        Some(Expr::Like { escape_char: Some(escape_char), .. }) if escape_char.is_empty()
+    );
+
+    let res = parse_sql_statements("SELECT * FROM customers WHERE name LIKE 'a' ESCAPE 'ab'");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a single character escape string, found: 'ab'".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_similar_to() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%(b|d)%' ESCAPE '#'",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::SimilarTo {
+                negated,
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString(
+                    "%(b|d)%".to_string()
+                ))),
+                escape_char: Some("#".to_string()),
+            },
+            select.selection.unwrap()
+        );
+
+        // SIMILAR TO binds like the other comparison operators
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO 'a' || b AND c",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::BinaryOp {
+                left: Box::new(Expr::SimilarTo {
+                    negated,
+                    expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                    pattern: Box::new(Expr::BinaryOp {
+                        left: Box::new(Expr::Value(Value::SingleQuotedString("a".to_string()))),
+                        op: BinaryOperator::StringConcat,
+                        right: Box::new(Expr::Identifier(Ident::new("b"))),
+                    }),
+                    escape_char: None,
+                }),
+                op: BinaryOperator::And,
+                right: Box::new(Expr::Identifier(Ident::new("c"))),
+            },
+            select.selection.unwrap()
+        );
+    }
+    chk(false);
+    chk(true);
+}
+
 #[test]
 fn parse_regexp() {
     fn chk(name: &str, negated: bool) {
@@ -1258,6 +1490,72 @@ fn parse_in_list() {
     chk(true);
 }
 
+#[test]
+fn parse_any_all_some() {
+    let sql = "SELECT * FROM t WHERE a = ANY(SELECT b FROM u)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::AnyOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            compare_op: BinaryOperator::Eq,
+            right: Box::new(Expr::Subquery(Box::new(verified_query("SELECT b FROM u")))),
+            is_some: false,
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM t WHERE a > ALL(b)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::AllOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            compare_op: BinaryOperator::Gt,
+            right: Box::new(Expr::Identifier(Ident::new("b"))),
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM t WHERE a <> SOME(SELECT b FROM u) AND c = 1";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::AnyOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                compare_op: BinaryOperator::NotEq,
+                right: Box::new(Expr::Subquery(Box::new(verified_query("SELECT b FROM u")))),
+                is_some: true,
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("c"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        },
+        select.selection.unwrap()
+    );
+
+    for op in ["=", "<>", "<", "<=", ">", ">="] {
+        for quantifier in ["ANY", "SOME", "ALL"] {
+            verified_only_select(&format!(
+                "SELECT * FROM t WHERE a {} {}(WITH x AS (SELECT 1) SELECT * FROM x)",
+                op, quantifier
+            ));
+        }
+    }
+
+    one_statement_parses_to(
+        "SELECT * FROM t WHERE a != ANY (SELECT b FROM u)",
+        "SELECT * FROM t WHERE a <> ANY(SELECT b FROM u)",
+    );
+
+    let res = parse_sql_statements("SELECT * FROM t WHERE a = ALL(SELECT b FROM u");
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_in_subquery() {
     let sql = "SELECT * FROM customers WHERE segment IN (SELECT segm FROM bar)";
@@ -1606,6 +1904,7 @@ fn parse_cast() {
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
             data_type: DataType::BigInt(None),
             pg_style: false,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1617,6 +1916,7 @@ fn parse_cast() {
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
             data_type: DataType::TinyInt(None),
             pg_style: false,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1656,7 +1956,8 @@ fn parse_try_cast() {
     assert_eq!(
         &Expr::TryCast {
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
-            data_type: DataType::BigInt(None)
+            data_type: DataType::BigInt(None),
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -1678,6 +1979,42 @@ fn parse_try_cast() {
     );
 }
 
+#[test]
+fn parse_cast_format() {
+    let sql = "SELECT CAST(d AS DATE FORMAT 'YYYY-MM-DD') FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            data_type: DataType::Date,
+            pg_style: false,
+            format: Some(Box::new(CastFormat::Value(Value::SingleQuotedString(
+                "YYYY-MM-DD".to_string()
+            )))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    verified_stmt("SELECT TRY_CAST(s AS TIMESTAMP FORMAT 'YYYY-MM-DD HH24:MI') FROM t");
+    verified_stmt(
+        "SELECT CAST(ts AS STRING FORMAT 'YYYY-MM-DD HH24:MI' AT TIME ZONE 'Asia/Tokyo') FROM t",
+    );
+}
+
+#[test]
+fn parse_safe_cast() {
+    let sql = "SELECT SAFE_CAST(id AS BIGINT) FROM customer";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::SafeCast {
+            expr: Box::new(Expr::Identifier(Ident::new("id"))),
+            data_type: DataType::BigInt(None),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    verified_stmt("SELECT SAFE_CAST(s AS DATE FORMAT 'DD/MM/YYYY') FROM t");
+}
+
 #[test]
 fn parse_datatypes() {
     {
@@ -1686,7 +2023,8 @@ fn parse_datatypes() {
         assert_eq!(
             &Expr::TryCast {
                 expr: Box::new(Expr::Identifier(Ident::new("id"))),
-                data_type: DataType::DateTime(Some(3))
+                data_type: DataType::DateTime(Some(3)),
+                format: None,
             },
             expr_from_projection(only(&select.projection))
         );
@@ -1701,7 +2039,8 @@ fn parse_datatypes() {
         assert_eq!(
             &Expr::TryCast {
                 expr: Box::new(Expr::Identifier(Ident::new("id"))),
-                data_type: DataType::Timestamp(Some(3))
+                data_type: DataType::Timestamp(Some(3), TimezoneInfo::None),
+                format: None,
             },
             expr_from_projection(only(&select.projection))
         );
@@ -1712,6 +2051,97 @@ fn parse_datatypes() {
     }
 }
 
+#[test]
+fn parse_extended_data_types() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    let sql = "CREATE TABLE t (\
+        a JSON, b JSONB, c ENUM('x', 'y''s'), d SET('r', 'w'), e MAP(STRING, INT), \
+        f STRUCT<a INT, b STRUCT<c STRING>>, g NVARCHAR(MAX), h NVARCHAR(20), \
+        i CHARACTER LARGE OBJECT(1000), j BIT(8), k BIT VARYING(16), l DOUBLE PRECISION, \
+        m MEDIUMINT(8) UNSIGNED, n LONGTEXT, o GEOMETRY, p INT64, q FLOAT64, r VARIANT, \
+        s OBJECT, u NUMBER(38,0), v INET, w MONEY, x INT[][])";
+    let data_types: Vec<DataType> = match dialects.verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            columns.into_iter().map(|c| c.data_type).collect()
+        }
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        vec![
+            DataType::Json,
+            DataType::Jsonb,
+            DataType::Enum(vec![
+                EnumMember {
+                    name: "x".to_string(),
+                    value: None,
+                },
+                EnumMember {
+                    name: "y's".to_string(),
+                    value: None,
+                },
+            ]),
+            DataType::Set(vec!["r".to_string(), "w".to_string()]),
+            DataType::Map(Box::new(DataType::String), Box::new(DataType::Int(None))),
+            DataType::Struct(vec![
+                StructField {
+                    field_name: Some(Ident::new("a")),
+                    field_type: DataType::Int(None),
+                },
+                StructField {
+                    field_name: Some(Ident::new("b")),
+                    field_type: DataType::Struct(vec![StructField {
+                        field_name: Some(Ident::new("c")),
+                        field_type: DataType::String,
+                    }]),
+                },
+            ]),
+            DataType::Nvarchar(Some(CharacterLength::Max)),
+            DataType::Nvarchar(Some(CharacterLength::Length(20))),
+            DataType::CharacterLargeObject(Some(1000)),
+            DataType::Bit(Some(8)),
+            DataType::BitVarying(Some(16)),
+            DataType::DoublePrecision,
+            DataType::UnsignedMediumInt(Some(8)),
+            DataType::LongText,
+            DataType::Geometry,
+            DataType::Int64,
+            DataType::Float64,
+            DataType::Variant,
+            DataType::Object,
+            DataType::Number(Some(38), Some(0)),
+            DataType::Inet,
+            DataType::Money,
+            DataType::BracketArray(
+                Box::new(DataType::BracketArray(Box::new(DataType::Int(None)), None)),
+                None,
+            ),
+        ],
+        data_types
+    );
+
+    match dialects.verified_stmt("CREATE TABLE t (a NUMBER(*,2), b NUMBER(10))") {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(DataType::Number(None, Some(2)), columns[0].data_type);
+            assert_eq!(DataType::Number(Some(10), None), columns[1].data_type);
+        }
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to(
+        "SELECT CAST(a AS VARCHAR(MAX)) FROM t",
+        "SELECT CAST(a AS CHARACTER VARYING(MAX)) FROM t",
+    );
+    dialects.verified_stmt("SELECT CAST(a AS STRUCT<x STRUCT<y ARRAY(INT)>, z INT>) FROM t");
+
+    let res = dialects.parse_sql_statements("SELECT CAST(a AS STRUCT<x INT>>) FROM t");
+    assert_eq!(
+        ParserError::ParserError("unmatched > after parsing data type STRUCT<x INT>".to_string()),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_extract() {
     let sql = "SELECT EXTRACT(YEAR FROM d)";
@@ -1732,13 +2162,67 @@ fn parse_extract() {
     verified_stmt("SELECT EXTRACT(MINUTE FROM d)");
     verified_stmt("SELECT EXTRACT(SECOND FROM d)");
 
-    let res = parse_sql_statements("SELECT EXTRACT(MILLISECOND FROM d)");
+    for field in [
+        "WEEK",
+        "CENTURY",
+        "DECADE",
+        "DOW",
+        "DOY",
+        "EPOCH",
+        "ISODOW",
+        "ISOYEAR",
+        "JULIAN",
+        "MICROSECOND",
+        "MILLENNIUM",
+        "MILLISECOND",
+        "NANOSECOND",
+        "QUARTER",
+        "TIMEZONE",
+        "TIMEZONE_HOUR",
+        "TIMEZONE_MINUTE",
+    ] {
+        verified_stmt(&format!("SELECT EXTRACT({} FROM d)", field));
+    }
+
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(PostgreSqlDialect {}), Box::new(HiveDialect {})],
+    };
+    let res = dialects.parse_sql_statements("SELECT EXTRACT(JIFFY FROM d)");
     assert_eq!(
-        ParserError::ParserError("Expected date/time field, found: MILLISECOND".to_string()),
+        ParserError::ParserError("Expected date/time field, found: JIFFY".to_string()),
         res.unwrap_err()
     );
 }
 
+#[test]
+fn parse_ceil_floor_to_field() {
+    let select = verified_only_select("SELECT CEIL(ts TO DAY), FLOOR(ts TO HOUR)");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Ceil {
+                expr: Box::new(Expr::Identifier(Ident::new("ts"))),
+                field: DateTimeField::Day,
+            }),
+            SelectItem::UnnamedExpr(Expr::Floor {
+                expr: Box::new(Expr::Identifier(Ident::new("ts"))),
+                field: DateTimeField::Hour,
+            }),
+        ],
+        select.projection
+    );
+
+    // without TO they remain ordinary function calls
+    let select = verified_only_select("SELECT CEIL(1.5), FLOOR(x, 2)");
+    assert!(matches!(
+        select.projection[0],
+        SelectItem::UnnamedExpr(Expr::Function(_))
+    ));
+    assert!(matches!(
+        select.projection[1],
+        SelectItem::UnnamedExpr(Expr::Function(_))
+    ));
+}
+
 #[test]
 fn parse_listagg() {
     let sql = "SELECT LISTAGG(DISTINCT dateid, ', ' ON OVERFLOW TRUNCATE '%' WITHOUT COUNT) \
@@ -1837,7 +2321,7 @@ fn parse_create_table() {
                 vec![
                     ColumnDef {
                         name: "name".into(),
-                        data_type: DataType::Varchar(Some(100)),
+                        data_type: DataType::Varchar(Some(CharacterLength::Length(100))),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -2263,7 +2747,7 @@ fn parse_create_external_table() {
                 vec![
                     ColumnDef {
                         name: "name".into(),
-                        data_type: DataType::Varchar(Some(100)),
+                        data_type: DataType::Varchar(Some(CharacterLength::Length(100))),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -2331,7 +2815,7 @@ fn parse_create_or_replace_external_table() {
                 columns,
                 vec![ColumnDef {
                     name: "name".into(),
-                    data_type: DataType::Varchar(Some(100)),
+                    data_type: DataType::Varchar(Some(CharacterLength::Length(100))),
                     collation: None,
                     options: vec![ColumnOptionDef {
                         name: None,
@@ -2374,12 +2858,15 @@ fn parse_create_external_table_lowercase() {
 #[test]
 fn parse_alter_table() {
     let add_column = "ALTER TABLE tab ADD COLUMN foo TEXT;";
-    match one_statement_parses_to(add_column, "ALTER TABLE tab ADD COLUMN foo TEXT") {
-        Statement::AlterTable {
-            name,
-            operation: AlterTableOperation::AddColumn { column_def },
+    match alter_table_op_with_name(
+        one_statement_parses_to(add_column, "ALTER TABLE tab ADD COLUMN foo TEXT"),
+        "tab",
+    ) {
+        AlterTableOperation::AddColumn {
+            column_def,
+            position,
         } => {
-            assert_eq!("tab", name.to_string());
+            assert_eq!(None, position);
             assert_eq!("foo", column_def.name.to_string());
             assert_eq!("TEXT", column_def.data_type.to_string());
         }
@@ -2387,28 +2874,19 @@ fn parse_alter_table() {
     };
 
     let rename_table = "ALTER TABLE tab RENAME TO new_tab";
-    match verified_stmt(rename_table) {
-        Statement::AlterTable {
-            name,
-            operation: AlterTableOperation::RenameTable { table_name },
-        } => {
-            assert_eq!("tab", name.to_string());
+    match alter_table_op_with_name(verified_stmt(rename_table), "tab") {
+        AlterTableOperation::RenameTable { table_name } => {
             assert_eq!("new_tab", table_name.to_string())
         }
         _ => unreachable!(),
     };
 
     let rename_column = "ALTER TABLE tab RENAME COLUMN foo TO new_foo";
-    match verified_stmt(rename_column) {
-        Statement::AlterTable {
-            name,
-            operation:
-                AlterTableOperation::RenameColumn {
-                    old_column_name,
-                    new_column_name,
-                },
+    match alter_table_op_with_name(verified_stmt(rename_column), "tab") {
+        AlterTableOperation::RenameColumn {
+            old_column_name,
+            new_column_name,
         } => {
-            assert_eq!("tab", name.to_string());
             assert_eq!(old_column_name.to_string(), "foo");
             assert_eq!(new_column_name.to_string(), "new_foo");
         }
@@ -2432,12 +2910,11 @@ fn parse_alter_table_constraints() {
     check_one("CHECK (end_date > start_date OR end_date IS NULL)");
 
     fn check_one(constraint_text: &str) {
-        match verified_stmt(&format!("ALTER TABLE tab ADD {}", constraint_text)) {
-            Statement::AlterTable {
-                name,
-                operation: AlterTableOperation::AddConstraint(constraint),
-            } => {
-                assert_eq!("tab", name.to_string());
+        match alter_table_op_with_name(
+            verified_stmt(&format!("ALTER TABLE tab ADD {}", constraint_text)),
+            "tab",
+        ) {
+            AlterTableOperation::AddConstraint(constraint) => {
                 assert_eq!(constraint_text, constraint.to_string());
             }
             _ => unreachable!(),
@@ -2459,17 +2936,15 @@ fn parse_alter_table_drop_column() {
     );
 
     fn check_one(constraint_text: &str) {
-        match verified_stmt(&format!("ALTER TABLE tab {}", constraint_text)) {
-            Statement::AlterTable {
-                name,
-                operation:
-                    AlterTableOperation::DropColumn {
-                        column_name,
-                        if_exists,
-                        cascade,
-                    },
+        match alter_table_op_with_name(
+            verified_stmt(&format!("ALTER TABLE tab {}", constraint_text)),
+            "tab",
+        ) {
+            AlterTableOperation::DropColumn {
+                column_name,
+                if_exists,
+                cascade,
             } => {
-                assert_eq!("tab", name.to_string());
                 assert_eq!("is_active", column_name.to_string());
                 assert!(if_exists);
                 assert!(cascade);
@@ -2479,6 +2954,100 @@ fn parse_alter_table_drop_column() {
     }
 }
 
+#[test]
+fn parse_alter_table_alter_column() {
+    let alter_stmt = "ALTER TABLE tab";
+    match alter_table_op(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active SET NOT NULL",
+        alter_stmt
+    ))) {
+        AlterTableOperation::AlterColumn { column_name, op } => {
+            assert_eq!("is_active", column_name.to_string());
+            assert_eq!(AlterColumnOperation::SetNotNull, op);
+        }
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to(
+        "ALTER TABLE tab ALTER is_active DROP NOT NULL",
+        "ALTER TABLE tab ALTER COLUMN is_active DROP NOT NULL",
+    );
+
+    match alter_table_op(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active SET DEFAULT false",
+        alter_stmt
+    ))) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::SetDefault {
+                value: Expr::Value(Value::Boolean(false))
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active DROP DEFAULT",
+        alter_stmt
+    ))) {
+        AlterTableOperation::AlterColumn { op, .. } => {
+            assert_eq!(AlterColumnOperation::DropDefault, op)
+        }
+        _ => unreachable!(),
+    }
+
+    match alter_table_op(verified_stmt(&format!(
+        "{} ALTER COLUMN is_active SET DATA TYPE TEXT",
+        alter_stmt
+    ))) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::SetDataType {
+                data_type: DataType::Text,
+                using: None,
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements(&format!("{} ALTER COLUMN is_active RENAME", alter_stmt));
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected SET/DROP NOT NULL, SET DEFAULT, DROP DEFAULT, SET DATA TYPE \
+             or ADD GENERATED after ALTER COLUMN, found: RENAME"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_alter_table_multiple_operations() {
+    let sql = "ALTER TABLE tab ADD COLUMN foo TEXT, DROP COLUMN bar, \
+               ALTER COLUMN baz SET NOT NULL, RENAME COLUMN a TO b";
+    match verified_stmt(sql) {
+        Statement::AlterTable { name, operations } => {
+            assert_eq!("tab", name.to_string());
+            assert_eq!(4, operations.len());
+            assert_matches!(operations[0], AlterTableOperation::AddColumn { .. });
+            assert_matches!(operations[1], AlterTableOperation::DropColumn { .. });
+            assert_matches!(operations[2], AlterTableOperation::AlterColumn { .. });
+            assert_matches!(operations[3], AlterTableOperation::RenameColumn { .. });
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("ALTER TABLE tab ADD COLUMN foo TEXT,");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected ADD, RENAME, PARTITION, DROP, CHANGE, MODIFY, ALTER, SET, OWNER TO, \
+             ENABLE, DISABLE, CLUSTER BY or SWAP WITH after ALTER TABLE, found: EOF"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_bad_constraint() {
     let res = parse_sql_statements("ALTER TABLE tab ADD");
@@ -2733,7 +3302,7 @@ fn parse_literal_time() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString {
-            data_type: DataType::Time,
+            data_type: DataType::Time(None, TimezoneInfo::None),
             value: "01:23:34".into()
         },
         expr_from_projection(only(&select.projection)),
@@ -2746,90 +3315,158 @@ fn parse_literal_timestamp() {
     let select = verified_only_select(sql);
     assert_eq!(
         &Expr::TypedString {
-            data_type: DataType::Timestamp(None),
+            data_type: DataType::Timestamp(None, TimezoneInfo::None),
             value: "1999-01-01 01:23:34".into()
         },
         expr_from_projection(only(&select.projection)),
     );
 }
 
+#[test]
+fn parse_literal_timestamp_with_time_zone() {
+    let sql = "SELECT TIMESTAMP WITH TIME ZONE '1999-01-01 01:23:34Z'";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::TypedString {
+            data_type: DataType::Timestamp(None, TimezoneInfo::WithTimeZone),
+            value: "1999-01-01 01:23:34Z".into()
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_only_select("SELECT TIME WITHOUT TIME ZONE '01:23:34'");
+    verified_only_select("SELECT CAST(t AS TIMESTAMP(3) WITHOUT TIME ZONE)");
+    verified_only_select("SELECT CAST(t AS TIME(6) WITH TIME ZONE)");
+}
+
+#[test]
+fn parse_at_timezone() {
+    let sql = "SELECT FROM_UNIXTIME(0) AT TIME ZONE 'UTC-06:00' FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::AtTimeZone {
+            timestamp: Box::new(Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("FROM_UNIXTIME")]),
+                params: vec![],
+                args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                    number("0")
+                )))],
+                over: None,
+                distinct: false,
+            })),
+            time_zone: Box::new(Expr::Value(Value::SingleQuotedString(
+                "UTC-06:00".to_string()
+            ))),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    // AT TIME ZONE binds tighter than comparison and arithmetic operators
+    let sql = "SELECT ts AT TIME ZONE 'UTC' + 1 = x AT TIME ZONE tz FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::AtTimeZone {
+                    timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                    time_zone: Box::new(Expr::Value(Value::SingleQuotedString("UTC".to_string()))),
+                }),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("x"))),
+                time_zone: Box::new(Expr::Identifier(Ident::new("tz"))),
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_only_select("SELECT ts AT TIME ZONE 'UTC' AT TIME ZONE 'Europe/Berlin' FROM t");
+}
+
 #[test]
 fn parse_literal_interval() {
     let sql = "SELECT INTERVAL '1-1' YEAR TO MONTH";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "1-1".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from("1-1")))),
             leading_field: Some(DateTimeField::Year),
             leading_precision: None,
             last_field: Some(DateTimeField::Month),
             fractional_seconds_precision: None,
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '01:01.01' MINUTE (5) TO SECOND (5)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "01:01.01".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from(
+                "01:01.01"
+            )))),
             leading_field: Some(DateTimeField::Minute),
             leading_precision: Some(5),
             last_field: Some(DateTimeField::Second),
             fractional_seconds_precision: Some(5),
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '1' SECOND (5, 4)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "1".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from("1")))),
             leading_field: Some(DateTimeField::Second),
             leading_precision: Some(5),
             last_field: None,
             fractional_seconds_precision: Some(4),
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '10' HOUR";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "10".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from("10")))),
             leading_field: Some(DateTimeField::Hour),
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '10' HOUR (1)";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "10".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from("10")))),
             leading_field: Some(DateTimeField::Hour),
             leading_precision: Some(1),
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
     let sql = "SELECT INTERVAL '1 DAY'";
     let select = verified_only_select(sql);
     assert_eq!(
-        &Expr::Value(Value::Interval {
-            value: "1 DAY".into(),
+        &Expr::Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from(
+                "1 DAY"
+            )))),
             leading_field: None,
             leading_precision: None,
             last_field: None,
             fractional_seconds_precision: None,
-        }),
+        },
         expr_from_projection(only(&select.projection)),
     );
 
@@ -2858,6 +3495,8 @@ fn parse_literal_interval() {
     verified_only_select("SELECT INTERVAL '1' HOUR TO MINUTE");
     verified_only_select("SELECT INTERVAL '1' HOUR TO SECOND");
     verified_only_select("SELECT INTERVAL '1' MINUTE TO SECOND");
+    verified_only_select("SELECT INTERVAL '1' WEEK");
+    verified_only_select("SELECT INTERVAL '1' QUARTER");
     verified_only_select("SELECT INTERVAL '1 YEAR'");
     verified_only_select("SELECT INTERVAL '1 YEAR' AS one_year");
     one_statement_parses_to(
@@ -2912,6 +3551,367 @@ fn parse_table_function() {
     );
 }
 
+#[test]
+fn parse_pivot_table() {
+    let sql = concat!(
+        "SELECT * FROM monthly_sales AS a ",
+        "PIVOT(SUM(a.amount) FOR a.month IN ('JAN', 'FEB', 'MAR')) AS p (c, d) ",
+        "ORDER BY empid"
+    );
+    let select = verified_only_select(sql);
+    assert_eq!(
+        only(select.from).relation,
+        TableFactor::Pivot {
+            table: Box::new(TableFactor::Table {
+                name: ObjectName(vec![Ident::new("monthly_sales")]),
+                alias: table_alias("a"),
+                args: vec![],
+                with_hints: vec![],
+                instant: None,
+                sample: None,
+            }),
+            aggregate_function: Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("SUM")]),
+                params: vec![],
+                args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                    Expr::CompoundIdentifier(vec![Ident::new("a"), Ident::new("amount")])
+                ))],
+                over: None,
+                distinct: false,
+            }),
+            value_column: vec![Ident::new("a"), Ident::new("month")],
+            pivot_values: vec![
+                Expr::Value(Value::SingleQuotedString("JAN".to_string())),
+                Expr::Value(Value::SingleQuotedString("FEB".to_string())),
+                Expr::Value(Value::SingleQuotedString("MAR".to_string())),
+            ],
+            alias: Some(TableAlias {
+                name: Ident::new("p"),
+                columns: vec![
+                    TableAliasColumnDef::from_name("c"),
+                    TableAliasColumnDef::from_name("d"),
+                ],
+            }),
+        }
+    );
+
+    // table operators can be chained and joined like any other table factor
+    verified_only_select(concat!(
+        "SELECT * FROM t PIVOT(SUM(amount) FOR month IN (\"JAN\", \"FEB\")) AS p ",
+        "UNPIVOT(amount FOR month IN (JAN, FEB)) AS u ",
+        "JOIN (SELECT * FROM s) PIVOT(MAX(x) FOR y IN (1, 2)) ON true"
+    ));
+
+    let res = parse_sql_statements("SELECT * FROM t PIVOT(SUM(a) FOR b (1, 2))");
+    assert_eq!(
+        ParserError::ParserError("Expected IN, found: (".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_unpivot_table() {
+    let sql = "SELECT * FROM sales AS s UNPIVOT(quantity FOR quarter IN (q1, q2, q3)) AS u";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        only(select.from).relation,
+        TableFactor::Unpivot {
+            table: Box::new(TableFactor::Table {
+                name: ObjectName(vec![Ident::new("sales")]),
+                alias: table_alias("s"),
+                args: vec![],
+                with_hints: vec![],
+                instant: None,
+                sample: None,
+            }),
+            value: Ident::new("quantity"),
+            name: Ident::new("quarter"),
+            columns: vec![Ident::new("q1"), Ident::new("q2"), Ident::new("q3")],
+            alias: table_alias("u"),
+        }
+    );
+
+    let res = parse_sql_statements("SELECT * FROM t UNPIVOT(a FOR b IN c)");
+    assert_eq!(
+        ParserError::ParserError("Expected a list of columns in parentheses, found: c".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_unnest() {
+    let select = verified_only_select("SELECT * FROM UNNEST(arr) WITH ORDINALITY AS t (x, i)");
+    assert_eq!(
+        only(select.from).relation,
+        TableFactor::UNNEST {
+            array_exprs: vec![Expr::Identifier(Ident::new("arr"))],
+            with_ordinality: true,
+            alias: Some(TableAlias {
+                name: Ident::new("t"),
+                columns: vec![
+                    TableAliasColumnDef::from_name("x"),
+                    TableAliasColumnDef::from_name("i"),
+                ],
+            }),
+            with_offset: false,
+            with_offset_alias: None,
+        }
+    );
+
+    // BigQuery
+    let select = verified_only_select("SELECT * FROM UNNEST(arr) AS x WITH OFFSET AS off");
+    assert_eq!(
+        only(select.from).relation,
+        TableFactor::UNNEST {
+            array_exprs: vec![Expr::Identifier(Ident::new("arr"))],
+            with_ordinality: false,
+            alias: table_alias("x"),
+            with_offset: true,
+            with_offset_alias: Some(Ident::new("off")),
+        }
+    );
+
+    verified_only_select("SELECT * FROM t, UNNEST(t.a, t.b) WITH OFFSET");
+    // a table that happens to be called `unnest`
+    verified_only_select("SELECT * FROM unnest AS u");
+}
+
+#[test]
+fn parse_match_recognize() {
+    let sql = concat!(
+        "SELECT * FROM stock_price_history MATCH_RECOGNIZE(",
+        "PARTITION BY company ORDER BY price_date ",
+        "MEASURES MATCH_NUMBER() AS match_number, FIRST(price_date) AS start_date ",
+        "ONE ROW PER MATCH ",
+        "AFTER MATCH SKIP TO LAST row_with_price_increase ",
+        "PATTERN (row_before_decrease row_with_price_decrease+ row_with_price_increase+) ",
+        "DEFINE row_with_price_decrease AS price < LAG(price), ",
+        "row_with_price_increase AS price > LAG(price)",
+        ") AS mr ORDER BY company"
+    );
+    let select = verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::MatchRecognize {
+            table: inner,
+            partition_by,
+            order_by,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            pattern,
+            symbols,
+            alias,
+        } => {
+            assert_eq!(*inner, table("stock_price_history"));
+            assert_eq!(partition_by, vec![Expr::Identifier(Ident::new("company"))]);
+            assert_eq!(
+                order_by,
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("price_date")),
+                    asc: None,
+                    nulls_first: None,
+                }]
+            );
+            assert_eq!(measures.len(), 2);
+            assert_eq!(measures[0].alias, Ident::new("match_number"));
+            assert_eq!(rows_per_match, Some(RowsPerMatch::OneRow));
+            assert_eq!(
+                after_match_skip,
+                Some(AfterMatchSkip::ToLast(Ident::new(
+                    "row_with_price_increase"
+                )))
+            );
+            let symbol = |name: &str| {
+                MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(Ident::new(name)))
+            };
+            assert_eq!(
+                pattern,
+                MatchRecognizePattern::Concat(vec![
+                    symbol("row_before_decrease"),
+                    MatchRecognizePattern::Repetition {
+                        pattern: Box::new(symbol("row_with_price_decrease")),
+                        quantifier: RepetitionQuantifier::OneOrMore,
+                        reluctant: false,
+                    },
+                    MatchRecognizePattern::Repetition {
+                        pattern: Box::new(symbol("row_with_price_increase")),
+                        quantifier: RepetitionQuantifier::OneOrMore,
+                        reluctant: false,
+                    },
+                ])
+            );
+            assert_eq!(symbols.len(), 2);
+            assert_eq!(symbols[0].symbol, Ident::new("row_with_price_decrease"));
+            assert_eq!(alias, table_alias("mr"));
+        }
+        _ => panic!("Expecting TableFactor::MatchRecognize"),
+    }
+
+    let res = parse_sql_statements(
+        "SELECT * FROM t MATCH_RECOGNIZE(AFTER MATCH SKIP PAST ROW PATTERN (A) DEFINE A AS true)",
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected PAST LAST ROW, TO NEXT ROW, TO FIRST or TO LAST after AFTER MATCH SKIP, found: PAST"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_match_recognize_patterns() {
+    fn check(pattern: &str, expected: MatchRecognizePattern) {
+        let sql = format!(
+            "SELECT * FROM t MATCH_RECOGNIZE(ALL ROWS PER MATCH WITH UNMATCHED ROWS \
+             AFTER MATCH SKIP PAST LAST ROW PATTERN ({}) DEFINE A AS true)",
+            pattern
+        );
+        // Hive allows braces in identifiers, which conflicts with `A{2}`
+        let dialects = TestedDialects {
+            dialects: vec![
+                Box::new(GenericDialect {}),
+                Box::new(PostgreSqlDialect {}),
+                Box::new(SnowflakeDialect {}),
+            ],
+        };
+        match only(dialects.verified_only_select(&sql).from).relation {
+            TableFactor::MatchRecognize { pattern, .. } => assert_eq!(pattern, expected),
+            _ => panic!("Expecting TableFactor::MatchRecognize"),
+        }
+    }
+    use MatchRecognizePattern::*;
+    use MatchRecognizeSymbol::*;
+    use RepetitionQuantifier::*;
+    let a = || Symbol(Named(Ident::new("A")));
+    let b = || Symbol(Named(Ident::new("B")));
+    let repeat = |pattern, quantifier| Repetition {
+        pattern: Box::new(pattern),
+        quantifier,
+        reluctant: false,
+    };
+
+    check("^ A $", Concat(vec![Symbol(Start), a(), Symbol(End)]));
+    check(
+        "A | B C",
+        Alternation(vec![a(), Concat(vec![b(), Symbol(Named(Ident::new("C")))])]),
+    );
+    check(
+        "(A | B)* {- B -}",
+        Concat(vec![
+            repeat(Group(Box::new(Alternation(vec![a(), b()]))), ZeroOrMore),
+            Exclude(Named(Ident::new("B"))),
+        ]),
+    );
+    check(
+        "PERMUTE(A, B)?",
+        repeat(
+            Permute(vec![Named(Ident::new("A")), Named(Ident::new("B"))]),
+            AtMostOne,
+        ),
+    );
+    check("A{2}", repeat(a(), Exactly(2)));
+    check("A{2,}", repeat(a(), AtLeast(2)));
+    check("A{,3}", repeat(a(), AtMost(3)));
+    check("A{2,3}+", repeat(repeat(a(), Range(2, 3)), OneOrMore));
+    check(
+        "A B+?",
+        Concat(vec![
+            a(),
+            Repetition {
+                pattern: Box::new(b()),
+                quantifier: OneOrMore,
+                reluctant: true,
+            },
+        ]),
+    );
+    check(
+        "A{2,}??",
+        repeat(
+            Repetition {
+                pattern: Box::new(a()),
+                quantifier: AtLeast(2),
+                reluctant: true,
+            },
+            AtMostOne,
+        ),
+    );
+}
+
+#[test]
+fn parse_table_sample() {
+    let sql = "SELECT * FROM big_table AS t TABLESAMPLE BERNOULLI (10) REPEATABLE (42)";
+    let select = verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Table { alias, sample, .. } => {
+            assert_eq!(alias, table_alias("t"));
+            assert_eq!(
+                sample,
+                Some(TableSampleKind::AfterTableAlias(TableSample {
+                    modifier: TableSampleModifier::TableSample,
+                    method: Some(TableSampleMethod::Bernoulli),
+                    size: TableSampleSize::Quantity {
+                        value: Expr::Value(number("10")),
+                        unit: None,
+                    },
+                    seed: Some(TableSampleSeed {
+                        modifier: TableSampleSeedModifier::Repeatable,
+                        value: number("42"),
+                    }),
+                }))
+            );
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    let sql = "SELECT * FROM (SELECT * FROM t) AS d TABLESAMPLE SYSTEM (10 PERCENT)";
+    let select = verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Derived { sample, .. } => assert_eq!(
+            sample,
+            Some(TableSampleKind::AfterTableAlias(TableSample {
+                modifier: TableSampleModifier::TableSample,
+                method: Some(TableSampleMethod::System),
+                size: TableSampleSize::Quantity {
+                    value: Expr::Value(number("10")),
+                    unit: Some(TableSampleUnit::Percent),
+                },
+                seed: None,
+            }))
+        ),
+        _ => panic!("Expecting TableFactor::Derived"),
+    }
+
+    verified_only_select(
+        "SELECT * FROM a TABLESAMPLE (0.5) JOIN b TABLESAMPLE (1 + 1) ON a.x = b.x",
+    );
+
+    let res = parse_sql_statements("SELECT * FROM t TABLESAMPLE BERNOULLI 10");
+    assert_eq!(
+        ParserError::ParserError("Expected (, found: 10".to_string()),
+        res.unwrap_err()
+    );
+
+    // the table operator keywords are not reserved, so they remain valid aliases
+    for alias in [
+        "tablesample",
+        "sample",
+        "pivot",
+        "unpivot",
+        "match_recognize",
+    ] {
+        let select = verified_only_select(&format!("SELECT * FROM t AS {}", alias));
+        match only(select.from).relation {
+            TableFactor::Table { alias: actual, .. } => assert_eq!(table_alias(alias), actual),
+            _ => panic!("Expecting TableFactor::Table"),
+        }
+        one_statement_parses_to(
+            &format!("SELECT * FROM t {}", alias),
+            &format!("SELECT * FROM t AS {}", alias),
+        );
+    }
+}
+
 #[test]
 fn parse_delimited_identifiers() {
     // check that quoted identifiers in any position remain quoted after serialization
@@ -2926,12 +3926,14 @@ fn parse_delimited_identifiers() {
             args,
             with_hints,
             instant,
+            sample,
         } => {
             assert_eq!(vec![Ident::with_quote('"', "a table")], name.0);
             assert_eq!(Ident::with_quote('"', "alias"), alias.unwrap().name);
             assert!(args.is_empty());
             assert!(with_hints.is_empty());
             assert!(instant.is_none());
+            assert!(sample.is_none());
         }
         _ => panic!("Expecting TableFactor::Table"),
     }
@@ -2990,6 +3992,270 @@ fn parse_parens() {
     );
 }
 
+#[test]
+fn parse_row_constructor() {
+    let select = verified_only_select("SELECT ROW(1, 'a'), ROW() FROM t");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Row(vec![
+                Expr::Value(number("1")),
+                Expr::Value(Value::SingleQuotedString("a".to_string())),
+            ])),
+            SelectItem::UnnamedExpr(Expr::Row(vec![])),
+        ],
+        select.projection
+    );
+    verified_only_select("SELECT * FROM t WHERE ROW(a, b) = ROW(1, 2)");
+}
+
+#[test]
+fn parse_composite_access() {
+    let select = verified_only_select("SELECT (item).name, (f(x)).a.b FROM t");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::CompositeAccess {
+                expr: Box::new(Expr::Nested(Box::new(Expr::Identifier(Ident::new("item"))))),
+                key: Ident::new("name"),
+            }),
+            SelectItem::UnnamedExpr(Expr::CompositeAccess {
+                expr: Box::new(Expr::CompositeAccess {
+                    expr: Box::new(Expr::Nested(Box::new(Expr::Function(Function {
+                        name: ObjectName(vec![Ident::new("f")]),
+                        params: vec![],
+                        args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                            Expr::Identifier(Ident::new("x"))
+                        ))],
+                        over: None,
+                        distinct: false,
+                    })))),
+                    key: Ident::new("a"),
+                }),
+                key: Ident::new("b"),
+            }),
+        ],
+        select.projection
+    );
+
+    // field access binds tighter than other operators
+    let select = verified_only_select("SELECT (item).price * 2 FROM t");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::CompositeAccess {
+                expr: Box::new(Expr::Nested(Box::new(Expr::Identifier(Ident::new("item"))))),
+                key: Ident::new("price"),
+            }),
+            op: BinaryOperator::Multiply,
+            right: Box::new(Expr::Value(number("2"))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = verified_only_select("SELECT (f(x)).*, (item).* FROM t");
+    assert_eq!(
+        SelectItem::ExprWildcard(Expr::Nested(Box::new(Expr::Identifier(Ident::new("item"))))),
+        select.projection[1]
+    );
+
+    // all fields of a composite value cannot be aliased as one column
+    let res = parse_sql_statements("SELECT (f()).* AS x FROM t");
+    assert_eq!(
+        ParserError::ParserError("Expected end of statement, found: AS".to_string()),
+        res.unwrap_err()
+    );
+
+    let res = parse_sql_statements("SELECT x IN (SELECT 1).y FROM t");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected a composite value before '.', found: x IN (SELECT 1)".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_struct_literal() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    let select = dialects.verified_only_select("SELECT STRUCT(1 AS a, 'x' AS b), STRUCT(c)");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Struct {
+                values: vec![
+                    Expr::Named {
+                        expr: Box::new(Expr::Value(number("1"))),
+                        name: Ident::new("a"),
+                    },
+                    Expr::Named {
+                        expr: Box::new(Expr::Value(Value::SingleQuotedString("x".to_string()))),
+                        name: Ident::new("b"),
+                    },
+                ],
+                fields: vec![],
+            }),
+            SelectItem::UnnamedExpr(Expr::Struct {
+                values: vec![Expr::Identifier(Ident::new("c"))],
+                fields: vec![],
+            }),
+        ],
+        select.projection
+    );
+
+    let select = dialects.verified_only_select("SELECT STRUCT<a INT64, STRING>(1, 'x')");
+    assert_eq!(
+        &Expr::Struct {
+            values: vec![
+                Expr::Value(number("1")),
+                Expr::Value(Value::SingleQuotedString("x".to_string())),
+            ],
+            fields: vec![
+                StructField {
+                    field_name: Some(Ident::new("a")),
+                    field_type: DataType::Int64,
+                },
+                StructField {
+                    field_name: None,
+                    field_type: DataType::String,
+                },
+            ],
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    dialects.verified_only_select("SELECT STRUCT(1 AS a).a");
+    dialects.verified_only_select("SELECT STRUCT<a STRUCT<b INT64>>(STRUCT(1)).a.b");
+}
+
+#[test]
+fn parse_dictionary_and_map_literals() {
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    let select = dialects.verified_only_select("SELECT {'a': 1, 'b': {'c': x + 1}}");
+    assert_eq!(
+        &Expr::Dictionary(vec![
+            DictionaryField {
+                key: Ident::with_quote('\'', "a"),
+                value: Box::new(Expr::Value(number("1"))),
+            },
+            DictionaryField {
+                key: Ident::with_quote('\'', "b"),
+                value: Box::new(Expr::Dictionary(vec![DictionaryField {
+                    key: Ident::with_quote('\'', "c"),
+                    value: Box::new(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("x"))),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Value(number("1"))),
+                    }),
+                }])),
+            },
+        ]),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = dialects.verified_only_select("SELECT MAP {'k': v, 1 + 1: 'two'}, MAP {}");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Map(vec![
+                MapEntry {
+                    key: Box::new(Expr::Value(Value::SingleQuotedString("k".to_string()))),
+                    value: Box::new(Expr::Identifier(Ident::new("v"))),
+                },
+                MapEntry {
+                    key: Box::new(Expr::BinaryOp {
+                        left: Box::new(Expr::Value(number("1"))),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Value(number("1"))),
+                    }),
+                    value: Box::new(Expr::Value(Value::SingleQuotedString("two".to_string()))),
+                },
+            ])),
+            SelectItem::UnnamedExpr(Expr::Map(vec![])),
+        ],
+        select.projection
+    );
+
+    // without braces MAP is an ordinary function call
+    let select = dialects.verified_only_select("SELECT MAP(ARRAY['k'], ARRAY[1])");
+    assert!(matches!(
+        expr_from_projection(only(&select.projection)),
+        Expr::Function(_)
+    ));
+}
+
+#[test]
+fn parse_lambdas() {
+    let dialects = TestedDialects {
+        dialects: vec![
+            Box::new(HiveDialect {}),
+            Box::new(SnowflakeDialect {}),
+            Box::new(ClickHouseDialect {}),
+        ],
+    };
+    let select = dialects.verified_only_select("SELECT transform(arr, x -> x + 1)");
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("transform")]),
+            params: vec![],
+            args: vec![
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Ident::new("arr")))),
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Lambda {
+                    params: vec![Ident::new("x")],
+                    body: Box::new(Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("x"))),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::Value(number("1"))),
+                    }),
+                })),
+            ],
+            over: None,
+            distinct: false,
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = dialects.verified_only_select("SELECT filter(m, (k, v) -> v > 0)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Lambda {
+                params: vec![Ident::new("k"), Ident::new("v")],
+                body: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("v"))),
+                    op: BinaryOperator::Gt,
+                    right: Box::new(Expr::Value(number("0"))),
+                }),
+            })),
+            args[1]
+        ),
+        _ => unreachable!(),
+    }
+
+    dialects.verified_only_select("SELECT arrayMap(x -> x * 2, arr)");
+    dialects.verified_only_select("SELECT transform(a, x -> transform(x, y -> y + 1))");
+    dialects.verified_only_select("SELECT f((a), (b + 1))");
+    dialects.one_statement_parses_to(
+        "SELECT transform(arr, (x) -> x + 1)",
+        "SELECT transform(arr, x -> x + 1)",
+    );
+
+    // without lambdas `->` stays the JSON operator
+    let select = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {}), Box::new(PostgreSqlDialect {})],
+    }
+    .verified_only_select("SELECT lower(info -> 'name')");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("info"))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("name".to_string()))),
+            })),
+            args[0]
+        ),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_tuples() {
     use self::BinaryOperator::*;
@@ -3084,6 +4350,7 @@ fn parse_implicit_join() {
                     args: vec![],
                     with_hints: vec![],
                     instant: None,
+                    sample: None,
                 },
                 joins: vec![],
             },
@@ -3094,6 +4361,7 @@ fn parse_implicit_join() {
                     args: vec![],
                     with_hints: vec![],
                     instant: None,
+                    sample: None,
                 },
                 joins: vec![],
             }
@@ -3112,6 +4380,7 @@ fn parse_implicit_join() {
                     args: vec![],
                     with_hints: vec![],
                     instant: None,
+                    sample: None,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
@@ -3120,6 +4389,7 @@ fn parse_implicit_join() {
                         args: vec![],
                         with_hints: vec![],
                         instant: None,
+                        sample: None,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
@@ -3131,6 +4401,7 @@ fn parse_implicit_join() {
                     args: vec![],
                     with_hints: vec![],
                     instant: None,
+                    sample: None,
                 },
                 joins: vec![Join {
                     relation: TableFactor::Table {
@@ -3139,6 +4410,7 @@ fn parse_implicit_join() {
                         args: vec![],
                         with_hints: vec![],
                         instant: None,
+                        sample: None,
                     },
                     join_operator: JoinOperator::Inner(JoinConstraint::Natural),
                 }]
@@ -3160,6 +4432,7 @@ fn parse_cross_join() {
                 args: vec![],
                 with_hints: vec![],
                 instant: None,
+                sample: None,
             },
             join_operator: JoinOperator::CrossJoin
         },
@@ -3181,6 +4454,7 @@ fn parse_joins_on() {
                 args: vec![],
                 with_hints: vec![],
                 instant: None,
+                sample: None,
             },
             join_operator: f(JoinConstraint::On(Expr::BinaryOp {
                 left: Box::new(Expr::Identifier("c1".into())),
@@ -3235,6 +4509,7 @@ fn parse_joins_using() {
                 args: vec![],
                 with_hints: vec![],
                 instant: None,
+                sample: None,
             },
             join_operator: f(JoinConstraint::Using(vec!["c1".into()])),
         }
@@ -3281,6 +4556,7 @@ fn parse_natural_join() {
                 args: vec![],
                 with_hints: vec![],
                 instant: None,
+                sample: None,
             },
             join_operator: f(JoinConstraint::Natural),
         }
@@ -3436,7 +4712,10 @@ fn parse_cte_renamed_columns() {
     let sql = "WITH cte (col1, col2) AS (SELECT foo, bar FROM baz) SELECT * FROM cte";
     let query = all_dialects().verified_query(sql);
     assert_eq!(
-        vec![Ident::new("col1"), Ident::new("col2")],
+        vec![
+            TableAliasColumnDef::from_name("col1"),
+            TableAliasColumnDef::from_name("col2")
+        ],
         query
             .with
             .unwrap()
@@ -3468,10 +4747,7 @@ fn parse_recursive_cte() {
                 value: "nums".to_string(),
                 quote_style: None,
             },
-            columns: vec![Ident {
-                value: "val".to_string(),
-                quote_style: None,
-            }],
+            columns: vec![TableAliasColumnDef::from_name("val")],
         },
         query: cte_query,
         from: None,
@@ -3511,7 +4787,8 @@ fn parse_derived_tables() {
                 alias: Some(TableAlias {
                     name: "t1".into(),
                     columns: vec![],
-                })
+                }),
+                sample: None,
             },
             joins: vec![Join {
                 relation: TableFactor::Table {
@@ -3520,6 +4797,7 @@ fn parse_derived_tables() {
                     args: vec![],
                     with_hints: vec![],
                     instant: None,
+                    sample: None,
                 },
                 join_operator: JoinOperator::Inner(JoinConstraint::Natural),
             }],
@@ -3705,6 +4983,49 @@ fn parse_exists_subquery() {
     );
 }
 
+#[test]
+fn parse_create_sql_function() {
+    // BigQuery style temporary SQL function
+    let sql = "CREATE TEMPORARY FUNCTION add_one(x INT64) RETURNS INT64 AS (x + 1)";
+    match verified_stmt(sql) {
+        Statement::CreateFunction {
+            temporary,
+            args,
+            return_type,
+            body,
+            ..
+        } => {
+            assert!(temporary);
+            assert_eq!(
+                Some(vec![OperateFunctionArg {
+                    mode: None,
+                    name: Some("x".into()),
+                    data_type: DataType::Int64,
+                    default_expr: None,
+                }]),
+                args
+            );
+            assert_eq!(
+                Some(FunctionReturnType::DataType(DataType::Int64)),
+                return_type
+            );
+            assert_eq!(
+                Some(FunctionDefinition::Expr(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("x".into())),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(number("1"))),
+                })),
+                body.as_
+            );
+        }
+        _ => unreachable!(),
+    }
+    one_statement_parses_to(
+        "CREATE TEMP FUNCTION f(a STRING, b STRING) AS (CONCAT(a, b))",
+        "CREATE TEMPORARY FUNCTION f(a STRING, b STRING) AS (CONCAT(a, b))",
+    );
+}
+
 #[test]
 fn parse_create_view() {
     let sql = "CREATE VIEW myschema.myview AS SELECT foo FROM bar";
@@ -3918,6 +5239,38 @@ fn parse_drop_view() {
     }
 }
 
+#[test]
+fn parse_drop_sequence() {
+    let sql = "DROP SEQUENCE IF EXISTS s1, myschema.s2 CASCADE";
+    match verified_stmt(sql) {
+        Statement::Drop {
+            names,
+            object_type,
+            if_exists,
+            cascade,
+            ..
+        } => {
+            assert_eq!(
+                vec!["s1", "myschema.s2"],
+                names.iter().map(ToString::to_string).collect::<Vec<_>>()
+            );
+            assert_eq!(ObjectType::Sequence, object_type);
+            assert!(if_exists);
+            assert!(cascade);
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("DROP FUNCTION f");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected TABLE, VIEW, INDEX, SCHEMA or SEQUENCE after DROP, found: FUNCTION"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_invalid_subquery_without_parens() {
     let res = parse_sql_statements("SELECT SELECT 1 FROM bar WHERE 1=1 FROM baz");
@@ -4135,6 +5488,7 @@ fn lateral_derived() {
             lateral,
             ref subquery,
             alias: Some(ref alias),
+            ..
         } = join.relation
         {
             assert_eq!(lateral_in, lateral);
@@ -4150,11 +5504,11 @@ fn lateral_derived() {
     chk(false);
     chk(true);
 
-    let sql = "SELECT * FROM customer LEFT JOIN LATERAL generate_series(1, customer.id)";
+    let sql = "SELECT * FROM customer LEFT JOIN LATERAL generate_series";
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected subquery after LATERAL, found: generate_series".to_string()
+            "Expected subquery or function after LATERAL, found: EOF".to_string()
         ),
         res.unwrap_err()
     );
@@ -4339,12 +5693,16 @@ fn parse_create_index() {
             columns,
             unique,
             if_not_exists,
+            with,
+            tablespace,
         } => {
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
             assert_eq!(indexed_columns, columns);
             assert!(unique);
-            assert!(if_not_exists)
+            assert!(if_not_exists);
+            assert!(with.is_empty());
+            assert_eq!(None, tablespace);
         }
         _ => unreachable!(),
     }
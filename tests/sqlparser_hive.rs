@@ -15,6 +15,7 @@
 //! Test SQL syntax specific to Hive. The parser based on the generic dialect
 //! is also tested (on the inputs it can handle).
 
+use sqlparser::ast::*;
 use sqlparser::dialect::HiveDialect;
 use sqlparser::test_utils::*;
 
@@ -121,6 +122,21 @@ fn test_drop_if_exists() {
     hive().verified_stmt(drop);
 }
 
+#[test]
+fn test_set_tblproperties() {
+    let sql = "ALTER TABLE db.table SET TBLPROPERTIES ('comment' = 'a table', 'retention' = 30)";
+    match alter_table_op(hive().verified_stmt(sql)) {
+        AlterTableOperation::SetTblProperties { table_properties } => assert_eq!(
+            vec!["'comment' = 'a table'", "'retention' = 30"],
+            table_properties
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        ),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn test_cluster_by() {
     let cluster = "SELECT a FROM db.table CLUSTER BY a, b";
@@ -199,6 +215,109 @@ fn from_cte() {
     println!("{}", hive().verified_stmt(rename));
 }
 
+#[test]
+fn table_sample() {
+    hive().verified_stmt("SELECT * FROM source TABLESAMPLE (BUCKET 3 OUT OF 32 ON id) AS s");
+    hive().one_statement_parses_to(
+        "SELECT * FROM source TABLESAMPLE(BUCKET 3 OUT OF 32 ON id) s",
+        "SELECT * FROM source TABLESAMPLE (BUCKET 3 OUT OF 32 ON id) AS s",
+    );
+    let select = hive().verified_only_select("SELECT * FROM source TABLESAMPLE (0.5 PERCENT) AS s");
+    match &select.from[0].relation {
+        TableFactor::Table { sample, .. } => {
+            assert!(matches!(sample, Some(TableSampleKind::BeforeTableAlias(_))))
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+    hive().verified_stmt("SELECT * FROM source TABLESAMPLE (BUCKET 1 OUT OF 4)");
+    hive().verified_stmt("SELECT * FROM source TABLESAMPLE (0.1 PERCENT)");
+}
+
+#[test]
+fn parse_named_struct() {
+    hive().verified_stmt("SELECT named_struct('a', 1, 'b', x).a FROM t");
+    hive().verified_stmt("SELECT (s).a, (s).* FROM t");
+}
+
+#[test]
+fn parse_create_function() {
+    let sql = "CREATE TEMPORARY FUNCTION mydb.myfunc AS 'org.example.MyUdf' \
+               USING JAR 'hdfs:///udf.jar', FILE 'hdfs:///data.txt'";
+    match hive().verified_stmt(sql) {
+        Statement::CreateFunction {
+            temporary,
+            name,
+            args,
+            body,
+            ..
+        } => {
+            assert!(temporary);
+            assert_eq!("mydb.myfunc", name.to_string());
+            assert_eq!(None, args);
+            assert_eq!(
+                Some(FunctionDefinition::SingleQuotedDef(
+                    "org.example.MyUdf".to_string()
+                )),
+                body.as_
+            );
+            assert_eq!(
+                vec![
+                    CreateFunctionUsing::Jar("hdfs:///udf.jar".to_string()),
+                    CreateFunctionUsing::File("hdfs:///data.txt".to_string()),
+                ],
+                body.using
+            );
+        }
+        _ => unreachable!(),
+    }
+    hive().verified_stmt("CREATE FUNCTION f AS 'org.example.F' USING ARCHIVE 'a.zip'");
+}
+
+#[test]
+fn parse_array_expr() {
+    let sql = "SELECT arr[i + 1], m['key'] FROM t";
+    let select = match hive().one_statement_parses_to(sql, "SELECT arr[i + 1], m[\"key\"] FROM t") {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::ArrayIndex {
+                obj: Box::new(Expr::Identifier(Ident::new("arr"))),
+                index: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("i"))),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(number("1"))),
+                }),
+            }),
+            SelectItem::UnnamedExpr(Expr::MapAccess {
+                column: Box::new(Expr::Identifier(Ident::new("m"))),
+                keys: vec![Value::SingleQuotedString("key".to_string())],
+            }),
+        ],
+        select.projection
+    );
+
+    // Hive's ARRAY(...) constructor is an array literal
+    let select = hive().one_statement_parses_to("SELECT ARRAY(1, 2)", "SELECT [1, 2]");
+    match select {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => assert_eq!(
+                &Expr::Array(Array {
+                    elem: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+                    named: false,
+                }),
+                expr_from_projection(only(&select.projection))
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 fn hive() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(HiveDialect {})],
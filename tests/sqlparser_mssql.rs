@@ -118,6 +118,130 @@ fn parse_mssql_bin_literal() {
     let _ = ms_and_generic().one_statement_parses_to("SELECT 0xdeadBEEF", "SELECT X'deadBEEF'");
 }
 
+#[test]
+fn parse_convert() {
+    let sql = "SELECT CONVERT(VARCHAR(10), d, 120) FROM t";
+    let canonical = "SELECT CONVERT(CHARACTER VARYING(10), d, 120) FROM t";
+    ms().one_statement_parses_to(sql, canonical);
+    let select = ms().verified_only_select(canonical);
+    assert_eq!(
+        &Expr::Convert {
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+            data_type: Some(DataType::Varchar(Some(CharacterLength::Length(10)))),
+            charset: None,
+            target_before_value: true,
+            styles: vec![Expr::Value(number("120"))],
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    ms().verified_stmt("SELECT CONVERT(INT, '42')");
+}
+
+#[test]
+fn parse_create_procedure() {
+    let sql = "CREATE PROCEDURE dbo.touch_user @id INT, @seen INT = 1, @count INT OUTPUT \
+               AS BEGIN UPDATE users SET seen = @seen WHERE id = @id; SELECT @count; END";
+    match ms().verified_stmt(sql) {
+        Statement::CreateProcedure {
+            or_alter,
+            name,
+            params,
+            body,
+        } => {
+            assert!(!or_alter);
+            assert_eq!("dbo.touch_user", name.to_string());
+            assert_eq!(
+                vec![
+                    ProcedureParam {
+                        name: "@id".into(),
+                        data_type: DataType::Int(None),
+                        default_expr: None,
+                        output: false,
+                    },
+                    ProcedureParam {
+                        name: "@seen".into(),
+                        data_type: DataType::Int(None),
+                        default_expr: Some(Expr::Value(number("1"))),
+                        output: false,
+                    },
+                    ProcedureParam {
+                        name: "@count".into(),
+                        data_type: DataType::Int(None),
+                        default_expr: None,
+                        output: true,
+                    },
+                ],
+                params
+            );
+            assert_eq!(2, body.len());
+        }
+        _ => unreachable!(),
+    }
+
+    ms().one_statement_parses_to(
+        "CREATE OR ALTER PROC p (@id INT OUT) AS SELECT @id",
+        "CREATE OR ALTER PROCEDURE p @id INT OUTPUT AS BEGIN SELECT @id; END",
+    );
+    ms().verified_stmt("CREATE PROCEDURE p AS BEGIN SELECT 1; SELECT 2; END");
+    // the last statement of the block needs no trailing `;`
+    ms().one_statement_parses_to(
+        "CREATE PROCEDURE p AS BEGIN SELECT * FROM t END",
+        "CREATE PROCEDURE p AS BEGIN SELECT * FROM t; END",
+    );
+    ms().one_statement_parses_to(
+        "CREATE PROCEDURE p AS BEGIN SELECT a END",
+        "CREATE PROCEDURE p AS BEGIN SELECT a; END",
+    );
+}
+
+#[test]
+fn parse_computed_column() {
+    let sql = "CREATE TABLE t (a INT, b AS (a * 2) PERSISTED, c AS (a + 1))";
+    match ms_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(DataType::Unspecified, columns[1].data_type);
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Generated {
+                        generated_keyword: false,
+                        expr: Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier("a".into())),
+                            op: BinaryOperator::Multiply,
+                            right: Box::new(Expr::Value(number("2"))),
+                        },
+                        mode: Some(GeneratedColumnMode::Persisted),
+                    },
+                }],
+                columns[1].options
+            );
+            assert_eq!(DataType::Unspecified, columns[2].data_type);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_lt_variable_is_not_containment() {
+    // `<@` is only the PostgreSQL containment operator
+    let select =
+        ms().one_statement_parses_to("SELECT * FROM t WHERE a<@b", "SELECT * FROM t WHERE a < @b");
+    match select {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => assert_eq!(
+                Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("a"))),
+                    op: BinaryOperator::Lt,
+                    right: Box::new(Expr::Identifier(Ident::new("@b"))),
+                }),
+                select.selection
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
@@ -21,7 +21,7 @@ use test_utils::*;
 
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, MySqlDialect};
-use sqlparser::tokenizer::Token;
+use sqlparser::parser::ParserError;
 
 #[test]
 fn parse_identifiers() {
@@ -140,9 +140,7 @@ fn parse_create_table_auto_increment() {
                         },
                         ColumnOptionDef {
                             name: None,
-                            option: ColumnOption::DialectSpecific(vec![Token::make_keyword(
-                                "AUTO_INCREMENT"
-                            )]),
+                            option: ColumnOption::AutoIncrement,
                         },
                     ],
                 }],
@@ -166,10 +164,7 @@ fn parse_create_table_with_column_comment() {
                     collation: None,
                     options: vec![ColumnOptionDef {
                         name: None,
-                        option: ColumnOption::DialectSpecific(vec![
-                            Token::make_keyword("COMMENT"),
-                            Token::make_word("bar", Some('\''))
-                        ]),
+                        option: ColumnOption::Comment("bar".to_string()),
                     },],
                 }],
                 columns
@@ -343,17 +338,26 @@ fn parse_simple_insert() {
                 Some(Box::new(Query {
                     with: None,
                     body: SetExpr::Values(Values(vec![
-                        vec![Expr::Array(vec![]),],
-                        vec![Expr::Array(vec![
-                            Expr::Value(number("1")),
-                            Expr::Value(number("2")),
-                            Expr::Value(number("3"))
-                        ]),],
-                        vec![Expr::Array(vec![
-                            Expr::Value(number("4")),
-                            Expr::Value(number("5")),
-                            Expr::Value(number("6"))
-                        ]),],
+                        vec![Expr::Array(Array {
+                            elem: vec![],
+                            named: false,
+                        })],
+                        vec![Expr::Array(Array {
+                            elem: vec![
+                                Expr::Value(number("1")),
+                                Expr::Value(number("2")),
+                                Expr::Value(number("3"))
+                            ],
+                            named: false,
+                        })],
+                        vec![Expr::Array(Array {
+                            elem: vec![
+                                Expr::Value(number("4")),
+                                Expr::Value(number("5")),
+                                Expr::Value(number("6"))
+                            ],
+                            named: false,
+                        })],
                     ])),
                     order_by: vec![],
                     limit: None,
@@ -391,10 +395,12 @@ fn parse_double_quoted() {
     let sql = r#"SELECT * FROM customers WHERE name LIKE "%a" IS NULL"#;
     let select = mysql().verified_only_select(sql);
     assert_eq!(
-        Expr::IsNull(Box::new(Expr::BinaryOp {
-            left: Box::new(Expr::Identifier(Ident::new("name"))),
-            op: BinaryOperator::Like,
-            right: Box::new(Expr::Value(Value::DoubleQuotedString("%a".to_string()))),
+        Expr::IsNull(Box::new(Expr::Like {
+            negated: false,
+            case_insensitive: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            pattern: Box::new(Expr::Value(Value::DoubleQuotedString("%a".to_string()))),
+            escape_char: None,
         })),
         select.selection.unwrap()
     );
@@ -420,6 +426,7 @@ fn parse_update_with_joins() {
                         args: vec![],
                         with_hints: vec![],
                         instant: None,
+                        sample: None,
                     },
                     joins: vec![Join {
                         relation: TableFactor::Table {
@@ -431,6 +438,7 @@ fn parse_update_with_joins() {
                             args: vec![],
                             with_hints: vec![],
                             instant: None,
+                            sample: None,
                         },
                         join_operator: JoinOperator::Inner(JoinConstraint::On(Expr::BinaryOp {
                             left: Box::new(Expr::CompoundIdentifier(vec![
@@ -478,22 +486,23 @@ fn parse_alter_table_change_column() {
         new_name: Ident::new("desc"),
         data_type: DataType::Text,
         options: vec![ColumnOption::NotNull],
+        position: None,
     };
 
     let sql1 = "ALTER TABLE orders CHANGE COLUMN description desc TEXT NOT NULL";
     match mysql().verified_stmt(sql1) {
-        Statement::AlterTable { name, operation } => {
+        Statement::AlterTable { name, operations } => {
             assert_eq!(expected_name, name);
-            assert_eq!(expected_operation, operation);
+            assert_eq!(vec![expected_operation.clone()], operations);
         }
         _ => unreachable!(),
     }
 
     let sql2 = "ALTER TABLE orders CHANGE description desc TEXT NOT NULL";
     match mysql().one_statement_parses_to(sql2, sql1) {
-        Statement::AlterTable { name, operation } => {
+        Statement::AlterTable { name, operations } => {
             assert_eq!(expected_name, name);
-            assert_eq!(expected_operation, operation);
+            assert_eq!(vec![expected_operation], operations);
         }
         _ => unreachable!(),
     }
@@ -538,6 +547,7 @@ fn parse_substring_in_select() {
                                 args: vec![],
                                 with_hints: vec![],
                                 instant: None,
+                                sample: None,
                             },
                             joins: vec![]
                         }],
@@ -562,6 +572,706 @@ fn parse_substring_in_select() {
     }
 }
 
+#[test]
+fn parse_json_path_operators() {
+    let sql = "SELECT col->'$.a', col->>'$.a' FROM t";
+    let canonical = "SELECT col -> '$.a', col ->> '$.a' FROM t";
+    mysql().one_statement_parses_to(sql, canonical);
+    let select = mysql().verified_only_select(canonical);
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("col"))),
+                op: BinaryOperator::Arrow,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("$.a".to_string()))),
+            }),
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("col"))),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("$.a".to_string()))),
+            }),
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_compound_date_time_fields() {
+    let select = mysql().verified_only_select("SELECT EXTRACT(YEAR_MONTH FROM d)");
+    assert_eq!(
+        &Expr::Extract {
+            field: DateTimeField::YearMonth,
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    mysql().verified_stmt("SELECT d + INTERVAL '1 2' DAY_HOUR");
+    mysql().verified_stmt("SELECT d - INTERVAL '10:30' MINUTE_SECOND");
+}
+
+#[test]
+fn parse_convert_using() {
+    let select = mysql().verified_only_select("SELECT CONVERT(name USING utf8mb4) FROM t");
+    assert_eq!(
+        &Expr::Convert {
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            data_type: None,
+            charset: Some(ObjectName(vec![Ident::new("utf8mb4")])),
+            target_before_value: false,
+            styles: vec![],
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    mysql().verified_stmt("SELECT CONVERT('42', SIGNED) FROM t");
+    mysql().verified_stmt("SELECT CONVERT(d, DATE) FROM t");
+}
+
+#[test]
+fn parse_match_against() {
+    let sql = "SELECT * FROM t WHERE MATCH (title, body) AGAINST ('term' IN BOOLEAN MODE)";
+    let select = mysql_and_generic().verified_only_select(sql);
+    assert_eq!(
+        Expr::MatchAgainst {
+            columns: vec![
+                ObjectName(vec![Ident::new("title")]),
+                ObjectName(vec![Ident::new("body")]),
+            ],
+            match_value: Box::new(Expr::Value(Value::SingleQuotedString("term".to_string()))),
+            search_modifier: Some(SearchModifier::InBooleanMode),
+        },
+        select.selection.unwrap()
+    );
+
+    mysql().verified_stmt("SELECT MATCH (t.title) AGAINST ('a b') AS score FROM t");
+    mysql().verified_stmt(
+        "SELECT * FROM t WHERE MATCH (title) AGAINST ('term' IN NATURAL LANGUAGE MODE)",
+    );
+    mysql().verified_stmt(
+        "SELECT * FROM t WHERE MATCH (title) AGAINST ('term' IN NATURAL LANGUAGE MODE WITH QUERY EXPANSION)",
+    );
+    mysql()
+        .verified_stmt("SELECT * FROM t WHERE MATCH (title) AGAINST ('term' WITH QUERY EXPANSION)");
+}
+
+#[test]
+fn parse_unquoted_interval() {
+    let select = mysql_and_generic().verified_only_select("SELECT INTERVAL 1 DAY + d");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Interval {
+                value: Box::new(Expr::Value(number("1"))),
+                leading_field: Some(DateTimeField::Day),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            }),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Identifier(Ident::new("d"))),
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    mysql().verified_stmt("SELECT d - INTERVAL n + 1 HOUR FROM t");
+    mysql().verified_stmt("SELECT DATE_ADD(d, INTERVAL -1 MONTH) FROM t");
+
+    assert_eq!(
+        ParserError::ParserError("Expected date/time field, found: EOF".to_string()),
+        mysql()
+            .parse_sql_statements("SELECT INTERVAL 1")
+            .unwrap_err()
+    );
+}
+
+#[test]
+fn parse_like_backslash_escape() {
+    let select = mysql().verified_only_select(r"SELECT * FROM t WHERE a LIKE 'a\_%' ESCAPE '\\'");
+    match select.selection {
+        Some(Expr::Like { escape_char, .. }) => assert_eq!(Some("\\".to_string()), escape_char),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_introduced_strings_and_bit_literals() {
+    let select = mysql().verified_only_select("SELECT _utf8mb4'abc', _binary X'FF', B'0101'");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::IntroducedString {
+                introducer: "_utf8mb4".to_string(),
+                value: Value::SingleQuotedString("abc".to_string()),
+            }),
+            SelectItem::UnnamedExpr(Expr::IntroducedString {
+                introducer: "_binary".to_string(),
+                value: Value::HexStringLiteral("FF".to_string()),
+            }),
+            SelectItem::UnnamedExpr(Expr::Value(Value::BitStringLiteral("0101".to_string()))),
+        ],
+        select.projection
+    );
+    mysql().one_statement_parses_to("SELECT b'0101'", "SELECT B'0101'");
+}
+
+#[test]
+fn parse_collate_positions() {
+    let select = mysql().verified_only_select(
+        "SELECT CONCAT(a, b) COLLATE utf8mb4_bin FROM t WHERE _utf8mb4'x' COLLATE utf8mb4_bin = name",
+    );
+    assert_eq!(
+        &Expr::Collate {
+            expr: Box::new(Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("CONCAT")]),
+                params: vec![],
+                args: vec![
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Ident::new("a")))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Ident::new("b")))),
+                ],
+                over: None,
+                distinct: false,
+            })),
+            collation: ObjectName(vec![Ident::new("utf8mb4_bin")]),
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    mysql().verified_stmt("SELECT * FROM t ORDER BY name COLLATE utf8mb4_unicode_ci DESC");
+    mysql().verified_stmt("SELECT CAST(a AS CHAR) COLLATE utf8mb4_bin FROM t");
+}
+
+#[test]
+fn parse_variable_assignment() {
+    let select = mysql().verified_only_select("SELECT @rownum := @rownum + 1 AS rank FROM t");
+    assert_eq!(
+        SelectItem::ExprWithAlias {
+            expr: Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("@rownum"))),
+                op: BinaryOperator::Assignment,
+                right: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("@rownum"))),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(number("1"))),
+                }),
+            },
+            alias: Ident::new("rank"),
+        },
+        select.projection[0]
+    );
+
+    // `:=` is right-associative
+    let select = mysql().verified_only_select("SELECT @a := @b := 1");
+    match expr_from_projection(&select.projection[0]) {
+        Expr::BinaryOp { right, .. } => assert!(matches!(
+            right.as_ref(),
+            Expr::BinaryOp {
+                op: BinaryOperator::Assignment,
+                ..
+            }
+        )),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_character_set_and_collation_on_types() {
+    let sql = "CREATE TABLE t (\
+        name VARCHAR(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL, \
+        kind ENUM('a', 'b') CHARSET latin1)";
+    let canonical = "CREATE TABLE t (\
+        name CHARACTER VARYING(20) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin NOT NULL, \
+        kind ENUM('a', 'b') CHARACTER SET latin1)";
+    let columns = match mysql().one_statement_parses_to(sql, canonical) {
+        Statement::CreateTable { columns, .. } => columns,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        DataType::CharacterSet(
+            Box::new(DataType::Varchar(Some(CharacterLength::Length(20)))),
+            ObjectName(vec![Ident::new("utf8mb4")])
+        ),
+        columns[0].data_type
+    );
+    assert_eq!(
+        Some(ObjectName(vec![Ident::new("utf8mb4_bin")])),
+        columns[0].collation
+    );
+    assert_eq!(
+        DataType::CharacterSet(
+            Box::new(DataType::Enum(vec![
+                EnumMember {
+                    name: "a".to_string(),
+                    value: None,
+                },
+                EnumMember {
+                    name: "b".to_string(),
+                    value: None,
+                },
+            ])),
+            ObjectName(vec![Ident::new("latin1")])
+        ),
+        columns[1].data_type
+    );
+
+    mysql()
+        .verified_stmt("SELECT CAST(a AS CHAR CHARACTER SET utf8mb4) COLLATE utf8mb4_bin FROM t");
+}
+
+#[test]
+fn parse_column_options() {
+    let sql = "CREATE TABLE t (\
+               a INT AUTO_INCREMENT, \
+               b INT GENERATED ALWAYS AS (a * 2) STORED, \
+               c INT AS (a + 1) VIRTUAL, \
+               d TIMESTAMP DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP \
+               COMMENT 'it''s updated', \
+               e TEXT NOT NULL CHARACTER SET utf8mb4)";
+    // a character set following other options belongs to the data type
+    let canonical = sql.replace(
+        "TEXT NOT NULL CHARACTER SET utf8mb4",
+        "TEXT CHARACTER SET utf8mb4 NOT NULL",
+    );
+    let columns = match mysql_and_generic().one_statement_parses_to(sql, &canonical) {
+        Statement::CreateTable { columns, .. } => columns,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        DataType::CharacterSet(
+            Box::new(DataType::Text),
+            ObjectName(vec![Ident::new("utf8mb4")])
+        ),
+        columns[4].data_type
+    );
+    let options: Vec<Vec<ColumnOption>> = columns
+        .into_iter()
+        .map(|c| c.options.into_iter().map(|o| o.option).collect())
+        .collect();
+    let a_times_two = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier("a".into())),
+        op: BinaryOperator::Multiply,
+        right: Box::new(Expr::Value(number("2"))),
+    };
+    let a_plus_one = Expr::BinaryOp {
+        left: Box::new(Expr::Identifier("a".into())),
+        op: BinaryOperator::Plus,
+        right: Box::new(Expr::Value(number("1"))),
+    };
+    assert_eq!(
+        vec![
+            vec![ColumnOption::AutoIncrement],
+            vec![ColumnOption::Generated {
+                generated_keyword: true,
+                expr: a_times_two,
+                mode: Some(GeneratedColumnMode::Stored),
+            }],
+            vec![ColumnOption::Generated {
+                generated_keyword: false,
+                expr: a_plus_one,
+                mode: Some(GeneratedColumnMode::Virtual),
+            }],
+            vec![
+                ColumnOption::Default(Expr::Identifier("CURRENT_TIMESTAMP".into())),
+                ColumnOption::OnUpdate(Expr::Identifier("CURRENT_TIMESTAMP".into())),
+                ColumnOption::Comment("it's updated".to_string()),
+            ],
+            vec![ColumnOption::NotNull],
+        ],
+        options
+    );
+
+    let res = mysql().parse_sql_statements(
+        "CREATE TABLE t (a VARCHAR(10) CHARACTER SET utf8mb4 NOT NULL CHARSET latin1)",
+    );
+    assert_eq!(
+        ParserError::ParserError(
+            "Duplicate CHARACTER SET for type CHARACTER VARYING(10) CHARACTER SET utf8mb4"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_options() {
+    let sql = "CREATE TABLE t (a INT) ENGINE=InnoDB AUTO_INCREMENT=10 DEFAULT CHARSET=utf8mb4 \
+               COLLATE=utf8mb4_0900_ai_ci ROW_FORMAT=DYNAMIC COMMENT='it''s a table' KEY_BLOCK_SIZE=8";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { table_options, .. } => assert_eq!(
+            vec![
+                TableOption::Engine("InnoDB".into()),
+                TableOption::AutoIncrement(10),
+                TableOption::CharacterSet {
+                    default: true,
+                    name: "utf8mb4".into(),
+                },
+                TableOption::Collate {
+                    default: false,
+                    name: "utf8mb4_0900_ai_ci".into(),
+                },
+                TableOption::RowFormat("DYNAMIC".into()),
+                TableOption::Comment("it's a table".to_string()),
+                TableOption::Other {
+                    name: "KEY_BLOCK_SIZE".into(),
+                    value: Expr::Value(number("8")),
+                },
+            ],
+            table_options
+        ),
+        _ => unreachable!(),
+    }
+
+    // the `=` and the separating commas are optional
+    mysql().one_statement_parses_to(
+        "CREATE TABLE t (a INT) ENGINE InnoDB, DEFAULT CHARACTER SET latin1, COMMENT 'c'",
+        "CREATE TABLE t (a INT) ENGINE=InnoDB DEFAULT CHARSET=latin1 COMMENT='c'",
+    );
+
+    let res = mysql().parse_sql_statements("CREATE TABLE t (a INT) ENGINE=InnoDB,");
+    assert_eq!(
+        ParserError::ParserError("Expected table option after ',', found: EOF".to_string()),
+        res.unwrap_err()
+    );
+    let res = mysql().parse_sql_statements("CREATE TABLE t (a INT) DEFAULT ENGINE=InnoDB");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected CHARSET, CHARACTER SET or COLLATE after DEFAULT, found: ENGINE".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_index_definitions() {
+    let sql = "CREATE TABLE t (\
+               id INT, \
+               email TEXT, \
+               body TEXT, \
+               g GEOMETRY, \
+               PRIMARY KEY (id) USING BTREE, \
+               UNIQUE KEY email (email(20) DESC), \
+               KEY idx_email (email(10)) USING HASH, \
+               INDEX (id, email DESC), \
+               FULLTEXT KEY ft_body (body), \
+               SPATIAL INDEX (g))";
+    let key_part = |column: &str, length, asc| KeyPart {
+        column: column.into(),
+        length,
+        asc,
+    };
+    match mysql().verified_stmt(sql) {
+        Statement::CreateTable { constraints, .. } => assert_eq!(
+            vec![
+                TableConstraint::Unique {
+                    name: None,
+                    index_name: None,
+                    index_type_display: KeyOrIndexDisplay::None,
+                    columns: vec![key_part("id", None, None)],
+                    is_primary: true,
+                    index_type: Some(IndexType::BTree),
+                },
+                TableConstraint::Unique {
+                    name: None,
+                    index_name: Some("email".into()),
+                    index_type_display: KeyOrIndexDisplay::Key,
+                    columns: vec![key_part("email", Some(20), Some(false))],
+                    is_primary: false,
+                    index_type: None,
+                },
+                TableConstraint::Index {
+                    index_type_display: KeyOrIndexDisplay::Key,
+                    index_name: Some("idx_email".into()),
+                    columns: vec![key_part("email", Some(10), None)],
+                    index_type: Some(IndexType::Hash),
+                },
+                TableConstraint::Index {
+                    index_type_display: KeyOrIndexDisplay::Index,
+                    index_name: None,
+                    columns: vec![
+                        key_part("id", None, None),
+                        key_part("email", None, Some(false))
+                    ],
+                    index_type: None,
+                },
+                TableConstraint::FulltextOrSpatial {
+                    fulltext: true,
+                    index_type_display: KeyOrIndexDisplay::Key,
+                    index_name: Some("ft_body".into()),
+                    columns: vec![key_part("body", None, None)],
+                },
+                TableConstraint::FulltextOrSpatial {
+                    fulltext: false,
+                    index_type_display: KeyOrIndexDisplay::Index,
+                    index_name: None,
+                    columns: vec![key_part("g", None, None)],
+                },
+            ],
+            constraints
+        ),
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("CREATE TABLE t (a INT, CONSTRAINT uq UNIQUE INDEX uq_a (a))");
+    let res = mysql().parse_sql_statements("CREATE TABLE t (a INT, CONSTRAINT c KEY (a))");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected PRIMARY, UNIQUE, FOREIGN, or CHECK, found: KEY".to_string()
+        ),
+        res.unwrap_err()
+    );
+
+    // elsewhere `key` and `index` are ordinary column names
+    let generic = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    };
+    match generic.verified_stmt("CREATE TABLE t (a INT, key INT, index TEXT, fulltext INT)") {
+        Statement::CreateTable {
+            columns,
+            constraints,
+            ..
+        } => {
+            assert_eq!(4, columns.len());
+            assert!(constraints.is_empty());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_partitions() {
+    let sql = "CREATE TABLE t (id INT, d DATE) ENGINE=InnoDB PARTITION BY RANGE (YEAR(d)) (\
+               PARTITION p0 VALUES LESS THAN (1991) ENGINE=InnoDB, \
+               PARTITION p1 VALUES LESS THAN MAXVALUE COMMENT='rest')";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable {
+            table_options,
+            partition_by,
+            ..
+        } => {
+            assert_eq!(vec![TableOption::Engine("InnoDB".into())], table_options);
+            let partition_by = partition_by.unwrap();
+            assert_eq!(PartitionMethod::Range, partition_by.method);
+            assert_eq!(None, partition_by.partitions);
+            assert_eq!(
+                vec![
+                    PartitionDefinition {
+                        name: "p0".into(),
+                        values: Some(PartitionValues::LessThan(vec![Expr::Value(number("1991"))])),
+                        options: vec![TableOption::Engine("InnoDB".into())],
+                    },
+                    PartitionDefinition {
+                        name: "p1".into(),
+                        values: Some(PartitionValues::LessThanMaxValue),
+                        options: vec![TableOption::Comment("rest".to_string())],
+                    },
+                ],
+                partition_by.definitions
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt(
+        "CREATE TABLE t (a INT, b INT) PARTITION BY RANGE COLUMNS (a, b) \
+         (PARTITION p0 VALUES LESS THAN (10, 20))",
+    );
+    mysql().verified_stmt(
+        "CREATE TABLE t (region INT) PARTITION BY LIST (region) \
+         (PARTITION east VALUES IN (1, 2), PARTITION west VALUES IN (3))",
+    );
+    mysql().verified_stmt("CREATE TABLE t (id INT) PARTITION BY HASH (id) PARTITIONS 4");
+    mysql().verified_stmt("CREATE TABLE t (id INT PRIMARY KEY) PARTITION BY KEY () PARTITIONS 2");
+}
+
+#[test]
+fn parse_mysqldump_no_data() {
+    let sql = r#"-- MySQL dump 10.13  Distrib 8.0.32, for Linux (x86_64)
+--
+-- Host: localhost    Database: shop
+-- ------------------------------------------------------
+-- Server version	8.0.32
+
+/*!40101 SET @OLD_CHARACTER_SET_CLIENT=@@CHARACTER_SET_CLIENT */;
+/*!50503 SET NAMES utf8mb4 */;
+/*!40103 SET @OLD_TIME_ZONE=@@TIME_ZONE */;
+/*!40014 SET @OLD_UNIQUE_CHECKS=@@UNIQUE_CHECKS, UNIQUE_CHECKS=0 */;
+
+--
+-- Table structure for table `orders`
+--
+
+DROP TABLE IF EXISTS `orders`;
+/*!40101 SET @saved_cs_client     = @@character_set_client */;
+/*!50503 SET character_set_client = utf8mb4 */;
+CREATE TABLE `orders` (
+  `id` bigint unsigned NOT NULL AUTO_INCREMENT,
+  `user_id` int NOT NULL,
+  `status` enum('new','paid','shipped') COLLATE utf8mb4_bin NOT NULL DEFAULT 'new',
+  `note` varchar(255) CHARACTER SET latin1 DEFAULT NULL,
+  `total` decimal(10,2) NOT NULL DEFAULT '0.00',
+  `created_at` timestamp NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` datetime DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uq_user_created` (`user_id`,`created_at`),
+  KEY `idx_status` (`status`) USING BTREE,
+  KEY `idx_note_prefix` (`note`(10)),
+  KEY `idx_total` (`total` DESC),
+  FULLTEXT KEY `ft_note` (`note`),
+  CONSTRAINT `fk_user` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB AUTO_INCREMENT=42 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_0900_ai_ci COMMENT='customer orders';
+/*!40101 SET character_set_client = @saved_cs_client */;
+/*!40101 SET CHARACTER_SET_CLIENT=@OLD_CHARACTER_SET_CLIENT */;
+
+-- Dump completed on 2023-01-01 12:00:00
+"#;
+    let statements = mysql().parse_sql_statements(sql).unwrap();
+    assert_eq!(2, statements.len());
+    match &statements[1] {
+        Statement::CreateTable {
+            name,
+            columns,
+            constraints,
+            table_options,
+            ..
+        } => {
+            assert_eq!("`orders`", name.to_string());
+            assert_eq!(7, columns.len());
+            assert_eq!(7, constraints.len());
+            assert_eq!(
+                "KEY `idx_note_prefix` (`note`(10))",
+                constraints[3].to_string()
+            );
+            assert_eq!("KEY `idx_total` (`total` DESC)", constraints[4].to_string());
+            assert_eq!(
+                "ENGINE=InnoDB AUTO_INCREMENT=42 DEFAULT CHARSET=utf8mb4 \
+                 COLLATE=utf8mb4_0900_ai_ci COMMENT='customer orders'",
+                table_options
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_mysqldump_key_prefixes() {
+    let sql = r#"--
+-- Table structure for table `articles`
+--
+
+DROP TABLE IF EXISTS `articles`;
+/*!40101 SET @saved_cs_client     = @@character_set_client */;
+/*!50503 SET character_set_client = utf8mb4 */;
+CREATE TABLE `articles` (
+  `id` int unsigned NOT NULL AUTO_INCREMENT,
+  `slug` varchar(255) COLLATE utf8mb4_unicode_ci NOT NULL,
+  `title` varchar(500) NOT NULL,
+  `body` mediumtext,
+  `published_at` datetime DEFAULT NULL,
+  PRIMARY KEY (`id`,`slug`(20)),
+  UNIQUE KEY `uk_slug` (`slug`(191)),
+  KEY `idx_title` (`title`(100)),
+  KEY `idx_published` (`published_at` DESC),
+  FULLTEXT KEY `ft_body` (`body`)
+) ENGINE=InnoDB AUTO_INCREMENT=7 DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci;
+/*!40101 SET character_set_client = @saved_cs_client */;
+"#;
+    let statements = mysql().parse_sql_statements(sql).unwrap();
+    assert_eq!(2, statements.len());
+    match &statements[1] {
+        Statement::CreateTable { constraints, .. } => assert_eq!(
+            vec![
+                "PRIMARY KEY (`id`, `slug`(20))",
+                "UNIQUE KEY `uk_slug` (`slug`(191))",
+                "KEY `idx_title` (`title`(100))",
+                "KEY `idx_published` (`published_at` DESC)",
+                "FULLTEXT KEY `ft_body` (`body`)",
+            ],
+            constraints
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_modify_column() {
+    let sql = "ALTER TABLE orders MODIFY COLUMN description TEXT NOT NULL AFTER id";
+    match alter_table_op(mysql().verified_stmt(sql)) {
+        AlterTableOperation::ModifyColumn {
+            column_def,
+            position,
+        } => {
+            assert_eq!("description TEXT NOT NULL", column_def.to_string());
+            assert_eq!(Some(ColumnPosition::After(Ident::new("id"))), position);
+        }
+        _ => unreachable!(),
+    }
+    mysql().one_statement_parses_to(
+        "ALTER TABLE orders MODIFY description TEXT FIRST",
+        "ALTER TABLE orders MODIFY COLUMN description TEXT FIRST",
+    );
+    mysql().verified_stmt("ALTER TABLE orders CHANGE COLUMN description descr TEXT AFTER id");
+}
+
+#[test]
+fn parse_alter_table_add_column_position() {
+    match alter_table_op(mysql().verified_stmt("ALTER TABLE orders ADD COLUMN id INT FIRST")) {
+        AlterTableOperation::AddColumn { position, .. } => {
+            assert_eq!(Some(ColumnPosition::First), position)
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt("ALTER TABLE orders ADD COLUMN a INT AFTER id, ADD COLUMN b INT AFTER a");
+}
+
+#[test]
+fn parse_alter_table_indexes() {
+    match alter_table_op(mysql().verified_stmt("ALTER TABLE orders ADD INDEX idx_user (user_id)")) {
+        AlterTableOperation::AddConstraint(TableConstraint::Index {
+            index_type_display,
+            index_name,
+            columns,
+            index_type,
+        }) => {
+            assert_eq!(KeyOrIndexDisplay::Index, index_type_display);
+            assert_eq!(Some(Ident::new("idx_user")), index_name);
+            assert_eq!(
+                vec![KeyPart {
+                    column: Ident::new("user_id"),
+                    length: None,
+                    asc: None,
+                }],
+                columns
+            );
+            assert_eq!(None, index_type);
+        }
+        _ => unreachable!(),
+    }
+    mysql().verified_stmt(
+        "ALTER TABLE orders ADD UNIQUE KEY uq_code (code), ADD FULLTEXT INDEX (note)",
+    );
+
+    match alter_table_op(mysql().verified_stmt("ALTER TABLE orders RENAME INDEX idx_a TO idx_b")) {
+        AlterTableOperation::RenameIndex { old_name, new_name } => {
+            assert_eq!(Ident::new("idx_a"), old_name);
+            assert_eq!(Ident::new("idx_b"), new_name);
+        }
+        _ => unreachable!(),
+    }
+    mysql().one_statement_parses_to(
+        "ALTER TABLE orders RENAME KEY idx_a TO idx_b",
+        "ALTER TABLE orders RENAME INDEX idx_a TO idx_b",
+    );
+    // a column named `key` is renamed, not an index
+    match alter_table_op(mysql().one_statement_parses_to(
+        "ALTER TABLE orders RENAME `key` TO k",
+        "ALTER TABLE orders RENAME COLUMN `key` TO k",
+    )) {
+        AlterTableOperation::RenameColumn { .. } => {}
+        _ => unreachable!(),
+    }
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
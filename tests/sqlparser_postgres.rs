@@ -77,7 +77,7 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "first_name".into(),
-                        data_type: DataType::Varchar(Some(45)),
+                        data_type: DataType::Varchar(Some(CharacterLength::Length(45))),
                         collation: None,
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -86,7 +86,7 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_name".into(),
-                        data_type: DataType::Varchar(Some(45)),
+                        data_type: DataType::Varchar(Some(CharacterLength::Length(45))),
                         collation: Some(ObjectName(vec![Ident::with_quote('"', "es_ES")])),
                         options: vec![ColumnOptionDef {
                             name: None,
@@ -95,7 +95,7 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "email".into(),
-                        data_type: DataType::Varchar(Some(50)),
+                        data_type: DataType::Varchar(Some(CharacterLength::Length(50))),
                         collation: None,
                         options: vec![],
                     },
@@ -140,7 +140,7 @@ fn parse_create_table_with_defaults() {
                     },
                     ColumnDef {
                         name: "last_update".into(),
-                        data_type: DataType::Timestamp(None),
+                        data_type: DataType::Timestamp(None, TimezoneInfo::WithoutTimeZone),
                         collation: None,
                         options: vec![
                             ColumnOptionDef {
@@ -215,7 +215,7 @@ fn parse_create_table_from_pg_dump() {
             activebool BOOLEAN DEFAULT true NOT NULL, \
             create_date DATE DEFAULT now()::DATE NOT NULL, \
             create_date1 DATE DEFAULT 'now'::TEXT::DATE NOT NULL, \
-            last_update TIMESTAMP DEFAULT now(), \
+            last_update TIMESTAMP WITHOUT TIME ZONE DEFAULT now(), \
             release_year public.year, \
             active INT\
         )",
@@ -735,10 +735,13 @@ fn parse_map_access_expr() {
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(
         &MapAccess {
-            column: Box::new(Expr::Array(vec![
-                Expr::Value(Value::Number(zero.clone(), false)),
-                Expr::Value(Value::Number(one.clone(), false)),
-            ])),
+            column: Box::new(Expr::Array(Array {
+                elem: vec![
+                    Expr::Value(Value::Number(zero.clone(), false)),
+                    Expr::Value(Value::Number(one.clone(), false)),
+                ],
+                named: false,
+            })),
             keys: vec![Value::Number(zero.clone(), false),]
         },
         expr_from_projection(only(&select.projection)),
@@ -747,10 +750,13 @@ fn parse_map_access_expr() {
     let select = pg_and_generic().verified_only_select(sql);
     assert_eq!(
         &MapAccess {
-            column: Box::new(Expr::Nested(Box::new(Expr::Array(vec![
-                Expr::Value(Value::Number(zero.clone(), false)),
-                Expr::Value(Value::Number(one, false)),
-            ])))),
+            column: Box::new(Expr::Nested(Box::new(Expr::Array(Array {
+                elem: vec![
+                    Expr::Value(Value::Number(zero.clone(), false)),
+                    Expr::Value(Value::Number(one, false)),
+                ],
+                named: false,
+            })))),
             keys: vec![Value::Number(zero.clone(), false),]
         },
         expr_from_projection(only(&select.projection)),
@@ -774,6 +780,136 @@ fn parse_map_access_expr() {
     );
 }
 
+#[test]
+fn parse_array_index_expr() {
+    let num = |n: &'static str| Box::new(Expr::Value(number(n)));
+
+    // subscripts take arbitrary expressions and bind tighter than operators
+    let sql = "SELECT a[i + 1] = b[c[2]] FROM t";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::ArrayIndex {
+                obj: Box::new(Identifier(Ident::new("a"))),
+                index: Box::new(Expr::BinaryOp {
+                    left: Box::new(Identifier(Ident::new("i"))),
+                    op: BinaryOperator::Plus,
+                    right: num("1"),
+                }),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::ArrayIndex {
+                obj: Box::new(Identifier(Ident::new("b"))),
+                index: Box::new(MapAccess {
+                    column: Box::new(Identifier(Ident::new("c"))),
+                    keys: vec![number("2")],
+                }),
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT arr[x = 1], arr[i][0]");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::ArrayIndex {
+                obj: Box::new(Identifier(Ident::new("arr"))),
+                index: Box::new(Expr::BinaryOp {
+                    left: Box::new(Identifier(Ident::new("x"))),
+                    op: BinaryOperator::Eq,
+                    right: num("1"),
+                }),
+            }),
+            SelectItem::UnnamedExpr(Expr::ArrayIndex {
+                obj: Box::new(Expr::ArrayIndex {
+                    obj: Box::new(Identifier(Ident::new("arr"))),
+                    index: Box::new(Identifier(Ident::new("i"))),
+                }),
+                index: num("0"),
+            }),
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_array_slice_expr() {
+    let num = |n: &'static str| Box::new(Expr::Value(number(n)));
+    let arr = || Box::new(Identifier(Ident::new("arr")));
+
+    let select = pg_and_generic().verified_only_select("SELECT arr[1:3], arr[:2], arr[2:], arr[:]");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::ArraySlice {
+                obj: arr(),
+                lower_bound: Some(num("1")),
+                upper_bound: Some(num("3")),
+            }),
+            SelectItem::UnnamedExpr(Expr::ArraySlice {
+                obj: arr(),
+                lower_bound: None,
+                upper_bound: Some(num("2")),
+            }),
+            SelectItem::UnnamedExpr(Expr::ArraySlice {
+                obj: arr(),
+                lower_bound: Some(num("2")),
+                upper_bound: None,
+            }),
+            SelectItem::UnnamedExpr(Expr::ArraySlice {
+                obj: arr(),
+                lower_bound: None,
+                upper_bound: None,
+            }),
+        ],
+        select.projection
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT arr[1][n - 1:n]");
+    assert_eq!(
+        &Expr::ArraySlice {
+            obj: Box::new(MapAccess {
+                column: arr(),
+                keys: vec![number("1")],
+            }),
+            lower_bound: Some(Box::new(Expr::BinaryOp {
+                left: Box::new(Identifier(Ident::new("n"))),
+                op: BinaryOperator::Minus,
+                right: num("1"),
+            })),
+            upper_bound: Some(Box::new(Identifier(Ident::new("n")))),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+}
+
+#[test]
+fn parse_array_constructors() {
+    let sql = "SELECT ARRAY[1, 2], ARRAY[], ARRAY(SELECT x FROM t), ARRAY[ARRAY[1], ARRAY[2]]";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::Array(Array {
+            elem: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+            named: true,
+        })),
+        select.projection[0]
+    );
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::ArraySubquery(Box::new(
+            pg().verified_query("SELECT x FROM t")
+        ))),
+        select.projection[2]
+    );
+
+    pg().verified_stmt("SELECT * FROM t WHERE x > ALL(ARRAY[1, 2, 3])");
+
+    // without a subquery ARRAY(...) is an ordinary function call
+    let select = pg().verified_only_select("SELECT ARRAY(1, 2)");
+    assert!(matches!(
+        expr_from_projection(only(&select.projection)),
+        Expr::Function(_)
+    ));
+}
+
 #[test]
 fn test_transaction_statement() {
     let statement = pg().verified_stmt("SET TRANSACTION SNAPSHOT '000003A1-1'");
@@ -809,6 +945,7 @@ fn parse_cast() {
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
             data_type: DataType::BigInt(None),
             pg_style: true,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
@@ -820,11 +957,758 @@ fn parse_cast() {
             expr: Box::new(Expr::Identifier(Ident::new("id"))),
             data_type: DataType::TinyInt(None),
             pg_style: true,
+            format: None,
         },
         expr_from_projection(only(&select.projection))
     );
 }
 
+#[test]
+fn parse_timestamp_with_time_zone_types() {
+    let sql = "CREATE TABLE t (a TIMESTAMPTZ, b TIMESTAMPTZ(3), c TIMETZ, d TIMESTAMP(6) WITH TIME ZONE, e TIME WITHOUT TIME ZONE)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                vec![
+                    DataType::Timestamp(None, TimezoneInfo::Tz),
+                    DataType::Timestamp(Some(3), TimezoneInfo::Tz),
+                    DataType::Time(None, TimezoneInfo::Tz),
+                    DataType::Timestamp(Some(6), TimezoneInfo::WithTimeZone),
+                    DataType::Time(None, TimezoneInfo::WithoutTimeZone),
+                ],
+                columns.into_iter().map(|c| c.data_type).collect::<Vec<_>>()
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("SELECT '2001-02-16 20:38:40'::TIMESTAMPTZ AT TIME ZONE 'America/Denver'");
+}
+
+#[test]
+fn parse_any_with_placeholder() {
+    let select = pg().verified_only_select("SELECT * FROM users WHERE id = ANY($1)");
+    assert_eq!(
+        Expr::AnyOp {
+            left: Box::new(Identifier(Ident::new("id"))),
+            compare_op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(Value::Placeholder("$1".to_string()))),
+            is_some: false,
+        },
+        select.selection.unwrap()
+    );
+
+    pg().verified_stmt("SELECT * FROM users WHERE $1 <= ALL(scores) AND name = $2");
+}
+
+#[test]
+fn parse_json_ops() {
+    let select =
+        pg().verified_only_select("SELECT info -> 'items' ->> 'product' = 'Beer' FROM orders");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Identifier(Ident::new("info"))),
+                    op: BinaryOperator::Arrow,
+                    right: Box::new(Expr::Value(Value::SingleQuotedString("items".to_string()))),
+                }),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Value(Value::SingleQuotedString(
+                    "product".to_string()
+                ))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(Value::SingleQuotedString("Beer".to_string()))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let operators = [
+        ("->", BinaryOperator::Arrow),
+        ("->>", BinaryOperator::LongArrow),
+        ("#>", BinaryOperator::HashArrow),
+        ("#>>", BinaryOperator::HashLongArrow),
+        ("@>", BinaryOperator::AtArrow),
+        ("<@", BinaryOperator::ArrowAt),
+        ("?", BinaryOperator::Question),
+        ("?|", BinaryOperator::QuestionPipe),
+        ("?&", BinaryOperator::QuestionAnd),
+        ("@?", BinaryOperator::AtQuestion),
+    ];
+    for (str_op, op) in operators {
+        let select = pg().verified_only_select(&format!("SELECT a {} b", str_op));
+        assert_eq!(
+            &Expr::BinaryOp {
+                left: Box::new(Identifier(Ident::new("a"))),
+                op,
+                right: Box::new(Identifier(Ident::new("b"))),
+            },
+            expr_from_projection(only(&select.projection))
+        );
+    }
+
+    pg().verified_stmt(r#"SELECT * FROM t WHERE data @> '{"a": 1}'::JSONB AND data ? 'b'"#);
+
+    // `->` in function arguments is the JSON operator, not a lambda
+    let select = pg().verified_only_select("SELECT lower(info -> 'name')");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { args, .. }) => assert_eq!(
+            vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                Expr::BinaryOp {
+                    left: Box::new(Identifier(Ident::new("info"))),
+                    op: BinaryOperator::Arrow,
+                    right: Box::new(Expr::Value(Value::SingleQuotedString("name".to_string()))),
+                }
+            ))],
+            *args
+        ),
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("SELECT data #>> '{a,b}' || 'x' FROM t");
+}
+
+#[test]
+fn parse_table_function_column_definitions() {
+    let select = pg_and_generic().verified_only_select("SELECT * FROM f(1) AS (a INT, b TEXT)");
+    assert_eq!(
+        only(select.from).relation,
+        TableFactor::Function {
+            lateral: false,
+            name: ObjectName(vec![Ident::new("f")]),
+            args: vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                number("1")
+            )))],
+            with_ordinality: false,
+            column_defs: vec![
+                TableAliasColumnDef {
+                    name: Ident::new("a"),
+                    data_type: Some(DataType::Int(None)),
+                },
+                TableAliasColumnDef {
+                    name: Ident::new("b"),
+                    data_type: Some(DataType::Text),
+                },
+            ],
+            alias: None,
+        }
+    );
+
+    let select = pg_and_generic().verified_only_select("SELECT * FROM f(1) AS t (a INT, b)");
+    match only(select.from).relation {
+        TableFactor::Table { alias, .. } => assert_eq!(
+            alias.unwrap().columns,
+            vec![
+                TableAliasColumnDef {
+                    name: Ident::new("a"),
+                    data_type: Some(DataType::Int(None)),
+                },
+                TableAliasColumnDef::from_name("b"),
+            ]
+        ),
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    pg_and_generic()
+        .verified_stmt("SELECT * FROM generate_series(1, 3) WITH ORDINALITY AS t (x, n)");
+    pg_and_generic().verified_stmt("SELECT * FROM a, LATERAL json_each(a.js) WITH ORDINALITY");
+}
+
+#[test]
+fn parse_rows_from() {
+    let sql = "SELECT * FROM ROWS FROM (f(1), g()) WITH ORDINALITY AS t (a, b, n)";
+    let select = pg_and_generic().verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::RowsFrom {
+            functions,
+            with_ordinality,
+            alias,
+        } => {
+            assert_eq!(
+                functions
+                    .iter()
+                    .map(|f| f.name.to_string())
+                    .collect::<Vec<_>>(),
+                vec!["f", "g"]
+            );
+            assert!(with_ordinality);
+            assert_eq!(alias.unwrap().columns.len(), 3);
+        }
+        _ => panic!("Expecting TableFactor::RowsFrom"),
+    }
+
+    pg_and_generic().verified_stmt("SELECT * FROM ROWS FROM (unnest(a))");
+}
+
+#[test]
+fn parse_convert_function() {
+    // Postgres' convert(bytea, src_encoding, dest_encoding) is an ordinary function
+    let select = pg().verified_only_select("SELECT convert(data, 'UTF8', 'LATIN1') FROM t");
+    assert!(matches!(
+        expr_from_projection(&select.projection[0]),
+        Expr::Function(_)
+    ));
+}
+
+#[test]
+fn parse_bit_string_and_collate() {
+    let select = pg().verified_only_select(r#"SELECT B'0101', a::TEXT COLLATE "C" FROM t"#);
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Value(Value::BitStringLiteral("0101".to_string()))),
+            SelectItem::UnnamedExpr(Expr::Collate {
+                expr: Box::new(Expr::Cast {
+                    expr: Box::new(Expr::Identifier(Ident::new("a"))),
+                    data_type: DataType::Text,
+                    pg_style: true,
+                    format: None,
+                }),
+                collation: ObjectName(vec![Ident::with_quote('"', "C")]),
+            }),
+        ],
+        select.projection
+    );
+}
+
+#[test]
+fn parse_pg_data_types() {
+    pg().verified_stmt(
+        "CREATE TABLE t (tags TEXT[], matrix INT[][], price MONEY, addr INET, doc JSONB)",
+    );
+    let select = pg().verified_only_select("SELECT '{1,2}'::INT[]");
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::Value(Value::SingleQuotedString("{1,2}".to_string()))),
+            data_type: DataType::BracketArray(Box::new(DataType::Int(None)), None),
+            pg_style: true,
+            format: None,
+        },
+        expr_from_projection(&select.projection[0])
+    );
+}
+
+#[test]
+fn parse_custom_type_modifiers() {
+    let sql = "CREATE TABLE places (\
+        geom geometry(Point, 4326), \
+        embedding vector(1536), \
+        label mylabel('en', 2), \
+        names CHARACTER VARYING(20)[3], \
+        slug citext COLLATE \"C\", \
+        tm mytype(-1))";
+    let columns = match pg().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => columns,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        DataType::Custom(
+            ObjectName(vec![Ident::new("geometry")]),
+            vec!["Point".to_string(), "4326".to_string()]
+        ),
+        columns[0].data_type
+    );
+    assert_eq!(
+        DataType::Custom(
+            ObjectName(vec![Ident::new("vector")]),
+            vec!["1536".to_string()]
+        ),
+        columns[1].data_type
+    );
+    assert_eq!(
+        DataType::Custom(
+            ObjectName(vec![Ident::new("mylabel")]),
+            vec!["'en'".to_string(), "2".to_string()]
+        ),
+        columns[2].data_type
+    );
+    assert_eq!(
+        DataType::BracketArray(
+            Box::new(DataType::Varchar(Some(CharacterLength::Length(20)))),
+            Some(3)
+        ),
+        columns[3].data_type
+    );
+    assert_eq!(
+        Some(ObjectName(vec![Ident::with_quote('"', "C")])),
+        columns[4].collation
+    );
+    assert_eq!(
+        DataType::Custom(
+            ObjectName(vec![Ident::new("mytype")]),
+            vec!["-1".to_string()]
+        ),
+        columns[5].data_type
+    );
+
+    // ClickHouse wrapper type names are ordinary custom types elsewhere
+    pg().verified_stmt("CREATE TABLE t (a nullable(int), b \"Nullable\"(int))");
+}
+
+#[test]
+fn parse_create_function() {
+    let sql = "CREATE OR REPLACE FUNCTION add(a INT, IN b INT DEFAULT 1) \
+               RETURNS INT LANGUAGE plpgsql IMMUTABLE STRICT SECURITY DEFINER \
+               AS $$BEGIN RETURN a + b; END$$";
+    match pg().verified_stmt(sql) {
+        Statement::CreateFunction {
+            or_replace,
+            temporary,
+            name,
+            args,
+            return_type,
+            body,
+        } => {
+            assert!(or_replace);
+            assert!(!temporary);
+            assert_eq!("add", name.to_string());
+            assert_eq!(
+                Some(vec![
+                    OperateFunctionArg {
+                        mode: None,
+                        name: Some("a".into()),
+                        data_type: DataType::Int(None),
+                        default_expr: None,
+                    },
+                    OperateFunctionArg {
+                        mode: Some(ArgMode::In),
+                        name: Some("b".into()),
+                        data_type: DataType::Int(None),
+                        default_expr: Some(Expr::Value(number("1"))),
+                    },
+                ]),
+                args
+            );
+            assert_eq!(
+                Some(FunctionReturnType::DataType(DataType::Int(None))),
+                return_type
+            );
+            assert_eq!(
+                CreateFunctionBody {
+                    language: Some("plpgsql".into()),
+                    behavior: Some(FunctionBehavior::Immutable),
+                    called_on_null: Some(FunctionCalledOnNull::Strict),
+                    security: Some(FunctionSecurity::Definer),
+                    as_: Some(FunctionDefinition::DollarQuotedDef(DollarQuotedString {
+                        value: "BEGIN RETURN a + b; END".to_string(),
+                        tag: None,
+                    })),
+                    using: vec![],
+                },
+                body
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // options may appear in any order, and arguments may be unnamed
+    pg().one_statement_parses_to(
+        "CREATE FUNCTION f(INTEGER, double precision = 0.5) RETURNS TEXT \
+         AS 'select $1' LANGUAGE SQL RETURNS NULL ON NULL INPUT STABLE",
+        "CREATE FUNCTION f(INT, DOUBLE PRECISION DEFAULT 0.5) RETURNS TEXT \
+         LANGUAGE SQL STABLE RETURNS NULL ON NULL INPUT AS 'select $1'",
+    );
+    pg().verified_stmt(
+        "CREATE FUNCTION split(VARIADIC parts TEXT[], OUT head TEXT, INOUT n INT) \
+         LANGUAGE plpgsql VOLATILE CALLED ON NULL INPUT SECURITY INVOKER \
+         AS $body$BEGIN head := parts[1]; END$body$",
+    );
+    pg().verified_stmt("CREATE FUNCTION trg() RETURNS trigger LANGUAGE plpgsql AS $$BEGIN END$$");
+    pg().verified_stmt("SELECT $$it's a string$$, $q$with $$ inside$q$");
+    match pg().one_statement_parses_to("SELECT -$$abc$$", "SELECT - $$abc$$") {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => assert_eq!(
+                &Expr::UnaryOp {
+                    op: UnaryOperator::Minus,
+                    expr: Box::new(Expr::Value(Value::DollarQuotedString(DollarQuotedString {
+                        value: "abc".to_string(),
+                        tag: None,
+                    }))),
+                },
+                expr_from_projection(only(&select.projection))
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(
+        "CREATE FUNCTION f() RETURNS TABLE (a INT, b TEXT) LANGUAGE sql AS 'select 1, 2'",
+    ) {
+        Statement::CreateFunction { return_type, .. } => assert_eq!(
+            Some(FunctionReturnType::Table(vec![
+                ColumnDef {
+                    name: "a".into(),
+                    data_type: DataType::Int(None),
+                    collation: None,
+                    options: vec![],
+                },
+                ColumnDef {
+                    name: "b".into(),
+                    data_type: DataType::Text,
+                    collation: None,
+                    options: vec![],
+                },
+            ])),
+            return_type
+        ),
+        _ => unreachable!(),
+    }
+    match pg().verified_stmt("CREATE FUNCTION f() RETURNS SETOF INT AS 'select 1'") {
+        Statement::CreateFunction { return_type, .. } => assert_eq!(
+            Some(FunctionReturnType::SetOf(DataType::Int(None))),
+            return_type
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = pg().parse_sql_statements("CREATE FUNCTION f() LANGUAGE sql LANGUAGE plpgsql");
+    assert_eq!(
+        ParserError::ParserError("LANGUAGE specified more than once".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_sequence() {
+    // the pg_dump form
+    let sql = "CREATE SEQUENCE public.users_id_seq AS INT START WITH 1 INCREMENT BY 1 \
+               NO MINVALUE NO MAXVALUE CACHE 1";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateSequence {
+            temporary,
+            if_not_exists,
+            name,
+            data_type,
+            sequence_options,
+        } => {
+            assert!(!temporary);
+            assert!(!if_not_exists);
+            assert_eq!("public.users_id_seq", name.to_string());
+            assert_eq!(Some(DataType::Int(None)), data_type);
+            assert_eq!(
+                vec![
+                    SequenceOptions::StartWith(Expr::Value(number("1"))),
+                    SequenceOptions::IncrementBy(Expr::Value(number("1"))),
+                    SequenceOptions::MinValue(None),
+                    SequenceOptions::MaxValue(None),
+                    SequenceOptions::Cache(Expr::Value(number("1"))),
+                ],
+                sequence_options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().one_statement_parses_to(
+        "CREATE TEMPORARY SEQUENCE IF NOT EXISTS s INCREMENT -2 MINVALUE -100 MAXVALUE 0 \
+         START 0 CYCLE OWNED BY t.id",
+        "CREATE TEMPORARY SEQUENCE IF NOT EXISTS s INCREMENT BY -2 MINVALUE -100 MAXVALUE 0 \
+         START WITH 0 CYCLE OWNED BY t.id",
+    );
+}
+
+#[test]
+fn parse_alter_sequence() {
+    let sql = "ALTER SEQUENCE public.users_id_seq OWNED BY public.users.id";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::AlterSequence {
+            if_exists,
+            name,
+            data_type,
+            sequence_options,
+        } => {
+            assert!(!if_exists);
+            assert_eq!("public.users_id_seq", name.to_string());
+            assert_eq!(None, data_type);
+            assert_eq!(
+                vec![SequenceOptions::OwnedBy(Some(ObjectName(vec![
+                    "public".into(),
+                    "users".into(),
+                    "id".into()
+                ])))],
+                sequence_options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("ALTER SEQUENCE IF EXISTS s AS BIGINT NO CYCLE OWNED BY NONE");
+    pg_and_generic().verified_stmt("ALTER SEQUENCE s RESTART");
+    pg_and_generic().one_statement_parses_to(
+        "ALTER SEQUENCE s RESTART 100",
+        "ALTER SEQUENCE s RESTART WITH 100",
+    );
+}
+
+#[test]
+fn parse_identity_column() {
+    let sql = "CREATE TABLE t (\
+               id INT GENERATED ALWAYS AS IDENTITY, \
+               n BIGINT GENERATED BY DEFAULT AS IDENTITY \
+               (SEQUENCE NAME t_n_seq START WITH 10 INCREMENT BY 5) NOT NULL)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Identity {
+                        generated_as: GeneratedAs::Always,
+                        sequence_options: vec![],
+                    },
+                }],
+                columns[0].options
+            );
+            assert_eq!(
+                vec![
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::Identity {
+                            generated_as: GeneratedAs::ByDefault,
+                            sequence_options: vec![
+                                SequenceOptions::SequenceName(ObjectName(vec!["t_n_seq".into()])),
+                                SequenceOptions::StartWith(Expr::Value(number("10"))),
+                                SequenceOptions::IncrementBy(Expr::Value(number("5"))),
+                            ],
+                        },
+                    },
+                    ColumnOptionDef {
+                        name: None,
+                        option: ColumnOption::NotNull,
+                    },
+                ],
+                columns[1].options
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_generated_column() {
+    let sql = "CREATE TABLE t (a INT, b INT GENERATED ALWAYS AS (a * 2) STORED)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable { columns, .. } => assert_eq!(
+            vec![ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Generated {
+                    generated_keyword: true,
+                    expr: Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier("a".into())),
+                        op: BinaryOperator::Multiply,
+                        right: Box::new(Expr::Value(number("2"))),
+                    },
+                    mode: Some(GeneratedColumnMode::Stored),
+                },
+            }],
+            columns[1].options
+        ),
+        _ => unreachable!(),
+    }
+
+    let res = pg().parse_sql_statements("CREATE TABLE t (a INT GENERATED BY DEFAULT AS (1))");
+    assert_eq!(
+        ParserError::ParserError("Expected IDENTITY, found: (".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_partition_by() {
+    let sql = "CREATE TABLE measurement (city_id INT NOT NULL, logdate DATE NOT NULL) \
+               PARTITION BY RANGE (logdate)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable {
+            name, partition_by, ..
+        } => {
+            assert_eq!("measurement", name.to_string());
+            assert_eq!(
+                Some(Box::new(PartitionBy {
+                    method: PartitionMethod::Range,
+                    columns: vec![Expr::Identifier(Ident::new("logdate"))],
+                    partitions: None,
+                    definitions: vec![],
+                })),
+                partition_by
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt(
+        "CREATE TABLE cities (city_id INT, name TEXT) PARTITION BY LIST (lower(name))",
+    );
+    pg().verified_stmt("CREATE TABLE orders (id INT, region TEXT) PARTITION BY HASH (id, region)");
+}
+
+#[test]
+fn parse_create_table_partition_of() {
+    let sql = "CREATE TABLE measurement_y2006m02 PARTITION OF measurement \
+               FOR VALUES FROM ('2006-02-01') TO ('2006-03-01')";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable {
+            name,
+            columns,
+            partition_of,
+            ..
+        } => {
+            assert_eq!("measurement_y2006m02", name.to_string());
+            assert!(columns.is_empty());
+            assert_eq!(
+                Some(Box::new(PartitionOf {
+                    parent: ObjectName(vec![Ident::new("measurement")]),
+                    bound: PartitionBound::FromTo {
+                        from: vec![Expr::Value(Value::SingleQuotedString(
+                            "2006-02-01".to_string()
+                        ))],
+                        to: vec![Expr::Value(Value::SingleQuotedString(
+                            "2006-03-01".to_string()
+                        ))],
+                    },
+                })),
+                partition_of
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt(
+        "CREATE TABLE orders_p0 PARTITION OF orders FOR VALUES WITH (MODULUS 4, REMAINDER 0)",
+    ) {
+        Statement::CreateTable { partition_of, .. } => assert_eq!(
+            PartitionBound::With {
+                modulus: 4,
+                remainder: 0
+            },
+            partition_of.unwrap().bound
+        ),
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt(
+        "CREATE TABLE measurement_old PARTITION OF measurement \
+         FOR VALUES FROM (MINVALUE) TO ('2006-01-01')",
+    );
+    pg().verified_stmt(
+        "CREATE TABLE cities_ab PARTITION OF cities (CONSTRAINT city_id_nonzero CHECK (city_id <> 0)) \
+         FOR VALUES IN ('a', 'b') PARTITION BY RANGE (population)",
+    );
+    pg().verified_stmt("CREATE TABLE cities_other PARTITION OF cities DEFAULT");
+
+    let res = pg().parse_sql_statements("CREATE TABLE t PARTITION OF p FOR VALUES LESS THAN (1)");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected IN, FROM or WITH after FOR VALUES, found: LESS".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_create_table_inherits_and_tablespace() {
+    let sql = "CREATE TABLE capitals (state CHAR(2)) INHERITS (cities, public.places) TABLESPACE fast_space";
+    match pg().verified_stmt(sql) {
+        Statement::CreateTable {
+            inherits,
+            tablespace,
+            ..
+        } => {
+            assert_eq!(
+                vec!["cities", "public.places"],
+                inherits.iter().map(ToString::to_string).collect::<Vec<_>>()
+            );
+            assert_eq!(Some(Ident::new("fast_space")), tablespace);
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("CREATE TABLE t (a INT) WITH (fillfactor = 70) TABLESPACE pg_default");
+}
+
+#[test]
+fn parse_create_index_with_storage_parameters() {
+    let sql = "CREATE INDEX title_idx ON films(title) WITH (fillfactor = 70, deduplicate_items = 'off') TABLESPACE indexspace";
+    match pg().verified_stmt(sql) {
+        Statement::CreateIndex {
+            with, tablespace, ..
+        } => {
+            assert_eq!(
+                vec![
+                    SqlOption {
+                        name: Ident::new("fillfactor"),
+                        value: number("70"),
+                    },
+                    SqlOption {
+                        name: Ident::new("deduplicate_items"),
+                        value: Value::SingleQuotedString("off".to_string()),
+                    },
+                ],
+                with
+            );
+            assert_eq!(Some(Ident::new("indexspace")), tablespace);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_alter_table_alter_column_type_and_identity() {
+    match alter_table_op(pg().verified_stmt(
+        "ALTER TABLE tab ALTER COLUMN created SET DATA TYPE TIMESTAMP USING to_timestamp(created)",
+    )) {
+        AlterTableOperation::AlterColumn { column_name, op } => {
+            assert_eq!("created", column_name.to_string());
+            match op {
+                AlterColumnOperation::SetDataType { data_type, using } => {
+                    assert_eq!("TIMESTAMP", data_type.to_string());
+                    assert_eq!("to_timestamp(created)", using.unwrap().to_string());
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => unreachable!(),
+    }
+    pg().one_statement_parses_to(
+        "ALTER TABLE tab ALTER id TYPE BIGINT",
+        "ALTER TABLE tab ALTER COLUMN id SET DATA TYPE BIGINT",
+    );
+
+    match alter_table_op(pg().verified_stmt(
+        "ALTER TABLE tab ALTER COLUMN id ADD GENERATED BY DEFAULT AS IDENTITY (START WITH 10)",
+    )) {
+        AlterTableOperation::AlterColumn { op, .. } => assert_eq!(
+            AlterColumnOperation::AddGenerated {
+                generated_as: GeneratedAs::ByDefault,
+                sequence_options: vec![SequenceOptions::StartWith(Expr::Value(number("10")))],
+            },
+            op
+        ),
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER TABLE tab ALTER COLUMN id ADD GENERATED ALWAYS AS IDENTITY");
+}
+
+#[test]
+fn parse_alter_table_schema_owner_and_triggers() {
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab SET SCHEMA archive")) {
+        AlterTableOperation::SetSchema { schema_name } => {
+            assert_eq!("archive", schema_name.to_string())
+        }
+        _ => unreachable!(),
+    }
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab OWNER TO admin")) {
+        AlterTableOperation::OwnerTo { new_owner } => assert_eq!(Ident::new("admin"), new_owner),
+        _ => unreachable!(),
+    }
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab ENABLE TRIGGER audit")) {
+        AlterTableOperation::EnableTrigger { name } => assert_eq!(Ident::new("audit"), name),
+        _ => unreachable!(),
+    }
+    match alter_table_op(pg().verified_stmt("ALTER TABLE tab DISABLE TRIGGER ALL")) {
+        AlterTableOperation::DisableTrigger { name } => assert_eq!(Ident::new("ALL"), name),
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("ALTER TABLE tab ENABLE TRIGGER USER, OWNER TO CURRENT_USER");
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],
@@ -26,7 +26,8 @@ use sqlparser::tokenizer::*;
 #[test]
 fn test_snowflake_create_table() {
     let sql = "CREATE TABLE _my_$table (am00unt number)";
-    match snowflake_and_generic().verified_stmt(sql) {
+    let canonical = "CREATE TABLE _my_$table (am00unt NUMBER)";
+    match snowflake_and_generic().one_statement_parses_to(sql, canonical) {
         Statement::CreateTable { name, .. } => {
             assert_eq!("_my_$table", name.to_string());
         }
@@ -76,32 +77,38 @@ fn test_snowflake_single_line_tokenize() {
 fn parse_colon_map_access_expr() {
     let sql = "SELECT foo:key1:key2 FROM foos";
     let select = snowflake_and_generic().verified_only_select(sql);
+    let key = |key: &str| JsonPathElem::Dot {
+        key: key.to_string(),
+        quoted: false,
+    };
     assert_eq!(
-        &Expr::MapAccess {
-            column: Box::new(Expr::Identifier(Ident {
-                value: "foo".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Value::ColonString("key1".to_string()),
-                Value::ColonString("key2".to_string()),
-            ]
+        &Expr::JsonAccess {
+            value: Box::new(Expr::JsonAccess {
+                value: Box::new(Expr::Identifier(Ident::new("foo"))),
+                path: JsonPath {
+                    path: vec![key("key1")]
+                },
+            }),
+            path: JsonPath {
+                path: vec![key("key2")]
+            },
         },
         expr_from_projection(only(&select.projection)),
     );
     let sql = r#"SELECT foo:key1.key2["key3"] FROM foos"#;
     let select = snowflake_and_generic().verified_only_select(sql);
     assert_eq!(
-        &Expr::MapAccess {
-            column: Box::new(Expr::Identifier(Ident {
-                value: "foo".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Value::ColonString("key1".to_string()),
-                Value::PeriodString("key2".to_string()),
-                Value::SingleQuotedString("key3".to_string()),
-            ]
+        &Expr::JsonAccess {
+            value: Box::new(Expr::Identifier(Ident::new("foo"))),
+            path: JsonPath {
+                path: vec![
+                    key("key1"),
+                    key("key2"),
+                    JsonPathElem::Bracket {
+                        key: Expr::Identifier(Ident::with_quote('"', "key3"))
+                    },
+                ]
+            },
         },
         expr_from_projection(only(&select.projection)),
     );
@@ -114,6 +121,133 @@ fn parse_colon_map_access_expr() {
     );
 }
 
+#[test]
+fn parse_semi_structured_path() {
+    let sql = r#"SELECT src:customer[0]."Name"::STRING, src:items[i + 1].value FROM t"#;
+    let select = snowflake_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            expr: Box::new(Expr::JsonAccess {
+                value: Box::new(Expr::Identifier(Ident::new("src"))),
+                path: JsonPath {
+                    path: vec![
+                        JsonPathElem::Dot {
+                            key: "customer".to_string(),
+                            quoted: false,
+                        },
+                        JsonPathElem::Bracket {
+                            key: Expr::Value(number("0")),
+                        },
+                        JsonPathElem::Dot {
+                            key: "Name".to_string(),
+                            quoted: true,
+                        },
+                    ]
+                },
+            }),
+            data_type: DataType::String,
+            pg_style: true,
+            format: None,
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    snowflake_and_generic().verified_stmt("SELECT * FROM t WHERE src:a.b = 'x' AND src:c:d > 1");
+}
+
+#[test]
+fn parse_timestamp_variants() {
+    let select = snowflake().verified_only_select(
+        "SELECT CAST(a AS TIMESTAMP_LTZ), CAST(b AS TIMESTAMP_NTZ(9)), CAST(c AS TIMESTAMPTZ)",
+    );
+    let data_types: Vec<_> = select
+        .projection
+        .into_iter()
+        .map(|item| match item {
+            SelectItem::UnnamedExpr(Expr::Cast { data_type, .. }) => data_type,
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            DataType::Timestamp(None, TimezoneInfo::LocalTz),
+            DataType::Timestamp(Some(9), TimezoneInfo::NoTz),
+            DataType::Timestamp(None, TimezoneInfo::Tz),
+        ],
+        data_types
+    );
+}
+
+#[test]
+fn parse_extract_custom_part() {
+    let select = snowflake_and_generic().verified_only_select("SELECT EXTRACT(yy FROM d)");
+    assert_eq!(
+        &Expr::Extract {
+            field: DateTimeField::Custom(Ident::new("yy")),
+            expr: Box::new(Expr::Identifier(Ident::new("d"))),
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+}
+
+#[test]
+fn parse_semi_structured_casts() {
+    let select = snowflake()
+        .verified_only_select("SELECT src:a::VARIANT, src:b::OBJECT, src:c::ARRAY FROM t");
+    let data_types: Vec<_> = select
+        .projection
+        .into_iter()
+        .map(|item| match item {
+            SelectItem::UnnamedExpr(Expr::Cast { data_type, .. }) => data_type,
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            DataType::Variant,
+            DataType::Object,
+            DataType::Custom(ObjectName(vec![Ident::new("ARRAY")]), vec![]),
+        ],
+        data_types
+    );
+}
+
+#[test]
+fn parse_like_any() {
+    let sql = "SELECT * FROM t WHERE name LIKE ANY ('a%', '%b') ESCAPE '^'";
+    let select = snowflake().verified_only_select(sql);
+    assert_eq!(
+        Expr::LikeQuantified {
+            negated: false,
+            case_insensitive: false,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            quantifier: LikeQuantifier::Any,
+            patterns: vec![
+                Expr::Value(Value::SingleQuotedString("a%".to_string())),
+                Expr::Value(Value::SingleQuotedString("%b".to_string())),
+            ],
+            escape_char: Some("^".to_string()),
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM t WHERE name NOT ILIKE ALL ('a%', '%b')";
+    let select = snowflake().verified_only_select(sql);
+    assert_eq!(
+        Expr::LikeQuantified {
+            negated: true,
+            case_insensitive: true,
+            expr: Box::new(Expr::Identifier(Ident::new("name"))),
+            quantifier: LikeQuantifier::All,
+            patterns: vec![
+                Expr::Value(Value::SingleQuotedString("a%".to_string())),
+                Expr::Value(Value::SingleQuotedString("%b".to_string())),
+            ],
+            escape_char: None,
+        },
+        select.selection.unwrap()
+    );
+}
+
 #[test]
 fn test_sf_derived_table_in_parenthesis() {
     // Nesting a subquery in an extra set of parentheses is non-standard,
@@ -186,6 +320,211 @@ fn test_single_table_in_parenthesis_with_alias() {
     );
 }
 
+#[test]
+fn test_sample() {
+    let select = snowflake().verified_only_select("SELECT * FROM t SAMPLE (1000 ROWS)");
+    match only(select.from).relation {
+        TableFactor::Table { sample, .. } => assert_eq!(
+            sample,
+            Some(TableSampleKind::AfterTableAlias(TableSample {
+                modifier: TableSampleModifier::Sample,
+                method: None,
+                size: TableSampleSize::Quantity {
+                    value: Expr::Value(number("1000")),
+                    unit: Some(TableSampleUnit::Rows),
+                },
+                seed: None,
+            }))
+        ),
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    snowflake().verified_stmt("SELECT * FROM t AS a SAMPLE ROW (10) SEED (42)");
+    snowflake().verified_stmt("SELECT * FROM t SAMPLE BLOCK (3) REPEATABLE (7)");
+    // `SAMPLE` only starts a clause when followed by a size or sampling method
+    snowflake().one_statement_parses_to("SELECT * FROM t sample", "SELECT * FROM t AS sample");
+    snowflake().one_statement_parses_to(
+        "SELECT * FROM t sample WHERE x = 1",
+        "SELECT * FROM t AS sample WHERE x = 1",
+    );
+    // and only in Snowflake
+    let res = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+    }
+    .parse_sql_statements("SELECT * FROM t SAMPLE (1000 ROWS)");
+    assert!(res.is_err());
+    snowflake_and_generic().one_statement_parses_to(
+        "SELECT * FROM t tablesample bernoulli (20.3)",
+        "SELECT * FROM t TABLESAMPLE BERNOULLI (20.3)",
+    );
+}
+
+#[test]
+fn test_time_travel() {
+    let select =
+        snowflake_and_generic().verified_only_select("SELECT * FROM t AT(OFFSET => 60 * -5) AS a");
+    match only(select.from).relation {
+        TableFactor::Table { instant, alias, .. } => {
+            assert_eq!(
+                instant,
+                Some(Instant::At {
+                    point: InstantPoint::Offset,
+                    value: Box::new(Expr::BinaryOp {
+                        left: Box::new(Expr::Value(number("60"))),
+                        op: BinaryOperator::Multiply,
+                        right: Box::new(Expr::Value(number("-5"))),
+                    }),
+                })
+            );
+            assert_eq!(alias.unwrap().name, Ident::new("a"));
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    snowflake_and_generic()
+        .verified_stmt("SELECT * FROM t AT(TIMESTAMP => '2021-06-01 12:00:00'::TIMESTAMP)");
+    snowflake_and_generic().verified_stmt(
+        "SELECT * FROM t BEFORE(STATEMENT => '8e5d0ca9-005e-44e6-b858-a8f5b37c5726')",
+    );
+    snowflake_and_generic().one_statement_parses_to(
+        "SELECT * FROM t at(stream => 's1')",
+        "SELECT * FROM t AT(STREAM => 's1')",
+    );
+    // `before` without parentheses is an alias
+    snowflake_and_generic()
+        .one_statement_parses_to("SELECT * FROM t before", "SELECT * FROM t AS before");
+}
+
+#[test]
+fn test_changes() {
+    let sql = concat!(
+        "SELECT * FROM t CHANGES(INFORMATION => DEFAULT) ",
+        "AT(TIMESTAMP => ts) END(OFFSET => -60)"
+    );
+    let select = snowflake_and_generic().verified_only_select(sql);
+    match only(select.from).relation {
+        TableFactor::Table { instant, .. } => assert_eq!(
+            instant,
+            Some(Instant::Changes {
+                information: Ident::new("DEFAULT"),
+                start: Box::new(Instant::At {
+                    point: InstantPoint::Timestamp,
+                    value: Box::new(Expr::Identifier(Ident::new("ts"))),
+                }),
+                end: Some(Box::new(Instant::End {
+                    point: InstantPoint::Offset,
+                    value: Box::new(Expr::Value(number("-60"))),
+                })),
+            })
+        ),
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    snowflake_and_generic().verified_stmt(
+        "SELECT * FROM t CHANGES(INFORMATION => APPEND_ONLY) BEFORE(STATEMENT => 'id') AS c",
+    );
+
+    let res = snowflake().parse_sql_statements("SELECT * FROM t CHANGES(INFORMATION => DEFAULT)");
+    assert_eq!(
+        ParserError::ParserError("Expected AT or BEFORE after CHANGES, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn test_lateral_flatten() {
+    let sql = "SELECT f.value FROM t, LATERAL FLATTEN(input => t.col) AS f";
+    let select = snowflake_and_generic().verified_only_select(sql);
+    assert_eq!(
+        select.from[1].relation,
+        TableFactor::Function {
+            lateral: true,
+            name: ObjectName(vec![Ident::new("FLATTEN")]),
+            args: vec![FunctionArg::Named {
+                name: Ident::new("input"),
+                arg: FunctionArgExpr::Expr(Expr::CompoundIdentifier(vec![
+                    Ident::new("t"),
+                    Ident::new("col")
+                ])),
+            }],
+            with_ordinality: false,
+            column_defs: vec![],
+            alias: Some(TableAlias {
+                name: Ident::new("f"),
+                columns: vec![],
+            }),
+        }
+    );
+
+    snowflake_and_generic().one_statement_parses_to(
+        "SELECT * FROM t JOIN LATERAL FLATTEN(input => t.col, outer => true) f ON true",
+        "SELECT * FROM t JOIN LATERAL FLATTEN(input => t.col, outer => true) AS f ON true",
+    );
+}
+
+#[test]
+fn parse_masking_policy_column_option() {
+    let sql = "CREATE TABLE t (email STRING WITH MASKING POLICY p USING (email, visibility))";
+    match snowflake().one_statement_parses_to(
+        sql,
+        "CREATE TABLE t (email STRING MASKING POLICY p USING (email, visibility))",
+    ) {
+        Statement::CreateTable { columns, .. } => assert_eq!(
+            vec![ColumnOptionDef {
+                name: None,
+                option: ColumnOption::MaskingPolicy {
+                    policy: ObjectName(vec!["p".into()]),
+                    using_columns: vec!["email".into(), "visibility".into()],
+                },
+            }],
+            columns[0].options
+        ),
+        _ => unreachable!(),
+    }
+    snowflake().verified_stmt("CREATE TABLE t (ssn STRING MASKING POLICY db.s.ssn_mask)");
+}
+
+#[test]
+fn test_alter_table_cluster_by_and_swap_with() {
+    match alter_table_op(
+        snowflake().verified_stmt("ALTER TABLE tab CLUSTER BY (a, date_trunc('DAY', b))"),
+    ) {
+        AlterTableOperation::ClusterBy { exprs } => assert_eq!(
+            vec!["a", "date_trunc('DAY', b)"],
+            exprs.iter().map(ToString::to_string).collect::<Vec<_>>()
+        ),
+        _ => unreachable!(),
+    }
+    match alter_table_op(snowflake().verified_stmt("ALTER TABLE tab SWAP WITH db.tab_staging")) {
+        AlterTableOperation::SwapWith { table_name } => {
+            assert_eq!("db.tab_staging", table_name.to_string())
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_array_index_expr() {
+    let select = snowflake().verified_only_select("SELECT arr[i + 1] FROM t");
+    assert_eq!(
+        &Expr::ArrayIndex {
+            obj: Box::new(Expr::Identifier(Ident::new("arr"))),
+            index: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("i"))),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+}
+
+#[test]
+fn parse_b_prefixed_string_as_alias() {
+    // Snowflake has no bit strings, so `b'abc'` is a column followed by an alias
+    snowflake().one_statement_parses_to("SELECT b'abc'", "SELECT b AS 'abc'");
+}
+
 fn snowflake() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(SnowflakeDialect {})],
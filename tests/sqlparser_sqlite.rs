@@ -20,7 +20,7 @@ use test_utils::*;
 
 use sqlparser::ast::*;
 use sqlparser::dialect::{GenericDialect, SQLiteDialect};
-use sqlparser::tokenizer::Token;
+use sqlparser::parser::ParserError;
 
 #[test]
 fn parse_create_table_without_rowid() {
@@ -77,9 +77,7 @@ fn parse_create_table_auto_increment() {
                         },
                         ColumnOptionDef {
                             name: None,
-                            option: ColumnOption::DialectSpecific(vec![Token::make_keyword(
-                                "AUTOINCREMENT"
-                            )])
+                            option: ColumnOption::Autoincrement
                         }
                     ],
                 }],
@@ -118,6 +116,36 @@ fn parse_create_sqlite_quote() {
     }
 }
 
+#[test]
+fn parse_collate_column_option() {
+    // a collation following other options is the column's collation
+    let sql = "CREATE TABLE t (a TEXT NOT NULL COLLATE NOCASE)";
+    let canonical = "CREATE TABLE t (a TEXT COLLATE NOCASE NOT NULL)";
+    match sqlite().one_statement_parses_to(sql, canonical) {
+        Statement::CreateTable { columns, .. } => {
+            assert_eq!(
+                Some(ObjectName(vec!["NOCASE".into()])),
+                columns[0].collation
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::NotNull,
+                }],
+                columns[0].options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    let res =
+        sqlite().parse_sql_statements("CREATE TABLE t (a TEXT COLLATE BINARY COLLATE NOCASE)");
+    assert_eq!(
+        ParserError::ParserError("Duplicate COLLATE for column a".to_string()),
+        res.unwrap_err()
+    );
+}
+
 fn sqlite() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(SQLiteDialect {})],